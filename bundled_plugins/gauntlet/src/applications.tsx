@@ -1,8 +1,8 @@
-import { GeneratedEntrypoint, GeneratorContext } from "@project-gauntlet/api/helpers";
+import { GeneratedEntrypoint, GeneratedEntrypointAction, GeneratorContext } from "@project-gauntlet/api/helpers";
 import { walk, WalkOptions } from "@std/fs/walk";
 import { debounce } from "@std/async/debounce";
 import { current_os, wayland } from "gauntlet:bridge/internal-all";
-import { linux_app_from_path, linux_application_dirs, linux_open_application, } from "gauntlet:bridge/internal-linux";
+import { linux_app_from_path, linux_application_dirs, linux_open_application } from "gauntlet:bridge/internal-linux";
 import {
     macos_app_from_arbitrary_path,
     macos_app_from_path,
@@ -16,15 +16,19 @@ import {
     macos_settings_pre_13,
     macos_system_applications
 } from "gauntlet:bridge/internal-macos";
-import { applicationAccessories, applicationActions } from "./window/shared";
+import { applicationAccessories, applicationActions, LinuxApplicationData } from "./window/shared";
 import { applicationEventLoopX11, focusX11Window } from "./window/x11";
 import { applicationEventLoopWayland, focusWaylandWindow } from "./window/wayland";
 import { windows_app_from_path, windows_application_dirs, windows_open_application } from "gauntlet:bridge/internal-windows";
 
-type EntrypointPreferences = { experimentalWindowTracking: boolean, bundleNameLang: "default" | "localized" };
+type EntrypointPreferences = {
+    experimentalWindowTracking: boolean,
+    bundleNameLang: "default" | "localized",
+    terminalCommand: string
+};
 
 export default async function Applications(context: GeneratorContext<object, EntrypointPreferences>): Promise<void | (() => void)> {
-    const { add, remove, get, getAll, entrypointPreferences: { experimentalWindowTracking, bundleNameLang } } = context;
+    const { add, remove, get, getAll, entrypointPreferences: { experimentalWindowTracking, bundleNameLang, terminalCommand } } = context;
 
     switch (current_os()) {
         case "linux": {
@@ -32,42 +36,43 @@ export default async function Applications(context: GeneratorContext<object, Ent
                 linux_application_dirs(),
                 path => linux_app_from_path(path),
                 (id, data) => {
-                    if (wayland()) {
-                        return {
-                            name: data.name,
-                            actions: applicationActions(
-                                id,
-                                experimentalWindowTracking,
-                                () => {
-                                    linux_open_application(id)
-                                },
-                                focusWaylandWindow,
-                            ),
-                            accessories: applicationAccessories(id, experimentalWindowTracking),
-                            icon: data.icon, // TODO lazy icons
-                            "__linux__": {
-                                startupWmClass: data.startup_wm_class,
-                                desktopFilePath: data.desktop_file_path
-                            }
-                        }
-                    } else {
-                        return {
-                            name: data.name,
-                            actions: applicationActions(
-                                id,
-                                experimentalWindowTracking,
-                                () => {
-                                    linux_open_application(id)
-                                },
-                                focusX11Window,
-                            ),
-                            accessories: applicationAccessories(id, experimentalWindowTracking),
-                            icon: data.icon, // TODO lazy icons
-                            "__linux__": {
-                                startupWmClass: data.startup_wm_class,
-                                desktopFilePath: data.desktop_file_path
-                            }
-                        }
+                    const focusWindow = wayland() ? focusWaylandWindow : focusX11Window;
+
+                    const open = (actionId?: string) => {
+                        linux_open_application(id, data.desktop_file_path, actionId, terminalCommand)
+                            .catch(err => console.error(`Unable to open application '${id}'`, err))
+                    };
+
+                    const desktopActions = data.actions.map((action): GeneratedEntrypointAction => ({
+                        label: action.name,
+                        run: () => {
+                            open(action.id)
+                        },
+                    }));
+
+                    const linuxData: LinuxApplicationData = {
+                        startupWmClass: data.startup_wm_class,
+                        desktopFilePath: data.desktop_file_path,
+                        open,
+                        desktopActions,
+                    };
+
+                    return {
+                        name: data.name,
+                        actions: applicationActions(
+                            id,
+                            experimentalWindowTracking,
+                            () => {
+                                open()
+                            },
+                            focusWindow,
+                            desktopActions,
+                        ),
+                        accessories: applicationAccessories(id, experimentalWindowTracking),
+                        icon: data.icon, // TODO lazy icons
+                        keywords: data.generic_name ? [data.generic_name, ...data.keywords] : data.keywords,
+                        isApplication: true,
+                        "__linux__": linuxData
                     }
                 },
                 add,
@@ -134,6 +139,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                             }
                         ],
                         icon: setting.icon,
+                        isApplication: true,
                     })
                 }
             } else {
@@ -149,6 +155,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                             }
                         ],
                         icon: setting.icon,
+                        isApplication: true,
                     })
                 }
             }
@@ -170,6 +177,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                                     }
                                 ],
                                 icon: data.icon,
+                                isApplication: true,
                             })
                             break;
                         }
@@ -193,6 +201,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                         }
                     ],
                     icon: data.icon,
+                    isApplication: true,
                 }),
                 add,
                 remove,
@@ -214,6 +223,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                         }
                     ],
                     icon: data.icon,
+                    isApplication: true,
                 }),
                 add,
                 remove,
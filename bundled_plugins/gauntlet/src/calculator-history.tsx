@@ -0,0 +1,76 @@
+import { Action, ActionPanel, Icons, List, TextAccessory } from "@project-gauntlet/api/components";
+import { ReactElement, useState } from "react";
+import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
+import { numbat_clear_history, numbat_history } from "gauntlet:bridge/internal-all";
+
+export default function CalculatorHistory(): ReactElement {
+    const [query, setQuery] = useState<string>("");
+    // bumped to re-read history after it was cleared
+    const [version, setVersion] = useState(0);
+
+    const entries = numbat_history(query);
+
+    return (
+        <List
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Copy Result"
+                        onAction={async (id: string | undefined) => {
+                            const entry = entries[Number(id)];
+                            if (entry) {
+                                await Clipboard.writeText(entry.right)
+                                showHud("Result copied")
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="copyExpression"
+                        label="Copy Expression"
+                        onAction={async (id: string | undefined) => {
+                            const entry = entries[Number(id)];
+                            if (entry) {
+                                await Clipboard.writeText(entry.input)
+                                showHud("Expression copied")
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="clearHistory"
+                        label="Clear History"
+                        onAction={() => {
+                            numbat_clear_history()
+                            setVersion(version + 1)
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                placeholder="Search calculator history..."
+                value={query}
+                onChange={value => setQuery(value ?? "")}
+            />
+            {
+                entries.map((entry, index) => (
+                    <List.Item
+                        key={`${version}-${index}`}
+                        id={index.toString()}
+                        title={`${entry.left} = ${entry.right}`}
+                        icon={Icons.Calculator}
+                        accessories={[
+                            <TextAccessory text={new Date(entry.created_at * 1000).toLocaleString()}/>
+                        ]}
+                    />
+                ))
+            }
+            {
+                entries.length === 0 && (
+                    <List.EmptyView title={query === "" ? "Calculator history is empty" : "Nothing found"} image={Icons.Calculator}/>
+                )
+            }
+        </List>
+    )
+}
@@ -1,23 +1,38 @@
 import { Action, ActionPanel, Content, Icons, Inline } from "@project-gauntlet/api/components";
 import { ReactNode } from "react";
 import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
-import { run_numbat } from "gauntlet:bridge/internal-all";
+import { numbat_save_history, run_numbat } from "gauntlet:bridge/internal-all";
+
+// expressions starting with this prefix are always treated as calculations, so errors are shown for them
+const EXPLICIT_PREFIX = "=";
 
 export default function Calculator(props: { text: string }): ReactNode | undefined {
-    const text = props.text;
+    const explicit = props.text.startsWith(EXPLICIT_PREFIX);
+    const text = explicit ? props.text.substring(EXPLICIT_PREFIX.length) : props.text;
 
-    if (text.length < 3) {
+    if (text.trim().length < (explicit ? 1 : 3)) {
         return undefined
     }
 
-    let result;
+    const result = run_numbat(text);
 
-    try {
-         result = run_numbat(text);
-    } catch (e) {
+    if (result.type === "error") {
         // this view is executed on every key press in main search bar
-        // when numbat run fails it means expression is not valid so we return here and do not show inline view
-        return undefined
+        // when numbat run fails it usually means text is not an expression at all,
+        // so errors are only shown when user explicitly asked for calculation
+        if (!explicit) {
+            return undefined
+        }
+
+        return (
+            <Inline>
+                <Inline.Center>
+                    <Content.CodeBlock>
+                        {formatError(text, result.message, result.start, result.end)}
+                    </Content.CodeBlock>
+                </Inline.Center>
+            </Inline>
+        )
     }
 
     const { left, right } = result;
@@ -34,6 +49,8 @@ export default function Calculator(props: { text: string }): ReactNode | undefin
                         label={"Copy result"}
                         onAction={async () => {
                             await Clipboard.writeText(right)
+                            // makes result available as `ans` and in calculator history
+                            numbat_save_history(text)
                             showHud("Result copied")
                         }}
                     />
@@ -54,3 +71,13 @@ export default function Calculator(props: { text: string }): ReactNode | undefin
         </Inline>
     )
 }
+
+function formatError(text: string, message: string, start: number | undefined, end: number | undefined): string {
+    if (start === undefined || end === undefined) {
+        return `${text}\n\n${message}`
+    }
+
+    const marker = " ".repeat(start) + "^".repeat(Math.max(end - start, 1));
+
+    return `${text}\n${marker}\n${message}`
+}
@@ -0,0 +1,128 @@
+import { Action, ActionPanel, IconAccessory, Icons, List, TextAccessory } from "@project-gauntlet/api/components";
+import { ReactElement, useState } from "react";
+import { usePromise } from "@project-gauntlet/api/hooks";
+import { pasteToActiveWindow, showHud } from "@project-gauntlet/api/helpers";
+import {
+    clipboard_history_copy,
+    clipboard_history_list,
+    clipboard_history_remove,
+    clipboard_history_set_pinned
+} from "gauntlet:bridge/internal-all";
+
+export default function ClipboardHistory(): ReactElement {
+    const [query, setQuery] = useState<string>("");
+    const [focusedId, setFocusedId] = useState<string | undefined>(undefined);
+
+    const { data: entries, isLoading, revalidate } = usePromise(
+        async (query: string) => await clipboard_history_list(query),
+        [query]
+    );
+
+    const findEntry = (id: string | undefined) => entries?.find(entry => entry.id.toString() === id);
+
+    const focusedEntry = findEntry(focusedId) ?? entries?.[0];
+
+    return (
+        <List
+            isLoading={isLoading}
+            onItemFocusChange={setFocusedId}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Paste to Active Window"
+                        onAction={async (id: string | undefined) => {
+                            const entry = findEntry(id);
+                            if (entry) {
+                                // only text can be pasted, images are copied instead
+                                if (entry.text_data !== undefined) {
+                                    try {
+                                        await pasteToActiveWindow(entry.text_data)
+                                        return { close: true }
+                                    } catch (e) {
+                                        // pasting is not supported on every system, so fall back to clipboard
+                                        console.warn("Unable to paste clipboard history entry", e)
+                                    }
+                                }
+                                await clipboard_history_copy(entry.id)
+                                showHud("Copied to clipboard")
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="copy"
+                        label="Copy to Clipboard"
+                        onAction={async (id: string | undefined) => {
+                            const entry = findEntry(id);
+                            if (entry) {
+                                await clipboard_history_copy(entry.id)
+                                showHud("Copied to clipboard")
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="togglePin"
+                        label={focusedEntry?.pinned ? "Unpin" : "Pin"}
+                        onAction={async (id: string | undefined) => {
+                            const entry = findEntry(id);
+                            if (entry) {
+                                await clipboard_history_set_pinned(entry.id, !entry.pinned)
+                                revalidate()
+                            }
+                        }}
+                    />
+                    <Action
+                        id="delete"
+                        label="Delete"
+                        onAction={async (id: string | undefined) => {
+                            const entry = findEntry(id);
+                            if (entry) {
+                                await clipboard_history_remove(entry.id)
+                                revalidate()
+                            }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                placeholder="Search clipboard history..."
+                value={query}
+                onChange={value => setQuery(value ?? "")}
+            />
+            {
+                (entries ?? []).map(entry => (
+                    <List.Item
+                        key={entry.id}
+                        id={entry.id.toString()}
+                        title={entryTitle(entry)}
+                        icon={entry.text_data === undefined ? Icons.Image : Icons.Text}
+                        accessories={[
+                            ...(entry.pinned ? [<IconAccessory icon={Icons.Star} tooltip="Pinned"/>] : []),
+                            <TextAccessory text={formatTime(entry.created_at)}/>
+                        ]}
+                    />
+                ))
+            }
+            {
+                entries !== undefined && entries.length === 0 && (
+                    <List.EmptyView title={query === "" ? "Clipboard history is empty" : "Nothing found"} image={Icons.Clipboard}/>
+                )
+            }
+        </List>
+    )
+}
+
+function entryTitle(entry: ClipboardHistoryEntry): string {
+    if (entry.text_data !== undefined) {
+        // list item title is a single line
+        return entry.text_data.trim().split("\n")[0]!!
+    }
+
+    return `Image (${entry.image_width ?? "?"}x${entry.image_height ?? "?"})`
+}
+
+function formatTime(createdAt: number): string {
+    return new Date(createdAt * 1000).toLocaleString()
+}
@@ -0,0 +1,109 @@
+import { Action, ActionPanel, Grid } from "@project-gauntlet/api/components";
+import { ReactElement, useState } from "react";
+import { useEntrypointPreferences } from "@project-gauntlet/api/hooks";
+import { Clipboard, pasteToActiveWindow, showHud } from "@project-gauntlet/api/helpers";
+import { emoji_mark_used, emoji_search } from "gauntlet:bridge/internal-all";
+
+type EntrypointPreferences = { skinTone: EmojiSkinTone };
+
+export default function EmojiPicker(): ReactElement {
+    const { skinTone } = useEntrypointPreferences<EntrypointPreferences>();
+
+    const [query, setQuery] = useState<string>("");
+    // bumped to re-read frequently used characters
+    const [version, setVersion] = useState(0);
+
+    const sections = emoji_search(query, skinTone);
+
+    // the same character can be present in multiple sections, so grid item id includes section title
+    const items = new Map<string, CharacterItem>(
+        sections.flatMap(section => section.items.map(item => [itemId(section, item), item] as const))
+    );
+
+    return (
+        <Grid
+            columns={8}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Paste to Active Window"
+                        onAction={async (id: string | undefined) => {
+                            const item = id !== undefined ? items.get(id) : undefined;
+                            if (item) {
+                                emoji_mark_used(item.id)
+                                setVersion(version + 1)
+                                try {
+                                    await pasteToActiveWindow(item.value)
+                                } catch (e) {
+                                    // pasting is not supported on every system, so fall back to clipboard
+                                    console.warn("Unable to paste character", e)
+                                    await Clipboard.writeText(item.value)
+                                    showHud(`Copied ${item.value}`)
+                                }
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="copy"
+                        label="Copy to Clipboard"
+                        onAction={async (id: string | undefined) => {
+                            const item = id !== undefined ? items.get(id) : undefined;
+                            if (item) {
+                                await Clipboard.writeText(item.value)
+                                emoji_mark_used(item.id)
+                                setVersion(version + 1)
+                                showHud(`Copied ${item.value}`)
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="copyName"
+                        label="Copy Name"
+                        onAction={async (id: string | undefined) => {
+                            const item = id !== undefined ? items.get(id) : undefined;
+                            if (item) {
+                                await Clipboard.writeText(item.name)
+                                showHud("Name copied")
+                                return { close: true }
+                            }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Grid.SearchBar
+                placeholder="Search emoji and symbols..."
+                value={query}
+                onChange={value => setQuery(value ?? "")}
+            />
+            {
+                sections.map(section => (
+                    <Grid.Section key={`${version}-${section.title}`} title={section.title}>
+                        {
+                            section.items.map(item => (
+                                <Grid.Item key={itemId(section, item)} id={itemId(section, item)} title={item.name}>
+                                    <Grid.Item.Content>
+                                        <Grid.Item.Content.H1>
+                                            {item.value}
+                                        </Grid.Item.Content.H1>
+                                    </Grid.Item.Content>
+                                </Grid.Item>
+                            ))
+                        }
+                    </Grid.Section>
+                ))
+            }
+            {
+                sections.length === 0 && (
+                    <Grid.EmptyView title="Nothing found"/>
+                )
+            }
+        </Grid>
+    )
+}
+
+function itemId(section: CharacterSection, item: CharacterItem): string {
+    return `${section.title}:${item.id}`
+}
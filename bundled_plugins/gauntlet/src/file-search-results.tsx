@@ -0,0 +1,49 @@
+import { GeneratorContext } from "@project-gauntlet/api/helpers";
+import { file_search } from "gauntlet:bridge/internal-all";
+import { fileActions, FileSearchPreferences, refreshFileIndex } from "./files/shared";
+
+type EntrypointPreferences = { showInMainSearch: boolean, mainSearchLimit: number };
+
+const REFRESH_INTERVAL = 10 * 60 * 1000;
+
+export default async function FileSearchResults(context: GeneratorContext<FileSearchPreferences, EntrypointPreferences>): Promise<void | (() => void)> {
+    const { add, remove, getAll, pluginPreferences, entrypointPreferences: { showInMainSearch, mainSearchLimit } } = context;
+
+    if (!showInMainSearch) {
+        return
+    }
+
+    // only most recently modified files are added to main search to keep search index small
+    const update = async () => {
+        await refreshFileIndex(pluginPreferences)
+
+        const items = file_search("", mainSearchLimit);
+
+        const paths = new Set(items.map(item => item.path));
+
+        for (const id of Object.keys(getAll())) {
+            if (!paths.has(id)) {
+                remove(id)
+            }
+        }
+
+        for (const item of items) {
+            add(item.path, {
+                name: item.name,
+                actions: fileActions(item.path),
+                accessories: [{ text: item.directory ? "Folder" : "File" }]
+            })
+        }
+    };
+
+    await update();
+
+    const interval = setInterval(() => {
+        update()
+            .catch(reason => console.error("Unable to refresh file search results", reason))
+    }, REFRESH_INTERVAL);
+
+    return () => {
+        clearInterval(interval)
+    }
+}
@@ -0,0 +1,75 @@
+import { Action, ActionPanel, Icons, List, TextAccessory } from "@project-gauntlet/api/components";
+import { ReactElement, useState } from "react";
+import { usePluginPreferences, usePromise } from "@project-gauntlet/api/hooks";
+import { file_search } from "gauntlet:bridge/internal-all";
+import { copyFilePath, FileSearchPreferences, openFile, refreshFileIndex, revealFile } from "./files/shared";
+
+const RESULT_LIMIT = 100;
+
+export default function SearchFiles(): ReactElement {
+    const preferences = usePluginPreferences<FileSearchPreferences>();
+
+    const [query, setQuery] = useState<string>("");
+
+    // index persisted in plugin cache dir is used until refresh finishes,
+    // finished refresh re-renders the view with up-to-date results
+    const { isLoading } = usePromise(async () => await refreshFileIndex(preferences));
+
+    const items = file_search(query, RESULT_LIMIT);
+
+    return (
+        <List
+            isLoading={isLoading}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Open"
+                        onAction={(path: string | undefined) => {
+                            if (path) {
+                                openFile(path)
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="reveal"
+                        label="Reveal in File Manager"
+                        onAction={(path: string | undefined) => {
+                            if (path) {
+                                revealFile(path)
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="copyPath"
+                        label="Copy Path"
+                        onAction={async (path: string | undefined) => {
+                            if (path) {
+                                await copyFilePath(path)
+                            }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                placeholder="Search files and folders..."
+                value={query}
+                onChange={value => setQuery(value ?? "")}
+            />
+            {
+                items.map(item => (
+                    <List.Item
+                        key={item.path}
+                        id={item.path}
+                        title={item.name}
+                        subtitle={item.path}
+                        icon={item.directory ? Icons.Folder : Icons.Document}
+                        accessories={[<TextAccessory text={new Date(item.modified * 1000).toLocaleDateString()}/>]}
+                    />
+                ))
+            }
+        </List>
+    )
+}
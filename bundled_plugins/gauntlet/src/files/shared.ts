@@ -0,0 +1,54 @@
+import { Clipboard, GeneratedEntrypointAction, showHud } from "@project-gauntlet/api/helpers";
+import { file_search_open, file_search_refresh, file_search_reveal } from "gauntlet:bridge/internal-all";
+
+export type FileSearchPreferences = {
+    fileSearchRoots: string,
+    fileSearchIgnore: string,
+}
+
+// preferences are single line strings, multiple values are separated by semicolon
+function splitPreference(value: string): string[] {
+    return value
+        .split(";")
+        .map(item => item.trim())
+        .filter(item => item !== "")
+}
+
+export async function refreshFileIndex(preferences: FileSearchPreferences): Promise<void> {
+    await file_search_refresh(
+        splitPreference(preferences.fileSearchRoots),
+        splitPreference(preferences.fileSearchIgnore)
+    )
+}
+
+export function openFile(path: string) {
+    file_search_open(path)
+}
+
+export function revealFile(path: string) {
+    file_search_reveal(path)
+}
+
+export async function copyFilePath(path: string) {
+    await Clipboard.writeText(path)
+    showHud("Path copied")
+}
+
+export function fileActions(path: string): GeneratedEntrypointAction[] {
+    return [
+        {
+            label: "Open",
+            run: () => openFile(path)
+        },
+        {
+            label: "Reveal in File Manager",
+            run: () => revealFile(path)
+        },
+        {
+            label: "Copy Path",
+            run: () => {
+                copyFilePath(path)
+            }
+        }
+    ]
+}
@@ -0,0 +1,34 @@
+import { GeneratorContext } from "@project-gauntlet/api/helpers";
+import { snippets_list } from "gauntlet:bridge/internal-all";
+import { onSnippetsChanged, snippetActions } from "./snippets/shared";
+
+export default async function SnippetSearchResults(context: GeneratorContext): Promise<void | (() => void)> {
+    const { add, remove, getAll } = context;
+
+    const update = async () => {
+        const snippets = await snippets_list("");
+
+        const ids = new Set(snippets.map(snippet => snippet.id.toString()));
+
+        for (const id of Object.keys(getAll())) {
+            if (!ids.has(id)) {
+                remove(id)
+            }
+        }
+
+        for (const snippet of snippets) {
+            add(snippet.id.toString(), {
+                name: snippet.name,
+                actions: snippetActions(snippet),
+                accessories: [{ text: "Snippet" }]
+            })
+        }
+    };
+
+    await update();
+
+    return onSnippetsChanged(() => {
+        update()
+            .catch(reason => console.error("Unable to refresh snippet search results", reason))
+    })
+}
@@ -0,0 +1,149 @@
+import { Action, ActionPanel, Form, Icons, List, TextAccessory } from "@project-gauntlet/api/components";
+import { ReactElement, useState } from "react";
+import { useNavigation, usePromise } from "@project-gauntlet/api/hooks";
+import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
+import { snippet_remove, snippet_save, snippets_list } from "gauntlet:bridge/internal-all";
+import { insertSnippet, notifySnippetsChanged, SnippetFieldsForm, snippetFields } from "./snippets/shared";
+
+export default function Snippets(): ReactElement {
+    const { pushView } = useNavigation();
+
+    const [query, setQuery] = useState<string>("");
+
+    const { data: snippets, isLoading, revalidate } = usePromise(
+        async (query: string) => await snippets_list(query),
+        [query]
+    );
+
+    const findSnippet = (id: string | undefined) => snippets?.find(snippet => snippet.id.toString() === id);
+
+    const onSaved = () => {
+        notifySnippetsChanged()
+        revalidate()
+    };
+
+    return (
+        <List
+            isLoading={isLoading}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Insert Snippet"
+                        onAction={async (id: string | undefined) => {
+                            const snippet = findSnippet(id);
+                            if (snippet) {
+                                if (snippetFields(snippet.text).length > 0) {
+                                    pushView(<SnippetFieldsForm snippet={snippet}/>)
+                                } else {
+                                    await insertSnippet(snippet, {})
+                                    return { close: true }
+                                }
+                            }
+                        }}
+                    />
+                    <Action
+                        id="edit"
+                        label="Edit Snippet"
+                        onAction={(id: string | undefined) => {
+                            const snippet = findSnippet(id);
+                            if (snippet) {
+                                pushView(<SnippetEditForm snippet={snippet} onSaved={onSaved}/>)
+                            }
+                        }}
+                    />
+                    <Action
+                        id="create"
+                        label="Create Snippet"
+                        onAction={() => {
+                            pushView(<SnippetEditForm onSaved={onSaved}/>)
+                        }}
+                    />
+                    <Action
+                        id="createFromClipboard"
+                        label="Create Snippet from Clipboard"
+                        onAction={async () => {
+                            const text = await Clipboard.readText();
+                            if (text) {
+                                pushView(<SnippetEditForm initialText={text} onSaved={onSaved}/>)
+                            } else {
+                                showHud("Clipboard doesn't contain text")
+                            }
+                        }}
+                    />
+                    <Action
+                        id="delete"
+                        label="Delete Snippet"
+                        onAction={async (id: string | undefined) => {
+                            const snippet = findSnippet(id);
+                            if (snippet) {
+                                await snippet_remove(snippet.id)
+                                onSaved()
+                            }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                placeholder="Search snippets..."
+                value={query}
+                onChange={value => setQuery(value ?? "")}
+            />
+            {
+                (snippets ?? []).map(snippet => (
+                    <List.Item
+                        key={snippet.id}
+                        id={snippet.id.toString()}
+                        title={snippet.name}
+                        subtitle={snippet.text.trim().split("\n")[0]}
+                        icon={Icons.Text}
+                        accessories={
+                            snippetFields(snippet.text).length > 0 ? [<TextAccessory text="Has fields"/>] : []
+                        }
+                    />
+                ))
+            }
+            {
+                snippets !== undefined && snippets.length === 0 && (
+                    <List.EmptyView
+                        title={query === "" ? "No snippets yet" : "Nothing found"}
+                        description={query === "" ? "Use \"Create Snippet\" action to add one" : undefined}
+                        image={Icons.Text}
+                    />
+                )
+            }
+        </List>
+    )
+}
+
+function SnippetEditForm(props: { snippet?: Snippet, initialText?: string, onSaved: () => void }): ReactElement {
+    const { popView } = useNavigation();
+
+    const [name, setName] = useState<string>(props.snippet?.name ?? "");
+    const [text, setText] = useState<string>(props.snippet?.text ?? props.initialText ?? "");
+
+    return (
+        <Form
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Save Snippet"
+                        onAction={async () => {
+                            if (name.trim() === "" || text === "") {
+                                showHud("Name and text are required")
+                                return
+                            }
+
+                            await snippet_save(props.snippet?.id, name.trim(), text)
+                            props.onSaved()
+                            popView()
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Form.TextField label="Name" value={name} onChange={value => setName(value ?? "")}/>
+            <Form.TextField label="Text" value={text} onChange={value => setText(value ?? "")}/>
+        </Form>
+    )
+}
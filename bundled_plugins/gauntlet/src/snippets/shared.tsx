@@ -0,0 +1,167 @@
+import { Action, ActionPanel, Form } from "@project-gauntlet/api/components";
+import { ReactElement, useState } from "react";
+import { Clipboard, GeneratedEntrypointAction, pasteToActiveWindow, showHud } from "@project-gauntlet/api/helpers";
+
+// {date}, {time}, {datetime}, {clipboard}, {cursor} and {field:Label}
+const PLACEHOLDER_PATTERN = /\{(date|time|datetime|clipboard|cursor|field:([^{}]+))}/g;
+
+export type ExpandedSnippet = {
+    text: string,
+    // number of characters after {cursor} placeholder
+    cursorOffsetFromEnd: number | undefined,
+}
+
+// labels of custom form fields, in order of first appearance
+export function snippetFields(text: string): string[] {
+    const fields: string[] = [];
+
+    for (const match of text.matchAll(PLACEHOLDER_PATTERN)) {
+        const field = match[2]?.trim();
+        if (field !== undefined && !fields.includes(field)) {
+            fields.push(field)
+        }
+    }
+
+    return fields
+}
+
+export async function expandSnippet(text: string, fieldValues: Record<string, string>): Promise<ExpandedSnippet> {
+    const now = new Date();
+
+    // read only when needed to not read clipboard unnecessarily
+    const clipboard = text.includes("{clipboard}") ? (await Clipboard.readText() ?? "") : "";
+
+    let cursorIndex: number | undefined = undefined;
+
+    let result = "";
+    let lastIndex = 0;
+
+    for (const match of text.matchAll(PLACEHOLDER_PATTERN)) {
+        result += text.substring(lastIndex, match.index);
+        lastIndex = match.index! + match[0].length;
+
+        const placeholder = match[1]!;
+        const field = match[2]?.trim();
+
+        if (field !== undefined) {
+            result += fieldValues[field] ?? ""
+        } else {
+            switch (placeholder) {
+                case "date": {
+                    result += now.toLocaleDateString()
+                    break;
+                }
+                case "time": {
+                    result += now.toLocaleTimeString()
+                    break;
+                }
+                case "datetime": {
+                    result += now.toLocaleString()
+                    break;
+                }
+                case "clipboard": {
+                    result += clipboard
+                    break;
+                }
+                case "cursor": {
+                    // only the first one is used
+                    cursorIndex ??= result.length
+                    break;
+                }
+            }
+        }
+    }
+
+    result += text.substring(lastIndex);
+
+    return {
+        text: result,
+        // counted in code points, because text cursor moves by characters, not UTF-16 code units
+        cursorOffsetFromEnd: cursorIndex === undefined ? undefined : [...result.substring(cursorIndex)].length,
+    }
+}
+
+export async function insertSnippet(snippet: Snippet, fieldValues: Record<string, string>): Promise<void> {
+    const expanded = await expandSnippet(snippet.text, fieldValues);
+
+    try {
+        await pasteToActiveWindow(expanded.text, { cursorOffsetFromEnd: expanded.cursorOffsetFromEnd })
+    } catch (e) {
+        // pasting is not supported on every system, so fall back to clipboard
+        console.warn("Unable to paste snippet", e)
+
+        await Clipboard.writeText(expanded.text)
+        showHud(`Snippet "${snippet.name}" copied`)
+    }
+}
+
+export function SnippetFieldsForm(props: { snippet: Snippet }): ReactElement {
+    const fields = snippetFields(props.snippet.text);
+
+    const [values, setValues] = useState<Record<string, string>>({});
+
+    return (
+        <Form
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Insert Snippet"
+                        onAction={async () => {
+                            await insertSnippet(props.snippet, values)
+                            return { close: true }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            {
+                fields.map(field => (
+                    <Form.TextField
+                        key={field}
+                        label={field}
+                        value={values[field] ?? ""}
+                        onChange={value => setValues({ ...values, [field]: value ?? "" })}
+                    />
+                ))
+            }
+        </Form>
+    )
+}
+
+export function snippetActions(snippet: Snippet): GeneratedEntrypointAction[] {
+    if (snippetFields(snippet.text).length > 0) {
+        return [
+            {
+                label: "Fill In and Insert",
+                view: () => <SnippetFieldsForm snippet={snippet}/>
+            }
+        ]
+    }
+
+    return [
+        {
+            label: "Insert Snippet",
+            run: () => {
+                insertSnippet(snippet, {})
+                    .catch(reason => console.error("Unable to insert snippet", reason))
+            }
+        }
+    ]
+}
+
+// lets main search results know that snippets were changed in "Snippets" view
+const snippetsChangedListeners = new Set<() => void>();
+
+export function onSnippetsChanged(listener: () => void): () => void {
+    snippetsChangedListeners.add(listener)
+
+    return () => {
+        snippetsChangedListeners.delete(listener)
+    }
+}
+
+export function notifySnippetsChanged() {
+    for (const listener of snippetsChangedListeners) {
+        listener()
+    }
+}
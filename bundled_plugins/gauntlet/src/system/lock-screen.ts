@@ -0,0 +1,5 @@
+import { runSystemAction } from "./shared";
+
+export default async function LockScreen(): Promise<void> {
+    await runSystemAction("lock", "Unable to lock screen")
+}
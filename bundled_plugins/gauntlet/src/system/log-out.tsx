@@ -0,0 +1,13 @@
+import { ReactElement } from "react";
+import { ConfirmSystemAction } from "./shared";
+
+export default function LogOut(): ReactElement {
+    return (
+        <ConfirmSystemAction
+            action="log_out"
+            label="Log Out"
+            question="Are you sure you want to log out?"
+            failureMessage="Unable to log out"
+        />
+    )
+}
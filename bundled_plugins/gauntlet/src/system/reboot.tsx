@@ -0,0 +1,13 @@
+import { ReactElement } from "react";
+import { ConfirmSystemAction } from "./shared";
+
+export default function Reboot(): ReactElement {
+    return (
+        <ConfirmSystemAction
+            action="reboot"
+            label="Reboot"
+            question="Are you sure you want to reboot?"
+            failureMessage="Unable to reboot"
+        />
+    )
+}
@@ -0,0 +1,41 @@
+import { Action, ActionPanel, Content, Detail } from "@project-gauntlet/api/components";
+import { ReactElement } from "react";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { linux_system_action } from "gauntlet:bridge/internal-linux";
+
+export async function runSystemAction(action: SystemAction, failureMessage: string): Promise<void> {
+    try {
+        await linux_system_action(action)
+    } catch (e) {
+        console.error(e)
+        showHud(failureMessage)
+    }
+}
+
+// actions which close applications ask for confirmation, because unsaved work may be lost
+export function ConfirmSystemAction(props: { action: SystemAction, label: string, question: string, failureMessage: string }): ReactElement {
+    return (
+        <Detail
+            actions={
+                <ActionPanel>
+                    <Action
+                        label={props.label}
+                        onAction={async () => {
+                            await runSystemAction(props.action, props.failureMessage)
+                            return { close: true }
+                        }}
+                    />
+                </ActionPanel>
+            }
+        >
+            <Detail.Content>
+                <Content.H3>
+                    {props.question}
+                </Content.H3>
+                <Content.Paragraph>
+                    {`Unsaved work in open applications may be lost. Press Enter to ${props.label.toLowerCase()} or Escape to cancel.`}
+                </Content.Paragraph>
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,13 @@
+import { ReactElement } from "react";
+import { ConfirmSystemAction } from "./shared";
+
+export default function ShutDown(): ReactElement {
+    return (
+        <ConfirmSystemAction
+            action="power_off"
+            label="Shut Down"
+            question="Are you sure you want to shut down?"
+            failureMessage="Unable to shut down"
+        />
+    )
+}
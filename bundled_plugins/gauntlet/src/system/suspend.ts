@@ -0,0 +1,5 @@
+import { runSystemAction } from "./shared";
+
+export default async function Suspend(): Promise<void> {
+    await runSystemAction("suspend", "Unable to suspend")
+}
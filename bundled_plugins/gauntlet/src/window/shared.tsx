@@ -3,19 +3,83 @@ import {
     GeneratedEntrypointAccessory,
     GeneratedEntrypointAction,
 } from "@project-gauntlet/api/helpers";
-import { linux_open_application } from "gauntlet:bridge/internal-linux";
-import React from "react";
-import { Action, ActionPanel, List } from "@project-gauntlet/api/components";
+import React, { useState } from "react";
+import { Action, ActionPanel, List, TextAccessory } from "@project-gauntlet/api/components";
+import { usePromise } from "@project-gauntlet/api/hooks";
 
-export function ListOfWindows({ windows, focusWindow }: {
+// linux specific data stored alongside generated entrypoint of application
+export type LinuxApplicationData = {
+    startupWmClass: string | undefined,
+    desktopFilePath: string,
+    open: (actionId?: string) => void,
+    // actions declared in "Desktop Action" groups of .desktop file
+    desktopActions: GeneratedEntrypointAction[],
+}
+
+export function linuxApplicationData(generatedEntrypoint: GeneratedEntrypoint): LinuxApplicationData {
+    return (generatedEntrypoint as any)["__linux__"]
+}
+
+export type WindowManager = {
+    action: (windowId: string, action: WindowAction) => void,
+    // workspaces are only supported on X11, wlr foreign toplevel protocol used on Wayland
+    // doesn't expose workspaces and moving windows between COSMIC workspaces is not implemented
+    workspaces?: {
+        list: () => X11Workspace[],
+        of: (windowId: string) => number | undefined,
+        moveTo: (windowId: string, workspace: number) => void,
+    },
+}
+
+export function ListOfWindows({ windows, focusWindow, windowManager }: {
     windows: Record<string, OpenWindowData>,
-    focusWindow: (windowId: string) => void
+    focusWindow: (windowId: string) => void,
+    windowManager?: WindowManager,
 }) {
+    const [query, setQuery] = useState<string>("");
+    const [appFilter, setAppFilter] = useState<string | undefined>(undefined);
+    const [workspaceFilter, setWorkspaceFilter] = useState<number | undefined>(undefined);
+    // closed windows are hidden right away, without waiting for window manager to destroy them
+    const [closedWindows, setClosedWindows] = useState<string[]>([]);
+
+    // listing workspaces queries window manager, so it is done only once when view is opened
+    const { data: workspaceList } = usePromise(async () => windowManager?.workspaces?.list() ?? []);
+    const workspaces = workspaceList ?? [];
+    const workspaceOf = (windowId: string) => windowManager?.workspaces?.of(windowId);
+
     const knownWindows = readWindowOrder();
 
+    const lowerCaseQuery = query.toLowerCase();
+
     const sortedWindows = Object.keys(windows) // sort windows based on array stored on storage
+        .filter(window => !closedWindows.includes(window))
+        .filter(window => {
+            const windowData = windows[window]!!;
+
+            if (appFilter !== undefined && windowData.appId !== appFilter) {
+                return false
+            }
+
+            // windows shown on all workspaces don't have workspace
+            const workspace = workspaceOf(window);
+            if (workspaceFilter !== undefined && workspace !== undefined && workspace !== workspaceFilter) {
+                return false
+            }
+
+            return windowData.title.toLowerCase().includes(lowerCaseQuery) || windowData.appId.toLowerCase().includes(lowerCaseQuery)
+        })
         .sort((a, b) => knownWindows.indexOf(a) - knownWindows.indexOf(b));
 
+    const windowAction = (action: WindowAction) => (id: string | undefined) => {
+        if (id && windowManager) {
+            windowManager.action(id, action)
+
+            if (action === "close") {
+                setClosedWindows([...closedWindows, id])
+            }
+        }
+    };
+
     return (
         <List
             actions={
@@ -29,11 +93,87 @@ export function ListOfWindows({ windows, focusWindow }: {
                             }
                         }}
                     />
+                    {
+                        windowManager && (
+                            <ActionPanel.Section title="Window">
+                                <Action id="closeWindow" label="Close Window" onAction={windowAction("close")}/>
+                                <Action id="minimizeWindow" label="Minimize Window" onAction={windowAction("minimize")}/>
+                                <Action id="toggleMaximizeWindow" label="Maximize or Restore Window" onAction={windowAction("toggle_maximize")}/>
+                            </ActionPanel.Section>
+                        )
+                    }
+                    {
+                        windowManager?.workspaces && workspaces.length > 1 && (
+                            <ActionPanel.Section title="Move to Workspace">
+                                {
+                                    workspaces.map(workspace => (
+                                        <Action
+                                            key={workspace.index}
+                                            label={`Move to ${workspace.name}`}
+                                            onAction={(id: string | undefined) => {
+                                                if (id) {
+                                                    windowManager?.workspaces?.moveTo(id, workspace.index)
+                                                }
+                                            }}
+                                        />
+                                    ))
+                                }
+                            </ActionPanel.Section>
+                        )
+                    }
+                    <ActionPanel.Section title="Filter">
+                        <Action
+                            id="filterByApp"
+                            label={appFilter === undefined ? "Show Only Windows of This Application" : "Show Windows of All Applications"}
+                            onAction={(id: string | undefined) => {
+                                if (appFilter !== undefined) {
+                                    setAppFilter(undefined)
+                                } else if (id) {
+                                    setAppFilter(windows[id]?.appId)
+                                }
+                            }}
+                        />
+                        {
+                            workspaces.length > 1 && (
+                                <Action
+                                    id="filterByWorkspace"
+                                    label={workspaceFilter === undefined ? "Show Only Windows on This Workspace" : "Show Windows on All Workspaces"}
+                                    onAction={(id: string | undefined) => {
+                                        if (workspaceFilter !== undefined) {
+                                            setWorkspaceFilter(undefined)
+                                        } else if (id) {
+                                            setWorkspaceFilter(workspaceOf(id))
+                                        }
+                                    }}
+                                />
+                            )
+                        }
+                    </ActionPanel.Section>
                 </ActionPanel>
             }
         >
+            <List.SearchBar
+                placeholder="Search windows..."
+                value={query}
+                onChange={value => setQuery(value ?? "")}
+            />
             {
-                sortedWindows.map(window => <List.Item key={window} id={window} title={windows[window]!!.title}/>)
+                sortedWindows.map(window => {
+                    const windowData = windows[window]!!;
+                    const workspace = workspaces.find(workspace => workspace.index === workspaceOf(window));
+
+                    return (
+                        <List.Item
+                            key={window}
+                            id={window}
+                            title={windowData.title}
+                            accessories={[
+                                <TextAccessory text={windowData.appId}/>,
+                                ...(workspace ? [<TextAccessory text={workspace.name}/>] : [])
+                            ]}
+                        />
+                    )
+                })
             }
         </List>
     )
@@ -52,6 +192,19 @@ export function applicationActions(
     experimentalWindowTracking: boolean,
     openApplication: () => void,
     focusWindow: (windowId: string) => void,
+    extraActions: GeneratedEntrypointAction[] = [],
+): GeneratedEntrypointAction[] {
+    return [
+        ...windowTrackingActions(id, experimentalWindowTracking, openApplication, focusWindow),
+        ...extraActions
+    ]
+}
+
+function windowTrackingActions(
+    id: string,
+    experimentalWindowTracking: boolean,
+    openApplication: () => void,
+    focusWindow: (windowId: string) => void,
 ): GeneratedEntrypointAction[] {
     if (!experimentalWindowTracking) {
         return [
@@ -159,7 +312,13 @@ export function addOpenWindow(
 
         add(appId, {
             ...generatedEntrypoint,
-            actions: applicationActions(appId, true, openApplication, focusWindow),
+            actions: applicationActions(
+                appId,
+                true,
+                openApplication,
+                focusWindow,
+                linuxApplicationData(generatedEntrypoint).desktopActions
+            ),
             accessories: applicationAccessories(appId, true)
         })
     }
@@ -167,7 +326,7 @@ export function addOpenWindow(
 
 export function deleteOpenWindow(
     windowId: string,
-    openApplication: (appId: string) => (() => void),
+    openApplication: (generatedEntrypoint: GeneratedEntrypoint) => (() => void),
     focusWindow: (windowId: string) => void,
     get: (id: string) => GeneratedEntrypoint | undefined,
     add: (id: string, data: GeneratedEntrypoint) => void,
@@ -185,16 +344,22 @@ export function deleteOpenWindow(
         if (generatedEntrypoint) {
             add(openWindow.appId, {
                 ...generatedEntrypoint,
-                actions: applicationActions(openWindow.appId, true, openApplication(openWindow.appId), focusWindow),
+                actions: applicationActions(
+                    openWindow.appId,
+                    true,
+                    openApplication(generatedEntrypoint),
+                    focusWindow,
+                    linuxApplicationData(generatedEntrypoint).desktopActions
+                ),
                 accessories: applicationAccessories(openWindow.appId, true)
             })
         }
     }
 }
 
-export function openLinuxApplication(appId: string) {
+export function openLinuxApplication(generatedEntrypoint: GeneratedEntrypoint) {
     return () => {
-        linux_open_application(appId)
+        linuxApplicationData(generatedEntrypoint).open()
     }
 }
 
@@ -1,7 +1,20 @@
-import { addOpenWindow, deleteOpenWindow, openLinuxApplication } from "./shared";
+import {
+    addOpenWindow,
+    deleteOpenWindow,
+    linuxApplicationData,
+    openLinuxApplication,
+    WindowManager
+} from "./shared";
 import { GeneratedEntrypoint } from "@project-gauntlet/api/helpers";
-import { linux_wayland_focus_window, application_wayland_pending_event } from "gauntlet:bridge/internal-linux";
+import {
+    application_wayland_pending_event,
+    linux_wayland_focus_window,
+    linux_wayland_window_action
+} from "gauntlet:bridge/internal-linux";
 
+export const waylandWindowManager: WindowManager = {
+    action: (windowId, action) => linux_wayland_window_action(windowId, action),
+};
 
 export function focusWaylandWindow(windowId: string) {
     linux_wayland_focus_window(windowId)
@@ -105,7 +118,7 @@ function addOpenWindowWayland(
     if (generatedEntrypoint == undefined) {
         const startupWmClassToAppId = Object.fromEntries(
             Object.entries(getAll())
-                .map(([appId, generated]): [string | undefined, string] => [((generated as any)["__linux__"]).startupWmClass, appId])
+                .map(([appId, generated]): [string | undefined, string] => [linuxApplicationData(generated).startupWmClass, appId])
                 .filter((val): val is [string, string]  => {
                     const [wmClass, _appId] = val
                     return wmClass != undefined
@@ -126,7 +139,7 @@ function addOpenWindowWayland(
             generatedEntrypoint,
             windowId,
             windowTitle,
-            openLinuxApplication(appId),
+            openLinuxApplication(generatedEntrypoint),
             focusWindow,
             add,
         )
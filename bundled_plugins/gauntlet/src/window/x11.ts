@@ -1,6 +1,13 @@
 import { GeneratedEntrypoint } from "@project-gauntlet/api/helpers";
-import { addOpenWindow, deleteOpenWindow, openLinuxApplication } from "./shared";
-import { application_x11_pending_event, linux_x11_focus_window } from "gauntlet:bridge/internal-linux";
+import { addOpenWindow, deleteOpenWindow, linuxApplicationData, openLinuxApplication } from "./shared";
+import { WindowManager } from "./shared";
+import {
+    application_x11_pending_event,
+    linux_x11_focus_window,
+    linux_x11_move_window_to_workspace,
+    linux_x11_window_action,
+    linux_x11_workspaces
+} from "gauntlet:bridge/internal-linux";
 
 export type X11WindowData = {
     // x11 window id
@@ -30,6 +37,26 @@ export type X11WindowData = {
     desktopFileName: string | undefined,
 }
 
+// _NET_WM_DESKTOP of windows, filled by application event loop.
+// windows not present here or with undefined value are shown on all workspaces
+const windowWorkspaces: Record<X11WindowId, number | undefined> = {};
+
+export const x11WindowManager: WindowManager = {
+    action: (windowId, action) => linux_x11_window_action(windowId, action),
+    workspaces: {
+        list: () => {
+            try {
+                return linux_x11_workspaces()
+            } catch (e) {
+                // window manager doesn't support workspaces
+                return []
+            }
+        },
+        of: windowId => windowWorkspaces[windowId],
+        moveTo: (windowId, workspace) => linux_x11_move_window_to_workspace(windowId, workspace),
+    },
+}
+
 
 export function focusX11Window(windowId: string) {
     linux_x11_focus_window(windowId)
@@ -85,6 +112,7 @@ export function applicationEventLoopX11(
                 }
                 case "DestroyNotify": {
                     delete windows[applicationEvent.id]
+                    delete windowWorkspaces[applicationEvent.id]
 
                     deleteOpenWindow(applicationEvent.id, openLinuxApplication, focusWindow, get, add)
 
@@ -190,6 +218,13 @@ export function applicationEventLoopX11(
                         validateAndAddOpenWindow(window, windows, openLinuxApplication, focusWindow, add, getAll)
                     }
 
+                    break;
+                }
+                case "WorkspacePropertyNotify": {
+                    if (windows[applicationEvent.id]) {
+                        windowWorkspaces[applicationEvent.id] = applicationEvent.workspace;
+                    }
+
                     break;
                 }
             }
@@ -200,7 +235,7 @@ export function applicationEventLoopX11(
 function validateAndAddOpenWindow(
     window: X11WindowData,
     windows: Record<string, X11WindowData>,
-    openApplication: (appId: string) => (() => void),
+    openApplication: (generatedEntrypoint: GeneratedEntrypoint) => (() => void),
     focusWindow: (windowId: string) => void,
     add: (id: string, data: GeneratedEntrypoint) => void,
     getAll: () => { [id: string]: GeneratedEntrypoint },
@@ -240,7 +275,7 @@ function validateAndAddOpenWindow(
                 generatedEntrypoint,
                 window.id,
                 window.title,
-                openApplication(appId),
+                openApplication(generatedEntrypoint),
                 focusWindow,
                 add,
             )
@@ -257,7 +292,7 @@ function validateAndAddOpenWindow(
 
     const startupWmClassToAppId = Object.fromEntries(
         Object.entries(generated)
-            .map(([appId, generated]): [string | undefined, string] => [((generated as any)["__linux__"]).startupWmClass, appId])
+            .map(([appId, generated]): [string | undefined, string] => [linuxApplicationData(generated).startupWmClass, appId])
             .filter((val): val is [string, string]  => {
                 const [wmClass, _appId] = val
                 return wmClass != undefined
@@ -2,19 +2,27 @@ import React, { ReactElement } from "react";
 import { List } from "@project-gauntlet/api/components";
 import { ListOfWindows, openWindows } from "./window/shared";
 import { current_os, wayland } from "gauntlet:bridge/internal-all";
-import { focusWaylandWindow } from "./window/wayland";
-import { focusX11Window } from "./window/x11";
+import { focusWaylandWindow, waylandWindowManager } from "./window/wayland";
+import { focusX11Window, x11WindowManager } from "./window/x11";
 
 export default function Windows(): ReactElement {
     switch (current_os()) {
         case "linux": {
             if (wayland()) {
                 return (
-                    <ListOfWindows windows={openWindows} focusWindow={(windowId) => focusWaylandWindow(windowId)}/>
+                    <ListOfWindows
+                        windows={openWindows}
+                        focusWindow={(windowId) => focusWaylandWindow(windowId)}
+                        windowManager={waylandWindowManager}
+                    />
                 )
             } else {
                 return (
-                    <ListOfWindows windows={openWindows} focusWindow={(windowId) => focusX11Window(windowId)}/>
+                    <ListOfWindows
+                        windows={openWindows}
+                        focusWindow={(windowId) => focusX11Window(windowId)}
+                        windowManager={x11WindowManager}
+                    />
                 )
             }
         }
@@ -0,0 +1,35 @@
+import { ReactElement } from "react";
+import { ActionPanel, Icons, List } from "@project-gauntlet/api/components";
+import { AlertActionStyle, confirmAlert } from "@project-gauntlet/api/helpers";
+
+export default function View(): ReactElement {
+    return (
+        <List actions={
+            <ActionPanel>
+                <ActionPanel.Action
+                    label="Delete"
+                    onAction={async id => {
+                        const confirmed = await confirmAlert({
+                            title: `Delete "${id}"?`,
+                            message: "This action cannot be undone",
+                            icon: Icons.Trash,
+                            primaryAction: {
+                                title: "Delete",
+                                style: AlertActionStyle.Destructive,
+                            },
+                        });
+
+                        if (confirmed) {
+                            console.log(`Item "${id}" was deleted`)
+                        } else {
+                            console.log(`Deletion of item "${id}" was cancelled`)
+                        }
+                    }}
+                />
+            </ActionPanel>
+        }>
+            <List.Item id={"notes.txt"} title={"notes.txt"}/>
+            <List.Item id={"report.pdf"} title={"report.pdf"}/>
+        </List>
+    )
+}
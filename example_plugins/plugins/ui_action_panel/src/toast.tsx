@@ -0,0 +1,68 @@
+import { ReactElement } from "react";
+import { ActionPanel, List } from "@project-gauntlet/api/components";
+import { showToast, ToastStyle } from "@project-gauntlet/api/helpers";
+
+async function upload(): Promise<void> {
+    const toast = await showToast({
+        style: ToastStyle.Animated,
+        title: "Uploading file",
+        message: "0%",
+    });
+
+    for (let progress = 25; progress <= 100; progress += 25) {
+        await new Promise(resolve => setTimeout(resolve, 1000));
+        toast.message = `${progress}%`;
+    }
+
+    toast.style = ToastStyle.Success;
+    toast.title = "File uploaded";
+    toast.message = undefined;
+    toast.primaryAction = {
+        title: "Open",
+        onAction: () => console.log("Open action was executed"),
+    };
+}
+
+export default function View(): ReactElement {
+    return (
+        <List actions={
+            <ActionPanel>
+                <ActionPanel.Action
+                    label="Show toast"
+                    onAction={async id => {
+                        switch (id) {
+                            case "success": {
+                                await showToast({ title: "Saved" });
+                                break;
+                            }
+                            case "failure": {
+                                await showToast({
+                                    style: ToastStyle.Failure,
+                                    title: "Unable to save",
+                                    message: "Network is unreachable",
+                                    primaryAction: {
+                                        title: "Retry",
+                                        onAction: () => console.log("Retry action was executed"),
+                                    },
+                                    secondaryAction: {
+                                        title: "Copy Error",
+                                        onAction: () => console.log("Copy Error action was executed"),
+                                    },
+                                });
+                                break;
+                            }
+                            case "animated": {
+                                await upload();
+                                break;
+                            }
+                        }
+                    }}
+                />
+            </ActionPanel>
+        }>
+            <List.Item id={"success"} title={"Success"}/>
+            <List.Item id={"failure"} title={"Failure"}/>
+            <List.Item id={"animated"} title={"Animated"}/>
+        </List>
+    )
+}
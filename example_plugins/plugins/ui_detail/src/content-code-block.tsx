@@ -12,7 +12,7 @@ export default function ContentCodeBlock(): ReactNode {
     return (
         <Detail>
             <Detail.Content>
-                <Detail.Content.CodeBlock>
+                <Detail.Content.CodeBlock language="haskell">
                     {code}
                 </Detail.Content.CodeBlock>
             </Detail.Content>
@@ -0,0 +1,36 @@
+import { ReactNode } from "react";
+import { Detail } from "@project-gauntlet/api/components";
+
+const markdown = `\
+# Pizza Margherita
+
+Classic Neapolitan pizza, see [Wikipedia](https://en.wikipedia.org/wiki/Pizza_Margherita) for its history.
+
+## Ingredients
+
+| Ingredient | Amount |
+|------------|--------|
+| Dough      | 250 g  |
+| Tomatoes   | 100 g  |
+| Mozzarella | 125 g  |
+
+## Steps
+
+1. Stretch the dough
+2. Add *crushed* tomatoes and **fresh** mozzarella
+3. Bake at \`450 °C\` for about 90 seconds
+
+> Finish with basil leaves and olive oil
+`
+
+export default function ContentMarkdown(): ReactNode {
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.Markdown>
+                    {markdown}
+                </Detail.Content.Markdown>
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,22 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function FilePickerExample(): ReactElement {
+    return (
+        <Form>
+            <Form.FilePicker
+                label="Death Star plans"
+                onChange={value => {
+                    console.log(`plans: ${value}`)
+                }}
+            />
+            <Form.FilePicker
+                label="Archive"
+                directory
+                onChange={value => {
+                    console.log(`archive: ${value}`)
+                }}
+            />
+        </Form>
+    );
+};
@@ -0,0 +1,18 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function NumberFieldExample(): ReactElement {
+    return (
+        <Form>
+            <Form.NumberField
+                label="Crew size"
+                value={4}
+                min={1}
+                max={10}
+                onChange={value => {
+                    console.log(`crew size: ${value}`)
+                }}
+            />
+        </Form>
+    );
+};
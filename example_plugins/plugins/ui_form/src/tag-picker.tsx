@@ -0,0 +1,21 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function TagPickerExample(): ReactElement {
+    return (
+        <Form>
+            <Form.TagPicker
+                label="Planets"
+                value={["tatooine"]}
+                onChange={value => {
+                    console.log(`planets: ${value.join(", ")}`)
+                }}
+            >
+                <Form.TagPicker.Item value="tatooine">Tatooine</Form.TagPicker.Item>
+                <Form.TagPicker.Item value="naboo">Naboo</Form.TagPicker.Item>
+                <Form.TagPicker.Item value="hoth">Hoth</Form.TagPicker.Item>
+                <Form.TagPicker.Item value="endor">Endor</Form.TagPicker.Item>
+            </Form.TagPicker>
+        </Form>
+    );
+};
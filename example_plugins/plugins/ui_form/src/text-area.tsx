@@ -0,0 +1,16 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function TextAreaExample(): ReactElement {
+    return (
+        <Form>
+            <Form.TextArea
+                label="Opening crawl"
+                markdownPreview
+                onChange={value => {
+                    console.log(`opening crawl: ${value}`)
+                }}
+            />
+        </Form>
+    );
+};
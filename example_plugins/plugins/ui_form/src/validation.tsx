@@ -0,0 +1,38 @@
+import { ReactElement, useState } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function ValidationExample(): ReactElement {
+    const [callsign, setCallsign] = useState<string | undefined>(undefined);
+
+    const callsignError = callsign !== undefined && callsign.includes(" ")
+        ? "Callsign cannot contain spaces"
+        : undefined;
+
+    return (
+        <Form
+            onSubmit={values => {
+                console.log(`pilot: ${values["pilot"]}, callsign: ${values["callsign"]}, crew size: ${values["crew-size"]}`)
+            }}
+        >
+            <Form.TextField
+                id="pilot"
+                label="Pilot"
+                required
+            />
+            <Form.TextField
+                id="callsign"
+                label="Callsign"
+                info="Used in radio communication"
+                error={callsignError}
+                onChange={value => setCallsign(value)}
+            />
+            <Form.NumberField
+                id="crew-size"
+                label="Crew size"
+                value={4}
+                min={1}
+                max={10}
+            />
+        </Form>
+    );
+};
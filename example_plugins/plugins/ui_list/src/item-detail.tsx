@@ -0,0 +1,28 @@
+import { ReactElement } from "react";
+import { List } from "@project-gauntlet/api/components";
+
+const species = [
+    { id: "frozian", name: "Frozian", homeworld: "Froz", diet: "Omnivorous" },
+    { id: "ezaraa", name: "Ezaraa", homeworld: "Ezaraa", diet: "Carnivorous" },
+    { id: "blutopian", name: "Blutopian", homeworld: "Blutopia", diet: "Herbivorous" },
+]
+
+export default function ItemDetailExample(): ReactElement {
+    return (
+        <List>
+            {species.map(item => (
+                <List.Item key={item.id} id={item.id} title={item.name}>
+                    <List.Item.Detail>
+                        <List.Item.Detail.Metadata>
+                            <List.Item.Detail.Metadata.Value label={"Homeworld"}>{item.homeworld}</List.Item.Detail.Metadata.Value>
+                            <List.Item.Detail.Metadata.Value label={"Diet"}>{item.diet}</List.Item.Detail.Metadata.Value>
+                        </List.Item.Detail.Metadata>
+                        <List.Item.Detail.Content>
+                            <List.Item.Detail.Content.H4>{item.name}</List.Item.Detail.Content.H4>
+                        </List.Item.Detail.Content>
+                    </List.Item.Detail>
+                </List.Item>
+            ))}
+        </List>
+    )
+}
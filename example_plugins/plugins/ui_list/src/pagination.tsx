@@ -0,0 +1,48 @@
+import { ReactElement, useEffect, useState } from "react";
+import { List } from "@project-gauntlet/api/components";
+
+const PAGE_SIZE = 50;
+const TOTAL = 500;
+
+function fetchPage(offset: number): Promise<string[]> {
+    return new Promise(resolve => {
+        setTimeout(() => {
+            const end = Math.min(offset + PAGE_SIZE, TOTAL);
+            const page = [];
+            for (let i = offset; i < end; i++) {
+                page.push(`Planet #${i + 1}`)
+            }
+            resolve(page)
+        }, 500)
+    })
+}
+
+export default function PaginationExample(): ReactElement {
+    const [items, setItems] = useState<string[]>([]);
+    const [isLoading, setIsLoading] = useState(false);
+
+    const loadMore = async () => {
+        if (items.length >= TOTAL) {
+            return
+        }
+
+        setIsLoading(true)
+
+        const page = await fetchPage(items.length);
+
+        setItems(prev => [...prev, ...page])
+        setIsLoading(false)
+    };
+
+    useEffect(() => {
+        loadMore()
+    }, []);
+
+    return (
+        <List isLoading={isLoading} onLoadMore={loadMore}>
+            {items.map(item => (
+                <List.Item key={item} id={item} title={item}/>
+            ))}
+        </List>
+    )
+}
@@ -0,0 +1,36 @@
+import { ReactElement, useState } from "react";
+import { Dropdown, List } from "@project-gauntlet/api/components";
+
+const results = [
+    { title: "Disturbances in the Force", era: "old-republic", keywords: ["jedi", "sith"] },
+    { title: "Bounty hunters", era: "empire", keywords: ["guild"] },
+    { title: "Astromech droids", era: "empire", keywords: ["r2-d2"] },
+    { title: "Ahsoka Tano", era: "old-republic", keywords: ["jedi", "clone wars"] },
+    { title: "Mandalorian Culture", era: "new-republic", keywords: ["beskar"] },
+]
+
+export default function SearchBarAccessoryExample(): ReactElement {
+    const [era, setEra] = useState<string>("all");
+
+    return (
+        <List
+            filtering
+            searchBarAccessory={
+                <Dropdown value={era} onChange={setEra}>
+                    <Dropdown.Item value="all">All Eras</Dropdown.Item>
+                    <Dropdown.Item value="old-republic">Old Republic</Dropdown.Item>
+                    <Dropdown.Item value="empire">Empire</Dropdown.Item>
+                    <Dropdown.Item value="new-republic">New Republic</Dropdown.Item>
+                </Dropdown>
+            }
+        >
+            <List.SearchBar placeholder="What knowledge do you seek...?"/>
+            {results
+                .filter(value => era === "all" || value.era === era)
+                .map(value => (
+                    <List.Item id={value.title} title={value.title} keywords={value.keywords}/>
+                ))
+            }
+        </List>
+    )
+}
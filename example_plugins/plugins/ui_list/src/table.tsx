@@ -0,0 +1,38 @@
+import { ReactElement } from "react";
+import { Action, ActionPanel, Icons, Table } from "@project-gauntlet/api/components";
+import { showHud } from "@project-gauntlet/api/helpers";
+
+const planets = [
+    { id: "tatooine", name: "Tatooine", region: "Outer Rim", population: 200000, moons: 3, inhabited: true },
+    { id: "naboo", name: "Naboo", region: "Mid Rim", population: 4500000000, moons: 3, inhabited: true },
+    { id: "hoth", name: "Hoth", region: "Outer Rim", population: 0, moons: 3, inhabited: false },
+    { id: "kamino", name: "Kamino", region: "Wild Space", population: 1000000000, moons: 3, inhabited: true },
+    { id: "mustafar", name: "Mustafar", region: "Outer Rim", population: 20000, moons: 0, inhabited: true },
+    { id: "yavin-4", name: "Yavin 4", region: "Outer Rim", population: 1000, moons: 0, inhabited: true },
+]
+
+export default function TableExample(): ReactElement {
+    return (
+        <Table
+            actions={
+                <ActionPanel>
+                    <Action label="Visit" onAction={id => showHud(`Visiting ${id}`)}/>
+                </ActionPanel>
+            }
+            onSortChange={(columnId, descending) => console.log(`Sorted by ${columnId}, descending: ${descending}`)}
+        >
+            <Table.Column id="name" title="Name" width={2} sortable/>
+            <Table.Column id="region" title="Region" width={2} sortable/>
+            <Table.Column id="population" title="Population" width={2} sortable/>
+            <Table.Column id="moons" title="Moons" sortable/>
+            {planets.map(planet => (
+                <Table.Row id={planet.id}>
+                    <Table.Row.Cell text={planet.name} icon={planet.inhabited ? Icons.Person : undefined}/>
+                    <Table.Row.Cell text={planet.region}/>
+                    <Table.Row.Cell text={String(planet.population)}/>
+                    <Table.Row.Cell text={String(planet.moons)}/>
+                </Table.Row>
+            ))}
+        </Table>
+    )
+}
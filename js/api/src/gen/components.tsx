@@ -68,12 +68,16 @@ declare global {
             ["gauntlet:horizontal_break"]: {};
             ["gauntlet:code_block"]: {
                 children?: StringComponent;
+                language?: string;
+            };
+            ["gauntlet:markdown"]: {
+                children?: StringComponent;
             };
             ["gauntlet:paragraph"]: {
                 children?: StringComponent;
             };
             ["gauntlet:content"]: {
-                children?: ElementComponent<typeof Paragraph | typeof Image | typeof Svg | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+                children?: ElementComponent<typeof Paragraph | typeof Image | typeof Svg | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof Markdown>;
             };
             ["gauntlet:detail"]: {
                 children?: ElementComponent<typeof ActionPanel | typeof Metadata | typeof Content>;
@@ -89,6 +93,20 @@ declare global {
                 value?: string;
                 onChange?: (value: string | undefined) => void;
             };
+            ["gauntlet:text_area"]: {
+                label?: string;
+                value?: string;
+                markdownPreview?: boolean;
+                onChange?: (value: string | undefined) => void;
+            };
+            ["gauntlet:number_field"]: {
+                label?: string;
+                value?: number;
+                min?: number;
+                max?: number;
+                step?: number;
+                onChange?: (value: number | undefined) => void;
+            };
             ["gauntlet:checkbox"]: {
                 label?: string;
                 title?: string;
@@ -110,9 +128,25 @@ declare global {
                 value?: string;
                 onChange?: (value: string | undefined) => void;
             };
+            ["gauntlet:tag_picker_item"]: {
+                children?: StringComponent;
+                value: string;
+            };
+            ["gauntlet:tag_picker"]: {
+                children?: ElementComponent<typeof TagPickerItem>;
+                label?: string;
+                value?: string[];
+                onChange?: (value: string[]) => void;
+            };
+            ["gauntlet:file_picker"]: {
+                label?: string;
+                value?: string;
+                directory?: boolean;
+                onChange?: (value: string | undefined) => void;
+            };
             ["gauntlet:separator"]: {};
             ["gauntlet:form"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof TextArea | typeof NumberField | typeof Checkbox | typeof DatePicker | typeof Select | typeof TagPicker | typeof FilePicker | typeof Separator>;
                 isLoading?: boolean;
             };
             ["gauntlet:inline_separator"]: {
@@ -141,7 +175,7 @@ declare global {
                 onChange?: (value: string | undefined) => void;
             };
             ["gauntlet:list_item"]: {
-                children?: ElementComponent<typeof TextAccessory | typeof IconAccessory>;
+                children?: ElementComponent<typeof TextAccessory | typeof IconAccessory | typeof Detail>;
                 id: string;
                 title: string;
                 subtitle?: string;
@@ -156,6 +190,7 @@ declare global {
                 children?: ElementComponent<typeof ActionPanel | typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
                 isLoading?: boolean;
                 onItemFocusChange?: (itemId: string | undefined) => void;
+                onLoadMore?: () => void;
             };
             ["gauntlet:grid_item"]: {
                 children?: ElementComponent<typeof IconAccessory | typeof Content>;
@@ -174,6 +209,28 @@ declare global {
                 isLoading?: boolean;
                 columns?: number;
                 onItemFocusChange?: (itemId: string | undefined) => void;
+                onLoadMore?: () => void;
+            };
+            ["gauntlet:table_column"]: {
+                id: string;
+                title: string;
+                width?: number;
+                sortable?: boolean;
+            };
+            ["gauntlet:table_cell"]: {
+                children?: ElementComponent<typeof TextAccessory | typeof IconAccessory>;
+                text?: string;
+                icon?: ImageLike;
+            };
+            ["gauntlet:table_row"]: {
+                children?: ElementComponent<typeof TableCell>;
+                id: string;
+            };
+            ["gauntlet:table"]: {
+                children?: ElementComponent<typeof ActionPanel | typeof TableColumn | typeof TableRow | typeof SearchBar | typeof EmptyView>;
+                isLoading?: boolean;
+                onItemFocusChange?: (itemId: string | undefined) => void;
+                onSortChange?: (columnId: string, descending: boolean) => void;
             };
         }
     }
@@ -508,9 +565,16 @@ export const HorizontalBreak: FC = (): ReactNode => {
 };
 export interface CodeBlockProps {
     children?: StringComponent;
+    language?: string;
 }
 export const CodeBlock: FC<CodeBlockProps> = (props: CodeBlockProps): ReactNode => {
-    return <gauntlet:code_block>{props.children}</gauntlet:code_block>;
+    return <gauntlet:code_block language={props.language}>{props.children}</gauntlet:code_block>;
+};
+export interface MarkdownProps {
+    children?: StringComponent;
+}
+export const Markdown: FC<MarkdownProps> = (props: MarkdownProps): ReactNode => {
+    return <gauntlet:markdown>{props.children}</gauntlet:markdown>;
 };
 export interface ParagraphProps {
     children?: StringComponent;
@@ -519,7 +583,7 @@ export const Paragraph: FC<ParagraphProps> = (props: ParagraphProps): ReactNode
     return <gauntlet:paragraph>{props.children}</gauntlet:paragraph>;
 };
 export interface ContentProps {
-    children?: ElementComponent<typeof Paragraph | typeof Image | typeof Svg | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+    children?: ElementComponent<typeof Paragraph | typeof Image | typeof Svg | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof Markdown>;
 }
 export const Content: FC<ContentProps> & {
     Paragraph: typeof Paragraph;
@@ -533,6 +597,7 @@ export const Content: FC<ContentProps> & {
     H6: typeof H6;
     HorizontalBreak: typeof HorizontalBreak;
     CodeBlock: typeof CodeBlock;
+    Markdown: typeof Markdown;
 } = (props: ContentProps): ReactNode => {
     return <gauntlet:content>{props.children}</gauntlet:content>;
 };
@@ -547,6 +612,7 @@ Content.H5 = H5;
 Content.H6 = H6;
 Content.HorizontalBreak = HorizontalBreak;
 Content.CodeBlock = CodeBlock;
+Content.Markdown = Markdown;
 export interface DetailProps {
     children?: ElementComponent<typeof Metadata | typeof Content>;
     isLoading?: boolean;
@@ -562,36 +628,80 @@ Detail.Metadata = Metadata;
 Detail.Content = Content;
 export interface TextFieldProps {
     label?: string;
+    id?: string;
     value?: string;
+    required?: boolean;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const TextField: FC<TextFieldProps> = (props: TextFieldProps): ReactNode => {
-    return <gauntlet:text_field label={props.label} value={props.value} onChange={props.onChange}></gauntlet:text_field>;
+    return <gauntlet:text_field label={props.label} id={props.id} value={props.value} required={props.required} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:text_field>;
 };
 export interface PasswordFieldProps {
     label?: string;
+    id?: string;
     value?: string;
+    required?: boolean;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const PasswordField: FC<PasswordFieldProps> = (props: PasswordFieldProps): ReactNode => {
-    return <gauntlet:password_field label={props.label} value={props.value} onChange={props.onChange}></gauntlet:password_field>;
+    return <gauntlet:password_field label={props.label} id={props.id} value={props.value} required={props.required} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:password_field>;
+};
+export interface TextAreaProps {
+    label?: string;
+    id?: string;
+    value?: string;
+    markdownPreview?: boolean;
+    required?: boolean;
+    error?: string;
+    info?: string;
+    onChange?: (value: string | undefined) => void;
+}
+export const TextArea: FC<TextAreaProps> = (props: TextAreaProps): ReactNode => {
+    return <gauntlet:text_area label={props.label} id={props.id} value={props.value} markdownPreview={props.markdownPreview} required={props.required} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:text_area>;
+};
+export interface NumberFieldProps {
+    label?: string;
+    id?: string;
+    value?: number;
+    min?: number;
+    max?: number;
+    step?: number;
+    required?: boolean;
+    error?: string;
+    info?: string;
+    onChange?: (value: number | undefined) => void;
+}
+export const NumberField: FC<NumberFieldProps> = (props: NumberFieldProps): ReactNode => {
+    return <gauntlet:number_field label={props.label} id={props.id} value={props.value} min={props.min} max={props.max} step={props.step} required={props.required} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:number_field>;
 };
 export interface CheckboxProps {
     label?: string;
+    id?: string;
     title?: string;
     value?: boolean;
+    required?: boolean;
+    error?: string;
+    info?: string;
     onChange?: (value: boolean) => void;
 }
 export const Checkbox: FC<CheckboxProps> = (props: CheckboxProps): ReactNode => {
-    return <gauntlet:checkbox label={props.label} title={props.title} value={props.value} onChange={props.onChange}></gauntlet:checkbox>;
+    return <gauntlet:checkbox label={props.label} id={props.id} title={props.title} value={props.value} required={props.required} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:checkbox>;
 };
 export interface DatePickerProps {
     label?: string;
+    id?: string;
     value?: string;
+    required?: boolean;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const DatePicker: FC<DatePickerProps> = (props: DatePickerProps): ReactNode => {
-    return <gauntlet:date_picker label={props.label} value={props.value} onChange={props.onChange}></gauntlet:date_picker>;
+    return <gauntlet:date_picker label={props.label} id={props.id} value={props.value} required={props.required} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:date_picker>;
 };
 export interface SelectItemProps {
     children?: StringComponent;
@@ -603,38 +713,87 @@ export const SelectItem: FC<SelectItemProps> = (props: SelectItemProps): ReactNo
 export interface SelectProps {
     children?: ElementComponent<typeof SelectItem>;
     label?: string;
+    id?: string;
     value?: string;
+    required?: boolean;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const Select: FC<SelectProps> & {
     Item: typeof SelectItem;
 } = (props: SelectProps): ReactNode => {
-    return <gauntlet:select label={props.label} value={props.value} onChange={props.onChange}>{props.children}</gauntlet:select>;
+    return <gauntlet:select label={props.label} id={props.id} value={props.value} required={props.required} error={props.error} info={props.info} onChange={props.onChange}>{props.children}</gauntlet:select>;
 };
 Select.Item = SelectItem;
+export interface TagPickerItemProps {
+    children?: StringComponent;
+    value: string;
+}
+export const TagPickerItem: FC<TagPickerItemProps> = (props: TagPickerItemProps): ReactNode => {
+    return <gauntlet:tag_picker_item value={props.value}>{props.children}</gauntlet:tag_picker_item>;
+};
+export interface TagPickerProps {
+    children?: ElementComponent<typeof TagPickerItem>;
+    label?: string;
+    id?: string;
+    value?: string[];
+    required?: boolean;
+    error?: string;
+    info?: string;
+    onChange?: (value: string[]) => void;
+}
+export const TagPicker: FC<TagPickerProps> & {
+    Item: typeof TagPickerItem;
+} = (props: TagPickerProps): ReactNode => {
+    return <gauntlet:tag_picker label={props.label} id={props.id} value={props.value} required={props.required} error={props.error} info={props.info} onChange={props.onChange}>{props.children}</gauntlet:tag_picker>;
+};
+TagPicker.Item = TagPickerItem;
+export interface FilePickerProps {
+    label?: string;
+    id?: string;
+    value?: string;
+    directory?: boolean;
+    required?: boolean;
+    error?: string;
+    info?: string;
+    onChange?: (value: string | undefined) => void;
+}
+export const FilePicker: FC<FilePickerProps> = (props: FilePickerProps): ReactNode => {
+    return <gauntlet:file_picker label={props.label} id={props.id} value={props.value} directory={props.directory} required={props.required} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:file_picker>;
+};
 export const Separator: FC = (): ReactNode => {
     return <gauntlet:separator></gauntlet:separator>;
 };
 export interface FormProps {
-    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof TextArea | typeof NumberField | typeof Checkbox | typeof DatePicker | typeof Select | typeof TagPicker | typeof FilePicker | typeof Separator>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
+    onSubmit?: (values: Record<string, string | number | boolean | string[]>) => void;
 }
 export const Form: FC<FormProps> & {
     TextField: typeof TextField;
     PasswordField: typeof PasswordField;
+    TextArea: typeof TextArea;
+    NumberField: typeof NumberField;
     Checkbox: typeof Checkbox;
     DatePicker: typeof DatePicker;
     Select: typeof Select;
+    TagPicker: typeof TagPicker;
+    FilePicker: typeof FilePicker;
     Separator: typeof Separator;
 } = (props: FormProps): ReactNode => {
-    return <gauntlet:form isLoading={props.isLoading}>{props.actions as any}{props.children}</gauntlet:form>;
+    return <gauntlet:form isLoading={props.isLoading} onSubmit={props.onSubmit}>{props.actions as any}{props.children}</gauntlet:form>;
 };
 Form.TextField = TextField;
 Form.PasswordField = PasswordField;
+Form.TextArea = TextArea;
+Form.NumberField = NumberField;
 Form.Checkbox = Checkbox;
 Form.DatePicker = DatePicker;
 Form.Select = Select;
+Form.TagPicker = TagPicker;
+Form.FilePicker = FilePicker;
 Form.Separator = Separator;
 export interface InlineSeparatorProps {
     icon?: Icons;
@@ -689,16 +848,39 @@ export interface SearchBarProps {
 export const SearchBar: FC<SearchBarProps> = (props: SearchBarProps): ReactNode => {
     return <gauntlet:search_bar value={props.value} placeholder={props.placeholder} onChange={props.onChange}></gauntlet:search_bar>;
 };
+export interface DropdownItemProps {
+    children?: StringComponent;
+    value: string;
+}
+export const DropdownItem: FC<DropdownItemProps> = (props: DropdownItemProps): ReactNode => {
+    return <gauntlet:dropdown_item value={props.value}>{props.children}</gauntlet:dropdown_item>;
+};
+export interface DropdownProps {
+    children?: ElementComponent<typeof DropdownItem>;
+    value?: string;
+    onChange?: (value: string) => void;
+}
+export const Dropdown: FC<DropdownProps> & {
+    Item: typeof DropdownItem;
+} = (props: DropdownProps): ReactNode => {
+    return <gauntlet:dropdown value={props.value} onChange={props.onChange}>{props.children}</gauntlet:dropdown>;
+};
+Dropdown.Item = DropdownItem;
 export interface ListItemProps {
+    children?: ElementComponent<typeof Detail>;
     id: string;
     title: string;
     subtitle?: string;
+    keywords?: string[];
     icon?: ImageLike;
     accessories?: (ElementComponent<typeof TextAccessory> | ElementComponent<typeof IconAccessory>)[];
 }
-export const ListItem: FC<ListItemProps> = (props: ListItemProps): ReactNode => {
-    return <gauntlet:list_item id={props.id} title={props.title} subtitle={props.subtitle} icon={props.icon}>{props.accessories as any}</gauntlet:list_item>;
+export const ListItem: FC<ListItemProps> & {
+    Detail: typeof Detail;
+} = (props: ListItemProps): ReactNode => {
+    return <gauntlet:list_item id={props.id} title={props.title} subtitle={props.subtitle} keywords={props.keywords} icon={props.icon}>{props.accessories as any}{props.children}</gauntlet:list_item>;
 };
+ListItem.Detail = Detail;
 export interface ListSectionProps {
     children?: ElementComponent<typeof ListItem>;
     title: string;
@@ -714,7 +896,10 @@ export interface ListProps {
     children?: ElementComponent<typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
     actions?: ElementComponent<typeof ActionPanel>;
     isLoading?: boolean;
+    searchBarAccessory?: ElementComponent<typeof Dropdown>;
+    filtering?: boolean;
     onItemFocusChange?: (itemId: string | undefined) => void;
+    onLoadMore?: () => void;
 }
 export const List: FC<ListProps> & {
     Item: typeof ListItem;
@@ -723,7 +908,7 @@ export const List: FC<ListProps> & {
     EmptyView: typeof EmptyView;
     Detail: typeof Detail;
 } = (props: ListProps): ReactNode => {
-    return <gauntlet:list isLoading={props.isLoading} onItemFocusChange={props.onItemFocusChange}>{props.actions as any}{props.children}</gauntlet:list>;
+    return <gauntlet:list isLoading={props.isLoading} filtering={props.filtering} onItemFocusChange={props.onItemFocusChange} onLoadMore={props.onLoadMore}>{props.actions as any}{props.searchBarAccessory as any}{props.children}</gauntlet:list>;
 };
 List.Item = ListItem;
 List.Section = ListSection;
@@ -735,12 +920,13 @@ export interface GridItemProps {
     id: string;
     title?: string;
     subtitle?: string;
+    keywords?: string[];
     accessory?: ElementComponent<typeof IconAccessory>;
 }
 export const GridItem: FC<GridItemProps> & {
     Content: typeof Content;
 } = (props: GridItemProps): ReactNode => {
-    return <gauntlet:grid_item id={props.id} title={props.title} subtitle={props.subtitle}>{props.accessory as any}{props.children}</gauntlet:grid_item>;
+    return <gauntlet:grid_item id={props.id} title={props.title} subtitle={props.subtitle} keywords={props.keywords}>{props.accessory as any}{props.children}</gauntlet:grid_item>;
 };
 GridItem.Content = Content;
 export interface GridSectionProps {
@@ -760,7 +946,10 @@ export interface GridProps {
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
     columns?: number;
+    searchBarAccessory?: ElementComponent<typeof Dropdown>;
+    filtering?: boolean;
     onItemFocusChange?: (itemId: string | undefined) => void;
+    onLoadMore?: () => void;
 }
 export const Grid: FC<GridProps> & {
     Item: typeof GridItem;
@@ -768,9 +957,55 @@ export const Grid: FC<GridProps> & {
     SearchBar: typeof SearchBar;
     EmptyView: typeof EmptyView;
 } = (props: GridProps): ReactNode => {
-    return <gauntlet:grid isLoading={props.isLoading} columns={props.columns} onItemFocusChange={props.onItemFocusChange}>{props.actions as any}{props.children}</gauntlet:grid>;
+    return <gauntlet:grid isLoading={props.isLoading} columns={props.columns} filtering={props.filtering} onItemFocusChange={props.onItemFocusChange} onLoadMore={props.onLoadMore}>{props.actions as any}{props.searchBarAccessory as any}{props.children}</gauntlet:grid>;
 };
 Grid.Item = GridItem;
 Grid.Section = GridSection;
 Grid.SearchBar = SearchBar;
 Grid.EmptyView = EmptyView;
+export interface TableColumnProps {
+    id: string;
+    title: string;
+    width?: number;
+    sortable?: boolean;
+}
+export const TableColumn: FC<TableColumnProps> = (props: TableColumnProps): ReactNode => {
+    return <gauntlet:table_column id={props.id} title={props.title} width={props.width} sortable={props.sortable}></gauntlet:table_column>;
+};
+export interface TableCellProps {
+    text?: string;
+    icon?: ImageLike;
+    accessories?: (ElementComponent<typeof TextAccessory> | ElementComponent<typeof IconAccessory>)[];
+}
+export const TableCell: FC<TableCellProps> = (props: TableCellProps): ReactNode => {
+    return <gauntlet:table_cell text={props.text} icon={props.icon}>{props.accessories as any}</gauntlet:table_cell>;
+};
+export interface TableRowProps {
+    children?: ElementComponent<typeof TableCell>;
+    id: string;
+}
+export const TableRow: FC<TableRowProps> & {
+    Cell: typeof TableCell;
+} = (props: TableRowProps): ReactNode => {
+    return <gauntlet:table_row id={props.id}>{props.children}</gauntlet:table_row>;
+};
+TableRow.Cell = TableCell;
+export interface TableProps {
+    children?: ElementComponent<typeof TableColumn | typeof TableRow | typeof SearchBar | typeof EmptyView>;
+    actions?: ElementComponent<typeof ActionPanel>;
+    isLoading?: boolean;
+    onItemFocusChange?: (itemId: string | undefined) => void;
+    onSortChange?: (columnId: string, descending: boolean) => void;
+}
+export const Table: FC<TableProps> & {
+    Column: typeof TableColumn;
+    Row: typeof TableRow;
+    SearchBar: typeof SearchBar;
+    EmptyView: typeof EmptyView;
+} = (props: TableProps): ReactNode => {
+    return <gauntlet:table isLoading={props.isLoading} onItemFocusChange={props.onItemFocusChange} onSortChange={props.onSortChange}>{props.actions as any}{props.children}</gauntlet:table>;
+};
+Table.Column = TableColumn;
+Table.Row = TableRow;
+Table.SearchBar = SearchBar;
+Table.EmptyView = EmptyView;
@@ -1,5 +1,5 @@
 // @ts-ignore TODO how to add declaration for this?
-import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow } from "ext:gauntlet/renderer.js";
+import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow, showToastWindow, showConfirmAlertWindow } from "ext:gauntlet/renderer.js";
 import {
     clipboard_clear,
     clipboard_read,
@@ -9,9 +9,11 @@ import {
     environment_gauntlet_version,
     environment_is_development,
     environment_plugin_cache_dir,
-    environment_plugin_data_dir
+    environment_plugin_data_dir,
+    paste_to_active_window
 } from "ext:core/ops";
 import type { FC } from "react";
+import type { Icons } from "./gen/components";
 
 export function assetDataSync(path: string): ArrayBuffer {
     return getAssetDataSync(path)
@@ -25,11 +27,91 @@ export function showHud(display: string): void {
     return showHudWindow(display)
 }
 
+export enum ToastStyle {
+    Success = "Success",
+    Failure = "Failure",
+    // shows loading bar, stays visible until style is changed or toast is hidden
+    Animated = "Animated",
+}
+
+export interface ToastAction {
+    title: string
+    onAction: () => void | Promise<void>
+}
+
+export interface ToastOptions {
+    title: string
+    message?: string
+    // defaults to ToastStyle.Success
+    style?: ToastStyle
+    primaryAction?: ToastAction
+    secondaryAction?: ToastAction
+}
+
+// assigning any of the properties updates toast that is currently shown
+export interface Toast {
+    title: string
+    message: string | undefined
+    style: ToastStyle
+    primaryAction: ToastAction | undefined
+    secondaryAction: ToastAction | undefined
+    hide(): Promise<void>
+}
+
+// shows toast at the bottom of Gauntlet window, unlike hud it doesn't close the window.
+// success and failure toasts are hidden after a few seconds
+export async function showToast(options: ToastOptions): Promise<Toast> {
+    return await showToastWindow(options)
+}
+
+export enum AlertActionStyle {
+    Default = "Default",
+    // shows action in red, for actions that can't be undone like deleting
+    Destructive = "Destructive",
+}
+
+export interface ConfirmAlertOptions {
+    title: string
+    message?: string
+    icon?: Icons
+    // defaults to "Confirm" with AlertActionStyle.Default
+    primaryAction?: {
+        title: string
+        style?: AlertActionStyle
+    }
+    // defaults to "Cancel"
+    dismissAction?: {
+        title: string
+    }
+}
+
+// shows modal dialog over Gauntlet window, resolves to true if primary action was chosen
+// and to false if alert was dismissed
+export async function confirmAlert(options: ConfirmAlertOptions): Promise<boolean> {
+    return await showConfirmAlertWindow(options)
+}
+
+export interface PasteOptions {
+    // number of characters from the end of pasted text to move text cursor to after pasting
+    cursorOffsetFromEnd?: number
+}
+
+// closes Gauntlet window and pastes text into window that was focused before it was opened.
+// clipboard is used to transfer text, its original content is restored after pasting.
+// requires "write" clipboard permission, currently only supported on X11 and wlroots-based Wayland compositors
+export async function pasteToActiveWindow(text: string, options?: PasteOptions): Promise<void> {
+    return await paste_to_active_window(text, options?.cursorOffsetFromEnd ?? 0)
+}
+
 export interface GeneratedEntrypoint {
     name: string
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    // additional words the entrypoint can be found by in search
+    keywords?: string[]
+    // entrypoint represents an installed application and is shown in "Applications" section of search results
+    isApplication?: boolean
 }
 
 export type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
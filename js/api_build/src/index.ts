@@ -676,6 +676,12 @@ function makeType(type: PropertyType): ts.TypeNode {
         case "array": {
             return ts.factory.createArrayTypeNode(makeType(type.item))
         }
+        case "record": {
+            return ts.factory.createTypeReferenceNode(
+                ts.factory.createIdentifier("Record"),
+                [ts.factory.createKeywordTypeNode(ts.SyntaxKind.StringKeyword), makeType(type.item)]
+            )
+        }
         case "shared_type_ref": {
             return ts.factory.createTypeReferenceNode(
                 ts.factory.createIdentifier(type.name),
@@ -712,6 +718,9 @@ function isInProperty(propertyType: PropertyType) {
         case "array": {
             return isInProperty(propertyType.item)
         }
+        case "record": {
+            return isInProperty(propertyType.item)
+        }
         case "shared_type_ref": {
             return true
         }
@@ -750,6 +759,9 @@ function collectAllComponentRefs(propertyType: PropertyType): ComponentRef[] {
         case "array": {
             return collectAllComponentRefs(propertyType.item)
         }
+        case "record": {
+            return collectAllComponentRefs(propertyType.item)
+        }
         case "shared_type_ref": {
             return []
         }
@@ -2,6 +2,7 @@ import type { FC } from "react";
 import { runEntrypointGenerators, runGeneratedEntrypoint, runGeneratedEntrypointAction } from "./entrypoint-generator";
 import { reloadSearchIndex } from "./search-index";
 import { closeView, handleEvent, handlePluginViewKeyboardEvent, renderInlineView, renderView } from "./render";
+import { handleConfirmAlert, handleToastAction } from "ext:gauntlet/renderer.js";
 import {
     entrypoint_preferences_required,
     get_entrypoint_preferences,
@@ -141,6 +142,22 @@ export async function runPluginLoop() {
                 }
                 break;
             }
+            case "ToastActionEvent": {
+                try {
+                    handleToastAction(pluginEvent.toastId, pluginEvent.actionIndex)
+                } catch (e) {
+                    console.error("Error occurred when handling toast action", e)
+                }
+                break;
+            }
+            case "ConfirmAlertEvent": {
+                try {
+                    handleConfirmAlert(pluginEvent.alertId, pluginEvent.confirmed)
+                } catch (e) {
+                    console.error("Error occurred when handling confirm alert", e)
+                }
+                break;
+            }
             case "RefreshSearchIndex": {
                 // noinspection ES6MissingAwait
                 reloadSearchIndex(false)
@@ -16,6 +16,10 @@ interface GeneratedEntrypoint { // TODO is it possible to import api here
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    // additional words the entrypoint can be found by in search
+    keywords?: string[]
+    // entrypoint represents an installed application and is shown in "Applications" section of search results
+    isApplication?: boolean
 }
 
 type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
@@ -211,7 +215,9 @@ export function generatedEntrypointSearchIndex(): GeneratedSearchItem[] {
                 action_type: action.type,
                 label: action.label
             })),
-        entrypoint_accessories: value.command.accessories || []
+        entrypoint_accessories: value.command.accessories || [],
+        entrypoint_keywords: value.command.keywords || [],
+        entrypoint_application: value.command.isApplication || false
     }))
 }
 
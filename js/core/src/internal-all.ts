@@ -1,6 +1,22 @@
 export {
     run_numbat,
+    numbat_save_history,
+    numbat_history,
+    numbat_clear_history,
+    emoji_search,
+    emoji_mark_used,
     open_settings,
     current_os,
     wayland,
+    clipboard_history_list,
+    clipboard_history_copy,
+    clipboard_history_set_pinned,
+    clipboard_history_remove,
+    snippets_list,
+    snippet_save,
+    snippet_remove,
+    file_search_refresh,
+    file_search,
+    file_search_open,
+    file_search_reveal,
 } from "ext:core/ops";
@@ -4,6 +4,11 @@ export {
     linux_open_application,
     linux_x11_focus_window,
     linux_wayland_focus_window,
+    linux_x11_window_action,
+    linux_x11_move_window_to_workspace,
+    linux_x11_workspaces,
+    linux_wayland_window_action,
     application_x11_pending_event,
     application_wayland_pending_event,
+    linux_system_action,
 } from "ext:core/ops";
@@ -64,6 +64,31 @@ function findAllActionHandlers(widget: UiWidget): { id: string, onAction: () =>
     return result;
 }
 
+function convertPropertyValue(arg: PropertyValue): any {
+    switch (arg.type) {
+        case "Undefined": {
+            return undefined
+        }
+        case "String": {
+            return arg.value
+        }
+        case "Number": {
+            return arg.value
+        }
+        case "Bool": {
+            return arg.value
+        }
+        case "Array": {
+            return arg.value.map(item => convertPropertyValue(item))
+        }
+        case "Object": {
+            return Object.fromEntries(
+                Object.entries(arg.value).map(([key, value]) => [key, convertPropertyValue(value)])
+            )
+        }
+    }
+}
+
 export function handleEvent(event: ViewEvent) {
     op_log_trace("plugin_event_handler", `Handling view event: ${Deno.inspect(event)}`);
     op_log_trace("plugin_event_handler", `Root widget: ${Deno.inspect(latestRootUiWidget)}`);
@@ -80,22 +105,7 @@ export function handleEvent(event: ViewEvent) {
                 if (typeof property === "function") {
 
                     const eventArgs = event.eventArguments
-                        .map(arg => {
-                            switch (arg.type) {
-                                case "Undefined": {
-                                    return undefined
-                                }
-                                case "String": {
-                                    return arg.value
-                                }
-                                case "Number": {
-                                    return arg.value
-                                }
-                                case "Bool": {
-                                    return arg.value
-                                }
-                            }
-                        });
+                        .map(arg => convertPropertyValue(arg));
 
                     op_log_trace("plugin_event_handler", `Calling handler with arguments ${Deno.inspect(eventArgs)}`);
 
@@ -3,6 +3,8 @@ declare module "ext:gauntlet/renderer.js" {
 
     export const render: (entrypointId: string, entrypointName: string, renderLocation: RenderLocation, component: ReactNode) => UiWidget;
     export const clearRenderer: () => void;
+    export const handleToastAction: (toastId: number, actionIndex: number) => void;
+    export const handleConfirmAlert: (alertId: number, confirmed: boolean) => void;
 }
 
 declare module "gauntlet:core" {
@@ -9,7 +9,10 @@ import {
     op_component_model,
     op_log_trace,
     op_react_replace_view,
-    show_hud
+    show_hud,
+    show_toast,
+    hide_toast,
+    show_confirm_alert
 } from "ext:core/ops";
 
 // Usage of MessageChannel seems to block Deno runtime from exiting
@@ -136,6 +139,188 @@ export function showHudWindow(display: string): void {
     show_hud(display)
 }
 
+type ToastStyle = "Success" | "Failure" | "Animated"
+
+interface ToastAction {
+    title: string
+    onAction: () => void | Promise<void>
+}
+
+interface ToastOptions {
+    title: string
+    message?: string
+    style?: ToastStyle
+    primaryAction?: ToastAction
+    secondaryAction?: ToastAction
+}
+
+// window shows only one toast at a time, so only actions of the last shown toast can be called
+let currentToast: ToastHandle | undefined = undefined;
+let nextToastId = 0;
+
+class ToastHandle {
+    readonly #id: number;
+    #options: ToastOptions;
+
+    constructor(id: number, options: ToastOptions) {
+        this.#id = id;
+        this.#options = options;
+    }
+
+    get id(): number {
+        return this.#id
+    }
+
+    get title(): string {
+        return this.#options.title
+    }
+
+    set title(title: string) {
+        this.#options.title = title;
+        this.#update()
+    }
+
+    get message(): string | undefined {
+        return this.#options.message
+    }
+
+    set message(message: string | undefined) {
+        this.#options.message = message;
+        this.#update()
+    }
+
+    get style(): ToastStyle {
+        return this.#options.style ?? "Success"
+    }
+
+    set style(style: ToastStyle) {
+        this.#options.style = style;
+        this.#update()
+    }
+
+    get primaryAction(): ToastAction | undefined {
+        return this.#options.primaryAction
+    }
+
+    set primaryAction(action: ToastAction | undefined) {
+        this.#options.primaryAction = action;
+        this.#update()
+    }
+
+    get secondaryAction(): ToastAction | undefined {
+        return this.#options.secondaryAction
+    }
+
+    set secondaryAction(action: ToastAction | undefined) {
+        this.#options.secondaryAction = action;
+        this.#update()
+    }
+
+    // setters can't be awaited, so errors are only logged
+    #update(): void {
+        this.show()
+            .catch(e => console.error("Error occurred when updating toast", e))
+    }
+
+    async show(): Promise<void> {
+        currentToast = this;
+
+        await show_toast({
+            id: this.#id,
+            style: this.style,
+            title: this.#options.title,
+            message: this.#options.message,
+            primary_action: this.#options.primaryAction?.title,
+            secondary_action: this.#options.secondaryAction?.title,
+        })
+    }
+
+    async hide(): Promise<void> {
+        if (currentToast === this) {
+            currentToast = undefined
+        }
+
+        await hide_toast(this.#id)
+    }
+}
+
+export async function showToastWindow(options: ToastOptions): Promise<ToastHandle> {
+    const toast = new ToastHandle(nextToastId++, { ...options });
+
+    await toast.show();
+
+    return toast
+}
+
+export function handleToastAction(toastId: number, actionIndex: number): void {
+    if (currentToast === undefined || currentToast.id !== toastId) {
+        return
+    }
+
+    const action = actionIndex === 0 ? currentToast.primaryAction : currentToast.secondaryAction;
+
+    if (action === undefined) {
+        return
+    }
+
+    Promise.resolve()
+        .then(() => action.onAction())
+        .catch(e => console.error("Error occurred when running toast action", e))
+}
+
+type ConfirmAlertActionStyle = "Default" | "Destructive"
+
+interface ConfirmAlertOptions {
+    title: string
+    message?: string
+    icon?: string
+    primaryAction?: {
+        title: string
+        style?: ConfirmAlertActionStyle
+    }
+    dismissAction?: {
+        title: string
+    }
+}
+
+const pendingConfirmAlerts = new Map<number, (confirmed: boolean) => void>();
+let nextConfirmAlertId = 0;
+
+export async function showConfirmAlertWindow(options: ConfirmAlertOptions): Promise<boolean> {
+    const id = nextConfirmAlertId++;
+
+    const result = new Promise<boolean>(resolve => pendingConfirmAlerts.set(id, resolve));
+
+    try {
+        await show_confirm_alert({
+            id,
+            title: options.title,
+            message: options.message,
+            icon: options.icon,
+            primary_action: options.primaryAction?.title ?? "Confirm",
+            primary_action_style: options.primaryAction?.style ?? "Default",
+            dismiss_action: options.dismissAction?.title ?? "Cancel",
+        })
+    } catch (e) {
+        pendingConfirmAlerts.delete(id)
+        throw e
+    }
+
+    return await result
+}
+
+export function handleConfirmAlert(alertId: number, confirmed: boolean): void {
+    const resolve = pendingConfirmAlerts.get(alertId);
+
+    if (resolve === undefined) {
+        return
+    }
+
+    pendingConfirmAlerts.delete(alertId)
+
+    resolve(confirmed)
+}
+
 function createWidget(id: number | undefined, hostContext: HostContext, type: ComponentType, properties: Props, children: UiWidget[]): Instance {
     const props = Object.fromEntries(
         Object.entries(properties)
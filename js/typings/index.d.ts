@@ -19,6 +19,14 @@ type LinuxDesktopApplicationData = {
     icon: ArrayBuffer | undefined,
     desktop_file_path: string,
     startup_wm_class: string | undefined,
+    generic_name: string | undefined,
+    keywords: string[],
+    actions: LinuxDesktopApplicationAction[],
+}
+
+type LinuxDesktopApplicationAction = {
+    id: string
+    name: string
 }
 
 type MacOSDesktopApplicationData = {
@@ -45,7 +53,7 @@ type MacOSDesktopSettings13AndPostData = {
     icon: ArrayBuffer | undefined,
 }
 
-type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | OpenInlineView | RefreshSearchIndex
+type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | OpenInlineView | RefreshSearchIndex | ToastActionEvent | ConfirmAlertEvent
 type RenderLocation = "InlineView" | "View"
 
 type ViewEvent = {
@@ -98,10 +106,43 @@ type RefreshSearchIndex = {
     type: "RefreshSearchIndex"
 }
 
-type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueUndefined
+type ToastActionEvent = {
+    type: "ToastActionEvent"
+    toastId: number
+    actionIndex: number
+}
+
+type ToastData = {
+    id: number
+    style: "Success" | "Failure" | "Animated"
+    title: string
+    message: string | undefined
+    primary_action: string | undefined
+    secondary_action: string | undefined
+}
+
+type ConfirmAlertEvent = {
+    type: "ConfirmAlertEvent"
+    alertId: number
+    confirmed: boolean
+}
+
+type ConfirmAlertData = {
+    id: number
+    title: string
+    message: string | undefined
+    icon: string | undefined
+    primary_action: string
+    primary_action_style: "Default" | "Destructive"
+    dismiss_action: string
+}
+
+type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueArray | PropertyValueObject | PropertyValueUndefined
 type PropertyValueString = { type: "String", value: string }
 type PropertyValueNumber = { type: "Number", value: number }
 type PropertyValueBool = { type: "Bool", value: boolean }
+type PropertyValueArray = { type: "Array", value: PropertyValue[] }
+type PropertyValueObject = { type: "Object", value: Record<string, PropertyValue> }
 type PropertyValueUndefined = { type: "Undefined" }
 
 type UiWidget = {
@@ -134,6 +175,8 @@ type GeneratedSearchItem = {
     entrypoint_icon: ArrayBuffer | undefined,
     entrypoint_actions: GeneratedSearchItemAction[],
     entrypoint_accessories: GeneratedEntrypointAccessory[],
+    entrypoint_keywords: string[],
+    entrypoint_application: boolean,
 }
 
 type GeneratedSearchItemAction = {
@@ -142,21 +185,99 @@ type GeneratedSearchItemAction = {
     label: string,
 }
 
+type ClipboardHistoryEntry = {
+    id: number,
+    text_data: string | undefined,
+    image_width: number | undefined,
+    image_height: number | undefined,
+    pinned: boolean,
+    created_at: number,
+}
+
+type Snippet = {
+    id: number,
+    name: string,
+    text: string,
+    updated_at: number,
+}
+
+type EmojiSkinTone = "default" | "light" | "medium_light" | "medium" | "medium_dark" | "dark"
+
+type CharacterSection = {
+    title: string,
+    items: CharacterItem[],
+}
+
+type CharacterItem = {
+    id: string,
+    value: string,
+    name: string,
+    shortcodes: string[],
+}
+
+type SystemAction = "lock" | "log_out" | "suspend" | "reboot" | "power_off"
+
+type NumbatResult = {
+    type: "value",
+    left: string,
+    right: string,
+} | {
+    type: "error",
+    message: string,
+    start: number | undefined,
+    end: number | undefined,
+}
+
+type CalculatorHistoryEntry = {
+    input: string,
+    left: string,
+    right: string,
+    created_at: number,
+}
+
+type FileSearchItem = {
+    path: string,
+    name: string,
+    directory: boolean,
+    modified: number,
+}
+
 declare module "gauntlet:bridge/internal-all" {
     function open_settings(): void
-    function run_numbat(input: string): { left: string, right: string }
+    function run_numbat(input: string): NumbatResult
+    function numbat_save_history(input: string): NumbatResult
+    function numbat_history(query: string): CalculatorHistoryEntry[]
+    function numbat_clear_history(): void
+    function emoji_search(query: string, skin_tone: EmojiSkinTone): CharacterSection[]
+    function emoji_mark_used(id: string): void
     function current_os(): string
     function wayland(): boolean
+    function clipboard_history_list(query: string): Promise<ClipboardHistoryEntry[]>
+    function clipboard_history_copy(id: number): Promise<void>
+    function clipboard_history_set_pinned(id: number, pinned: boolean): Promise<void>
+    function clipboard_history_remove(id: number): Promise<void>
+    function snippets_list(query: string): Promise<Snippet[]>
+    function snippet_save(id: number | undefined, name: string, text: string): Promise<number>
+    function snippet_remove(id: number): Promise<void>
+    function file_search_refresh(roots: string[], ignore: string[]): Promise<void>
+    function file_search(query: string, limit: number): FileSearchItem[]
+    function file_search_open(path: string): void
+    function file_search_reveal(path: string): void
 }
 
 declare module "gauntlet:bridge/internal-linux" {
-    function linux_open_application(desktop_id: string): void
+    function linux_open_application(desktop_id: string, desktop_file_path: string, action_id: string | undefined, terminal_command: string | undefined): Promise<void>
     function linux_x11_focus_window(window_id: string): void
     function linux_wayland_focus_window(window_id: string): void
+    function linux_x11_window_action(window_id: string, action: WindowAction): void
+    function linux_x11_move_window_to_workspace(window_id: string, workspace: number): void
+    function linux_x11_workspaces(): X11Workspace[]
+    function linux_wayland_window_action(window_id: string, action: WindowAction): void
     function linux_application_dirs(): string[]
     function linux_app_from_path(path: string): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
     function application_x11_pending_event(): Promise<X11ApplicationEvent>
     function application_wayland_pending_event(): Promise<WaylandApplicationEvent>
+    function linux_system_action(action: SystemAction): Promise<void>
 }
 
 declare module "gauntlet:bridge/internal-macos" {
@@ -182,16 +303,37 @@ declare module "gauntlet:bridge/internal-windows" {
 
 declare module "ext:core/ops" {
     function open_settings(): void
-    function run_numbat(input: string): { left: string, right: string }
+    function run_numbat(input: string): NumbatResult
+    function numbat_save_history(input: string): NumbatResult
+    function numbat_history(query: string): CalculatorHistoryEntry[]
+    function numbat_clear_history(): void
+    function emoji_search(query: string, skin_tone: EmojiSkinTone): CharacterSection[]
+    function emoji_mark_used(id: string): void
 
     function current_os(): string
     function wayland(): boolean
+    function clipboard_history_list(query: string): Promise<ClipboardHistoryEntry[]>
+    function clipboard_history_copy(id: number): Promise<void>
+    function clipboard_history_set_pinned(id: number, pinned: boolean): Promise<void>
+    function clipboard_history_remove(id: number): Promise<void>
+    function snippets_list(query: string): Promise<Snippet[]>
+    function snippet_save(id: number | undefined, name: string, text: string): Promise<number>
+    function snippet_remove(id: number): Promise<void>
+    function file_search_refresh(roots: string[], ignore: string[]): Promise<void>
+    function file_search(query: string, limit: number): FileSearchItem[]
+    function file_search_open(path: string): void
+    function file_search_reveal(path: string): void
     function application_x11_pending_event(): Promise<X11ApplicationEvent>
     function application_wayland_pending_event(): Promise<WaylandApplicationEvent>
+    function linux_system_action(action: SystemAction): Promise<void>
 
-    function linux_open_application(desktop_id: string): void
+    function linux_open_application(desktop_id: string, desktop_file_path: string, action_id: string | undefined, terminal_command: string | undefined): Promise<void>
     function linux_x11_focus_window(window_id: string): void
     function linux_wayland_focus_window(window_id: string): void
+    function linux_x11_window_action(window_id: string, action: WindowAction): void
+    function linux_x11_move_window_to_workspace(window_id: string, workspace: number): void
+    function linux_x11_workspaces(): X11Workspace[]
+    function linux_wayland_window_action(window_id: string, action: WindowAction): void
     function linux_application_dirs(): string[]
     function linux_app_from_path(path: string): Promise<undefined | DesktopPathAction<LinuxDesktopApplicationData>>
 
@@ -227,6 +369,7 @@ declare module "ext:core/ops" {
     function clear_inline_view(): void;
     function op_plugin_get_pending_event(): Promise<PluginEvent>;
     function hide_window(): void;
+    function paste_to_active_window(text: string, cursorOffsetFromEnd: number): Promise<void>;
 
     function get_entrypoint_generator_entrypoint_ids(): Promise<string[]>
 
@@ -239,6 +382,9 @@ declare module "ext:core/ops" {
     function reload_search_index(searchItems: GeneratedSearchItem[], refreshSearchList: boolean): Promise<void>;
 
     function show_hud(display: string): void;
+    function show_toast(toast: ToastData): Promise<void>;
+    function hide_toast(toastId: number): Promise<void>;
+    function show_confirm_alert(alert: ConfirmAlertData): Promise<void>;
     function update_loading_bar(entrypoint_id: string, show: boolean): void;
 
     function op_react_replace_view(render_location: RenderLocation, top_level_view: boolean, entrypoint_id: string, entrypoint_name: string, container: any): void;
@@ -330,7 +476,7 @@ type ComponentRef = {
     componentName: string,
 }
 
-type PropertyType = TypeString | TypeNumber | TypeBoolean | TypeComponent | TypeFunction | TypeSharedTypeRef | TypeImageArray | TypeImageUnion
+type PropertyType = TypeString | TypeNumber | TypeBoolean | TypeComponent | TypeFunction | TypeSharedTypeRef | TypeImageArray | TypeImageUnion | TypeRecord
 
 type TypeString = {
     type: "string"
@@ -361,6 +507,10 @@ type TypeImageArray = {
     type: "array"
     item: PropertyType
 }
+type TypeRecord = {
+    type: "record"
+    item: PropertyType
+}
 
 type WaylandApplicationEvent = WaylandApplicationEventWindowOpened
     | WaylandApplicationEventWindowClosed
@@ -390,6 +540,14 @@ type X11WindowProtocol = "DeleteWindow" | "TakeFocus"
 type X11WindowType = "DropdownMenu" | "Dialog" | "Menu" | "Notification" | "Normal" | "PopupMenu" | "Splash" | "Toolbar" | "Tooltip" | "Utility"
 type X11WindowId = string
 
+type X11Workspace = {
+    index: number,
+    name: string,
+    current: boolean,
+}
+
+type WindowAction = "close" | "minimize" | "toggle_maximize"
+
 type X11ApplicationEvent = X11ApplicationEventInit
     | X11ApplicationEventCreateNotify
     | X11ApplicationEventDestroyNotify
@@ -402,7 +560,8 @@ type X11ApplicationEvent = X11ApplicationEventInit
     | X11ApplicationEventProtocolsPropertyNotify
     | X11ApplicationEventTransientForPropertyNotify
     | X11ApplicationEventWindowTypePropertyNotify
-    | X11ApplicationEventDesktopFileNamePropertyNotify;
+    | X11ApplicationEventDesktopFileNamePropertyNotify
+    | X11ApplicationEventWorkspacePropertyNotify;
 
 
 type X11ApplicationEventInit = {
@@ -482,3 +641,10 @@ type X11ApplicationEventDesktopFileNamePropertyNotify = {
     id: X11WindowId,
     desktop_file_name: string
 };
+
+type X11ApplicationEventWorkspacePropertyNotify = {
+    type: "WorkspacePropertyNotify",
+    id: X11WindowId,
+    // undefined if window is shown on all workspaces
+    workspace: number | undefined
+};
@@ -11,8 +11,8 @@
     system,
     ...
   }: let
-    inherit (lib) makeBinPath makeLibraryPath optionals optionalString;
-    inherit (pkgs) alejandra cargo cmake deno gauntlet gtk3 libxkbcommon libGL mkShell nodejs pkg-config protobuf stdenv xorg wayland;
+    inherit (lib) makeLibraryPath optionals optionalString;
+    inherit (pkgs) alejandra cargo cmake deno gauntlet libxkbcommon libGL mkShell nodejs pkg-config protobuf stdenv xorg wayland;
     inherit (stdenv.hostPlatform) isLinux;
   in {
     _module.args.pkgs = import inputs.nixpkgs {
@@ -23,7 +23,6 @@
       packages = [cargo cmake deno nodejs protobuf] ++ optionals isLinux [libxkbcommon pkg-config];
       shellHook = optionalString isLinux ''
         export LD_LIBRARY_PATH="$LD_LIBRARY_PATH:${makeLibraryPath [libGL xorg.libX11 wayland]}"
-        export PATH="$PATH:${makeBinPath [gtk3]}"
       '';
     };
     formatter = alejandra;
@@ -23,12 +23,10 @@
       buildPackages
       fetchurl
       fetchNpmDeps
-      makeWrapper
       writeShellScriptBin
       # Packages
       cmake
       deno
-      gtk3
       libxkbcommon
       libGL
       xorg
@@ -40,7 +38,7 @@
       yq
       ;
     inherit (buildPackages.npmHooks.override {inherit nodejs;}) npmConfigHook;
-    inherit (lib) concatStringsSep getExe' makeBinPath makeLibraryPath optional;
+    inherit (lib) concatStringsSep getExe' makeLibraryPath optional;
     inherit (stdenv.hostPlatform) isLinux rust system;
     # Borrowed from other packages in nixpkgs https://github.com/search?q=repo%3ANixOS%2Fnixpkgs%20RUSTY_V8_ARCHIVE&type=code
     buildRustyV8Url = version: target: "https://github.com/denoland/rusty_v8/releases/download/v${version}/librusty_v8_release_${target}.a.gz";
@@ -86,7 +84,7 @@
         hash = npmDepsHash;
       };
       makeCacheWritable = true;
-      nativeBuildInputs = cargoArtifactsArgs.nativeBuildInputs ++ [nodejs npmConfigHook] ++ optional isLinux makeWrapper;
+      nativeBuildInputs = cargoArtifactsArgs.nativeBuildInputs ++ [nodejs npmConfigHook];
       buildInputs = cargoArtifactsArgs.buildInputs ++ [deno];
       preBuild = "npm run build";
       postInstall =
@@ -106,7 +104,6 @@
         if isLinux
         then ''
           patchelf --add-rpath ${makeLibraryPath [libGL xorg.libX11 wayland]} $out/bin/gauntlet
-          wrapProgram $out/bin/gauntlet --suffix PATH : ${makeBinPath [gtk3]}
           substituteInPlace $out/lib/systemd/user/gauntlet.service --replace /usr/bin/gauntlet $out/bin/gauntlet
         ''
         else ''
@@ -76,6 +76,15 @@ fn main() -> anyhow::Result<()> {
                                     ));
                                 }
                             }
+                            PropertyType::Array { ref item } if matches!(item.as_ref(), PropertyType::String) => {
+                                output.push_str(&format!("            gauntlet_common::model::UiPropertyValue::Array({}.into_iter().map(|value| gauntlet_common::model::UiPropertyValue::String(value)).collect()),\n", arg.name));
+                            }
+                            PropertyType::Record { .. } => {
+                                output.push_str(&format!(
+                                    "            gauntlet_common::model::UiPropertyValue::Object({}),\n",
+                                    arg.name
+                                ));
+                            }
                             _ => {
                                 panic!("not yet supported")
                             }
@@ -138,5 +147,9 @@ fn generate_required_type(property_type: &PropertyType, union_name: Option<Strin
             }
         }
         PropertyType::Array { item } => format!("Vec<{}>", generate_required_type(item, union_name)),
+        // values of record are converted by the caller, because record items usually are unions
+        PropertyType::Record { .. } => {
+            "std::collections::HashMap<String, gauntlet_common::model::UiPropertyValue>".to_owned()
+        }
     }
 }
@@ -164,10 +164,26 @@ impl ClientContext {
         self.view.toggle_action_panel()
     }
 
+    pub fn toggle_detail(&mut self) -> bool {
+        self.view.toggle_detail()
+    }
+
+    pub fn cycle_search_bar_accessory(&self) -> Option<Task<AppMsg>> {
+        self.view.cycle_search_bar_accessory()
+    }
+
+    pub fn submit_form(&mut self) -> Option<Task<AppMsg>> {
+        self.view.submit_form()
+    }
+
     pub fn get_action_ids(&self) -> Vec<UiWidgetId> {
         self.view.get_action_ids()
     }
 
+    pub fn get_plugin_action_ids(&self) -> Vec<UiWidgetId> {
+        self.view.get_plugin_action_ids()
+    }
+
     pub fn get_focused_item_id(&self) -> Option<String> {
         self.view.get_focused_item_id()
     }
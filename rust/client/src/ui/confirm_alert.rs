@@ -0,0 +1,88 @@
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::UiConfirmAlert;
+use gauntlet_common::model::UiConfirmAlertActionStyle;
+use iced::advanced::text::Shaping;
+use iced::widget::button;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::horizontal_space;
+use iced::widget::mouse_area;
+use iced::widget::row;
+use iced::widget::text;
+use iced::widget::value;
+use iced::Length;
+use iced_fonts::BOOTSTRAP_FONT;
+
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::Element;
+use crate::ui::theme::ThemableWidget;
+use crate::ui::widget::images::icon_to_bootstrap;
+use crate::ui::AppMsg;
+
+pub struct ConfirmAlertState {
+    pub plugin_id: PluginId,
+    pub alert: UiConfirmAlert,
+}
+
+pub fn view_confirm_alert<'a>(state: &ConfirmAlertState) -> Element<'a, AppMsg> {
+    let ConfirmAlertState { alert, .. } = state;
+
+    let mut content = vec![];
+
+    if let Some(icon) = &alert.icon {
+        let icon: Element<_> = value(icon_to_bootstrap(icon)).font(BOOTSTRAP_FONT).size(32).into();
+
+        let icon: Element<_> = container(icon).themed(ContainerStyle::ConfirmAlertIcon);
+
+        content.push(icon);
+    }
+
+    let title: Element<_> = text(alert.title.to_string()).shaping(Shaping::Advanced).into();
+
+    let title: Element<_> = container(title).themed(ContainerStyle::ConfirmAlertTitle);
+
+    content.push(title);
+
+    if let Some(message) = &alert.message {
+        let message: Element<_> = text(message.to_string())
+            .shaping(Shaping::Advanced)
+            .themed(TextStyle::ConfirmAlertMessage);
+
+        content.push(message);
+    }
+
+    let dismiss_label: Element<_> = text(alert.dismiss_action.to_string()).shaping(Shaping::Advanced).into();
+
+    let dismiss_button: Element<_> = button(dismiss_label)
+        .on_press(AppMsg::CloseConfirmAlert { confirmed: false })
+        .themed(ButtonStyle::ConfirmAlertAction);
+
+    let primary_label: Element<_> = text(alert.primary_action.to_string()).shaping(Shaping::Advanced).into();
+
+    let primary_style = match alert.primary_action_style {
+        UiConfirmAlertActionStyle::Default => ButtonStyle::ConfirmAlertAction,
+        UiConfirmAlertActionStyle::Destructive => ButtonStyle::ConfirmAlertDestructiveAction,
+    };
+
+    let primary_button: Element<_> = button(primary_label)
+        .on_press(AppMsg::CloseConfirmAlert { confirmed: true })
+        .themed(primary_style);
+
+    let space: Element<_> = horizontal_space().into();
+
+    let actions: Element<_> = row(vec![space, dismiss_button, primary_button]).themed(RowStyle::ConfirmAlertActions);
+
+    content.push(actions);
+
+    let alert: Element<_> = container(column(content)).themed(ContainerStyle::ConfirmAlert);
+
+    let alert: Element<_> = container(alert).center(Length::Fill).into();
+
+    // backdrop captures all clicks so that the view underneath can't be interacted with while alert is open
+    let alert: Element<_> = mouse_area(alert).on_press(AppMsg::Noop).into();
+
+    container(alert).themed(ContainerStyle::ConfirmAlertBackdrop)
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use gauntlet_common::model::quick_pick_shortcut;
+use gauntlet_common::model::LauncherAction;
+use gauntlet_common::model::NavigationKeymap;
+use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::QUICK_PICK_COUNT;
+use gauntlet_common_ui::physical_key_model;
+use iced::keyboard::key::Named;
+use iced::keyboard::key::Physical;
+use iced::keyboard::Modifiers;
+
+/// Maps additional navigation shortcuts of selected keymap to arrow keys
+pub fn navigation_key(keymap: NavigationKeymap, physical_key: &Physical, modifiers: Modifiers) -> Option<Named> {
+    let Physical::Code(physical_key) = physical_key else {
+        return None;
+    };
+
+    let Some(PhysicalShortcut {
+        physical_key,
+        modifier_shift: false,
+        modifier_control: true,
+        modifier_alt: false,
+        modifier_meta: false,
+    }) = physical_key_model(*physical_key, modifiers)
+    else {
+        return None;
+    };
+
+    match (keymap, physical_key) {
+        (NavigationKeymap::Vim, PhysicalKey::KeyJ) => Some(Named::ArrowDown),
+        (NavigationKeymap::Vim, PhysicalKey::KeyK) => Some(Named::ArrowUp),
+        (NavigationKeymap::Emacs, PhysicalKey::KeyN) => Some(Named::ArrowDown),
+        (NavigationKeymap::Emacs, PhysicalKey::KeyP) => Some(Named::ArrowUp),
+        _ => None,
+    }
+}
+
+/// Index of search result which is run when modifier + digit is pressed
+pub fn quick_pick_index(shortcut: &PhysicalShortcut) -> Option<usize> {
+    (0..QUICK_PICK_COUNT).find(|index| quick_pick_shortcut(*index).as_ref() == Some(shortcut))
+}
+
+pub fn quick_pick_label(index: usize) -> Option<String> {
+    if index >= QUICK_PICK_COUNT {
+        return None;
+    }
+
+    if cfg!(target_os = "macos") {
+        Some(format!("⌘{}", index + 1))
+    } else {
+        Some(format!("Alt+{}", index + 1))
+    }
+}
+
+/// Shortcut of launcher action, `keybindings` are loaded from config by server
+pub fn launcher_action_shortcut(
+    keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
+    action: LauncherAction,
+) -> PhysicalShortcut {
+    keybindings
+        .get(&action)
+        .cloned()
+        .unwrap_or_else(|| action.default_shortcut())
+}
+
+pub fn launcher_action(
+    keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
+    physical_key: &Physical,
+    modifiers: Modifiers,
+) -> Option<LauncherAction> {
+    let Physical::Code(physical_key) = physical_key else {
+        return None;
+    };
+
+    let mut shortcut = physical_key_model(*physical_key, modifiers)?;
+
+    // treat both enter keys the same, like logical key does
+    if shortcut.physical_key == PhysicalKey::NumpadEnter {
+        shortcut.physical_key = PhysicalKey::Enter;
+    }
+
+    // if multiple actions have the same shortcut, first one wins
+    LauncherAction::ALL
+        .into_iter()
+        .find(|action| launcher_action_shortcut(keybindings, *action) == shortcut)
+}
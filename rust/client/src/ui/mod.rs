@@ -12,6 +12,8 @@ use anyhow::anyhow;
 use client_context::ClientContext;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::KeyboardEventOrigin;
+use gauntlet_common::model::LauncherAction;
+use gauntlet_common::model::NavigationKeymap;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
@@ -21,9 +23,11 @@ use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SearchResultEntrypointAction;
 use gauntlet_common::model::SearchResultEntrypointActionType;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::UiConfirmAlert;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiSetupData;
 use gauntlet_common::model::UiTheme;
+use gauntlet_common::model::UiToast;
 use gauntlet_common::model::UiWidgetId;
 use gauntlet_common::model::WindowPositionMode;
 use gauntlet_common::rpc::backend_api::BackendForFrontendApi;
@@ -71,6 +75,7 @@ use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::scrollable::scroll_to;
 use iced::widget::scrollable::AbsoluteOffset;
+use iced::widget::stack;
 use iced::widget::text;
 use iced::widget::text::Shaping;
 use iced::widget::text_input;
@@ -110,15 +115,19 @@ use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 
 mod client_context;
+mod confirm_alert;
 mod custom_widgets;
 mod grid_navigation;
 mod hud;
+mod keymap;
 mod scroll_handle;
+mod search_history;
 mod search_list;
 mod state;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod sys_tray;
 mod theme;
+mod toast;
 mod widget;
 mod widget_container;
 
@@ -128,11 +137,31 @@ pub use theme::GauntletComplexTheme;
 
 use crate::global_shortcut::convert_physical_shortcut_to_hotkey;
 use crate::global_shortcut::register_listener;
+use crate::ui::confirm_alert::view_confirm_alert;
+use crate::ui::confirm_alert::ConfirmAlertState;
 use crate::ui::custom_widgets::loading_bar::LoadingBar;
 use crate::ui::hud::show_hud_window;
+use crate::ui::keymap::launcher_action;
+use crate::ui::keymap::launcher_action_shortcut;
+use crate::ui::keymap::navigation_key;
+use crate::ui::keymap::quick_pick_index;
 #[cfg(target_os = "linux")]
 use crate::ui::platform::linux::listen_on_x11_active_window_change;
+#[cfg(target_os = "linux")]
+use crate::ui::platform::linux::x11_monitor_at;
+#[cfg(target_os = "linux")]
+use crate::ui::platform::linux::x11_monitor_with_cursor;
+#[cfg(target_os = "linux")]
+use crate::ui::platform::linux::x11_monitor_with_focused_window;
+#[cfg(target_os = "linux")]
+use crate::ui::platform::linux::x11_paste_to_window;
+#[cfg(target_os = "linux")]
+use crate::ui::platform::linux::X11Monitor;
+#[cfg(target_os = "linux")]
+use crate::ui::platform::wayland::wayland_send_paste_keystroke;
+use crate::ui::scroll_handle::main_list_rows_per_view;
 use crate::ui::scroll_handle::ScrollHandle;
+use crate::ui::search_history::SearchHistory;
 use crate::ui::state::ErrorViewData;
 use crate::ui::state::Focus;
 use crate::ui::state::GlobalState;
@@ -140,9 +169,14 @@ use crate::ui::state::LoadingBarState;
 use crate::ui::state::MainViewState;
 use crate::ui::state::PluginViewData;
 use crate::ui::state::PluginViewState;
+use crate::ui::toast::hide_toast_after_delay;
+use crate::ui::toast::view_toast;
+use crate::ui::toast::ToastState;
 use crate::ui::widget::action_panel::ActionPanel;
 use crate::ui::widget::action_panel::ActionPanelItem;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::file_browser::FileBrowserLocation;
+use crate::ui::widget::file_browser::FileBrowserState;
 use crate::ui::widget::root::render_root;
 use crate::ui::widget_container::PluginWidgetContainer;
 
@@ -162,9 +196,21 @@ pub struct AppModel {
     theme: GauntletComplexTheme,
     window_position_mode: WindowPositionMode,
     close_on_unfocus: bool,
+    restore_last_query: bool,
+    navigation_keymap: NavigationKeymap,
+    keybindings: HashMap<LauncherAction, PhysicalShortcut>,
+    window_size: Size,
+    main_list_rows_per_view: usize,
+    max_visible_results: Option<usize>,
+    #[cfg(target_os = "linux")]
+    window_position_per_monitor: HashMap<String, (i32, i32)>,
+    #[cfg(target_os = "linux")]
+    window_position_per_monitor_file: Option<PathBuf>,
     window_position_file: Option<PathBuf>,
     #[cfg(target_os = "linux")]
     x11_active_window: Option<u32>,
+    #[cfg(target_os = "linux")]
+    x11_previous_window: Option<u32>,
     pending_window_state_reset: bool,
 
     // ephemeral state
@@ -174,8 +220,12 @@ pub struct AppModel {
     client_context: ClientContext,
     global_state: GlobalState,
     search_results: Vec<SearchResult>,
+    search_history: SearchHistory,
     loading_bar_state: HashMap<(PluginId, EntrypointId), ()>,
     hud_display: Option<String>,
+    toast: Option<ToastState>,
+    toast_revision: usize,
+    confirm_alert: Option<ConfirmAlertState>,
 }
 
 #[cfg(target_os = "linux")]
@@ -233,6 +283,7 @@ pub enum AppMsg {
     PromptSubmit,
     UpdateSearchResults,
     SetSearchResults(Vec<SearchResult>),
+    SetSearchHistory(Vec<String>),
     RenderPluginUI {
         plugin_id: PluginId,
         plugin_name: String,
@@ -263,6 +314,7 @@ pub enum AppMsg {
     ToggleActionPanel {
         keyboard: bool,
     },
+    SubmitForm,
     ShowPreferenceRequiredView {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -297,6 +349,37 @@ pub enum AppMsg {
     ShowHud {
         display: String,
     },
+    ShowToast {
+        plugin_id: PluginId,
+        toast: UiToast,
+    },
+    HideToast {
+        plugin_id: PluginId,
+        toast_id: u32,
+    },
+    ClearToasts {
+        plugin_id: PluginId,
+    },
+    ExpireToast {
+        revision: usize,
+    },
+    RunToastAction {
+        plugin_id: PluginId,
+        toast_id: u32,
+        action_index: usize,
+    },
+    ShowConfirmAlert {
+        plugin_id: PluginId,
+        alert: UiConfirmAlert,
+    },
+    CloseConfirmAlert {
+        confirmed: bool,
+    },
+    LoadFilePickerBrowser {
+        plugin_id: PluginId,
+        widget_id: UiWidgetId,
+        location: FileBrowserLocation,
+    },
     OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus,
     OnPrimaryActionMainViewNoPanel {
         search_result: SearchResult,
@@ -344,6 +427,10 @@ pub enum AppMsg {
         shortcut: Option<PhysicalShortcut>,
         responder: Arc<Mutex<Option<Responder<FrontendApiResponseData>>>>,
     },
+    PasteToActiveWindow {
+        cursor_offset_from_end: u32,
+        responder: Arc<Mutex<Option<Responder<FrontendApiResponseData>>>>,
+    },
     UpdateLoadingBar {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -370,6 +457,16 @@ pub enum AppMsg {
         window: u32,
         wm_name: Option<String>,
     },
+    #[cfg(target_os = "linux")]
+    SaveWindowPositionForMonitor {
+        position: Point,
+        scale_factor: f32,
+    },
+    #[cfg(target_os = "linux")]
+    SetWindowPositionForMonitor {
+        monitor_name: String,
+        offset: (i32, i32),
+    },
     RunEntrypoint {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -387,13 +484,10 @@ impl TryInto<iced_layershell::actions::LayershellCustomActionsWithId> for AppMsg
     }
 }
 
-const WINDOW_WIDTH: f32 = 750.0;
-const WINDOW_HEIGHT: f32 = 450.0;
-
 #[cfg(not(target_os = "macos"))]
-fn window_settings(visible: bool, position: Position) -> window::Settings {
+fn window_settings(visible: bool, position: Position, size: Size) -> window::Settings {
     window::Settings {
-        size: Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        size,
         position,
         resizable: false,
         decorations: false,
@@ -411,9 +505,9 @@ fn window_settings(visible: bool, position: Position) -> window::Settings {
 }
 
 #[cfg(target_os = "macos")]
-fn window_settings(visible: bool, position: Position) -> window::Settings {
+fn window_settings(visible: bool, position: Position, size: Size) -> window::Settings {
     window::Settings {
-        size: Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        size,
         position,
         resizable: false,
         decorations: true,
@@ -433,7 +527,7 @@ fn window_settings(visible: bool, position: Position) -> window::Settings {
 }
 
 #[cfg(target_os = "linux")]
-fn layer_shell_settings() -> iced_layershell::reexport::NewLayerShellSettings {
+fn layer_shell_settings(size: Size) -> iced_layershell::reexport::NewLayerShellSettings {
     iced_layershell::reexport::NewLayerShellSettings {
         layer: iced_layershell::reexport::Layer::Overlay,
         keyboard_interactivity: iced_layershell::reexport::KeyboardInteractivity::Exclusive,
@@ -441,12 +535,16 @@ fn layer_shell_settings() -> iced_layershell::reexport::NewLayerShellSettings {
         anchor: iced_layershell::reexport::Anchor::empty(),
         margin: Default::default(),
         exclusive_zone: Some(0),
-        size: Some((WINDOW_WIDTH as u32, WINDOW_HEIGHT as u32)),
+        size: Some((size.width as u32, size.height as u32)),
         use_last_output: false,
     }
 }
 
-fn open_main_window_non_wayland(minimized: bool, window_position_file: Option<&PathBuf>) -> (window::Id, Task<AppMsg>) {
+fn open_main_window_non_wayland(
+    minimized: bool,
+    window_position_file: Option<&PathBuf>,
+    window_size: Size,
+) -> (window::Id, Task<AppMsg>) {
     let position = window_position_file
         .map(|window_position_file| fs::read_to_string(window_position_file).ok())
         .flatten()
@@ -463,7 +561,7 @@ fn open_main_window_non_wayland(minimized: bool, window_position_file: Option<&P
         .unwrap_or(None)
         .unwrap_or(Position::Centered);
 
-    let (main_window_id, open_task) = window::open(window_settings(!minimized, position));
+    let (main_window_id, open_task) = window::open(window_settings(!minimized, position, window_size));
 
     (
         main_window_id,
@@ -476,8 +574,8 @@ fn open_main_window_non_wayland(minimized: bool, window_position_file: Option<&P
 }
 
 #[cfg(target_os = "linux")]
-fn open_main_window_wayland(id: window::Id) -> (window::Id, Task<AppMsg>) {
-    let settings = layer_shell_settings();
+fn open_main_window_wayland(id: window::Id, window_size: Size) -> (window::Id, Task<AppMsg>) {
+    let settings = layer_shell_settings(window_size);
 
     (
         id,
@@ -488,6 +586,44 @@ fn open_main_window_wayland(id: window::Id) -> (window::Id, Task<AppMsg>) {
     )
 }
 
+#[cfg(target_os = "linux")]
+fn read_window_position_per_monitor(file: Option<&PathBuf>) -> HashMap<String, (i32, i32)> {
+    let Some(data) = file.map(|file| fs::read_to_string(file).ok()).flatten() else {
+        return HashMap::new();
+    };
+
+    // each line has "<monitor name>:<x>:<y>" format
+    data.lines()
+        .filter_map(|line| {
+            let mut parts = line.rsplitn(3, ":");
+
+            let y = parts.next()?.parse().ok()?;
+            let x = parts.next()?.parse().ok()?;
+            let monitor = parts.next()?;
+
+            Some((monitor.to_string(), (x, y)))
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn write_window_position_per_monitor(file: &Path, positions: &HashMap<String, (i32, i32)>) -> anyhow::Result<()> {
+    let data = positions
+        .iter()
+        .map(|(monitor, (x, y))| format!("{}:{}:{}", monitor, x, y))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let parent = file
+        .parent()
+        .ok_or_else(|| anyhow!("window position file has no parent directory: {:?}", file))?;
+
+    fs::create_dir_all(parent)?;
+    fs::write(file, data)?;
+
+    Ok(())
+}
+
 pub fn run(
     minimized: bool,
     frontend_receiver: RequestReceiver<FrontendApiRequestData, FrontendApiResponseData>,
@@ -599,6 +735,10 @@ fn new(
 
     let mut tasks = vec![font::load(BOOTSTRAP_FONT_BYTES).map(AppMsg::FontLoaded)];
 
+    let window_size = Size::new(setup_data.window_width, setup_data.window_height);
+
+    let main_list_rows_per_view = main_list_rows_per_view(window_size.height);
+
     #[cfg(target_os = "linux")]
     let (main_window_id, open_task) = if wayland {
         let id = window::Id::unique();
@@ -606,17 +746,27 @@ fn new(
         if minimized {
             (id, Task::none())
         } else {
-            open_main_window_wayland(id)
+            open_main_window_wayland(id, window_size)
         }
     } else {
-        open_main_window_non_wayland(minimized, setup_data.window_position_file.as_ref())
+        open_main_window_non_wayland(minimized, setup_data.window_position_file.as_ref(), window_size)
     };
 
     #[cfg(not(target_os = "linux"))]
-    let (main_window_id, open_task) = open_main_window_non_wayland(minimized, setup_data.window_position_file.as_ref());
+    let (main_window_id, open_task) =
+        open_main_window_non_wayland(minimized, setup_data.window_position_file.as_ref(), window_size);
 
     tasks.push(open_task);
 
+    tasks.push(Task::perform(
+        {
+            let mut backend_api = backend_api.clone();
+
+            async move { backend_api.search_history().await }
+        },
+        |result| handle_backend_error(result, |search_history| AppMsg::SetSearchHistory(search_history)),
+    ));
+
     let mut client_context = ClientContext::new();
 
     let global_state = if cfg!(feature = "scenario_runner") {
@@ -680,7 +830,7 @@ fn new(
                 }
 
                 match render_location {
-                    UiRenderLocation::InlineView => GlobalState::new(text_input::Id::unique()),
+                    UiRenderLocation::InlineView => GlobalState::new(text_input::Id::unique(), main_list_rows_per_view),
                     UiRenderLocation::View => {
                         GlobalState::new_plugin(
                             PluginViewData {
@@ -723,7 +873,7 @@ fn new(
             }
         }
     } else {
-        GlobalState::new(text_input::Id::unique())
+        GlobalState::new(text_input::Id::unique(), main_list_rows_per_view)
     };
 
     (
@@ -743,9 +893,23 @@ fn new(
             theme,
             window_position_mode: setup_data.window_position_mode,
             close_on_unfocus: setup_data.close_on_unfocus,
+            restore_last_query: setup_data.restore_last_query,
+            navigation_keymap: setup_data.navigation_keymap,
+            keybindings: setup_data.keybindings,
+            window_size,
+            main_list_rows_per_view,
+            max_visible_results: setup_data.max_visible_results,
+            #[cfg(target_os = "linux")]
+            window_position_per_monitor: read_window_position_per_monitor(
+                setup_data.window_position_per_monitor_file.as_ref(),
+            ),
+            #[cfg(target_os = "linux")]
+            window_position_per_monitor_file: setup_data.window_position_per_monitor_file,
             window_position_file: setup_data.window_position_file,
             #[cfg(target_os = "linux")]
             x11_active_window: None,
+            #[cfg(target_os = "linux")]
+            x11_previous_window: None,
             pending_window_state_reset: false,
 
             // ephemeral state
@@ -755,8 +919,12 @@ fn new(
             global_state,
             client_context,
             search_results: vec![],
+            search_history: SearchHistory::default(),
             loading_bar_state: HashMap::new(),
             hud_display: None,
+            toast: None,
+            toast_revision: 0,
+            confirm_alert: None,
         },
         Task::batch(tasks),
     )
@@ -876,7 +1044,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::RunSearchItemAction(search_result, action_index) => {
-            match search_result.entrypoint_type {
+            let save_search_history = state.save_search_history_entry();
+
+            let run_action = match search_result.entrypoint_type {
                 SearchResultEntrypointType::Command => {
                     if action_index == 0 {
                         Task::done(AppMsg::RunCommand {
@@ -920,9 +1090,13 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         }
                     }
                 }
-            }
+            };
+
+            Task::batch([save_search_history, run_action])
         }
         AppMsg::PromptChanged(mut new_prompt) => {
+            state.search_history.reset();
+
             match &mut state.global_state {
                 GlobalState::MainView {
                     focused_search_result,
@@ -950,12 +1124,28 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 _ => Task::none(),
             }
         }
-        AppMsg::PromptSubmit => state.global_state.primary(&state.client_context, &state.search_results),
-        AppMsg::SetSearchResults(new_search_results) => {
+        AppMsg::PromptSubmit => {
+            // enter in search bar while alert is open confirms the alert instead of running the selected item
+            if state.confirm_alert.is_some() {
+                return Task::done(AppMsg::CloseConfirmAlert { confirmed: true });
+            }
+
+            state.global_state.primary(&state.client_context, &state.search_results)
+        }
+        AppMsg::SetSearchResults(mut new_search_results) => {
+            if let Some(max_visible_results) = state.max_visible_results {
+                new_search_results.truncate(max_visible_results);
+            }
+
             state.search_results = new_search_results;
 
             Task::none()
         }
+        AppMsg::SetSearchHistory(search_history) => {
+            state.search_history.set_entries(search_history);
+
+            Task::none()
+        }
         AppMsg::RenderPluginUI {
             plugin_id,
             plugin_name,
@@ -1070,14 +1260,45 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         modifiers.alt(),
                         modifiers.logo()
                     );
+
+                    if state.confirm_alert.is_some() {
+                        return match key {
+                            Key::Named(Named::Escape) => Task::done(AppMsg::CloseConfirmAlert { confirmed: false }),
+                            Key::Named(Named::Enter) => Task::done(AppMsg::CloseConfirmAlert { confirmed: true }),
+                            _ => Task::none(),
+                        };
+                    }
+
+                    let key = match navigation_key(state.navigation_keymap, &physical_key, modifiers) {
+                        Some(named) => Key::Named(named),
+                        None => {
+                            let task = launcher_action(&state.keybindings, &physical_key, modifiers)
+                                .and_then(|action| state.run_launcher_action(action));
+
+                            if let Some(task) = task {
+                                return task;
+                            }
+
+                            key
+                        }
+                    };
+
                     match key {
                         Key::Named(Named::ArrowUp) => {
-                            state.global_state.up(&mut state.client_context, &state.search_results)
+                            match state.recall_older_search_history() {
+                                Some(task) => task,
+                                None => state.global_state.up(&mut state.client_context, &state.search_results),
+                            }
                         }
                         Key::Named(Named::ArrowDown) => {
-                            state
-                                .global_state
-                                .down(&mut state.client_context, &state.search_results)
+                            match state.recall_newer_search_history() {
+                                Some(task) => task,
+                                None => {
+                                    state
+                                        .global_state
+                                        .down(&mut state.client_context, &state.search_results)
+                                }
+                            }
                         }
                         Key::Named(Named::ArrowLeft) => {
                             state
@@ -1089,25 +1310,11 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                 .global_state
                                 .right(&mut state.client_context, &state.search_results)
                         }
-                        Key::Named(Named::Escape) => state.global_state.back(&state.client_context),
                         Key::Named(Named::Tab) if !modifiers.shift() => state.global_state.next(&state.client_context),
                         Key::Named(Named::Tab) if modifiers.shift() => {
                             state.global_state.previous(&state.client_context)
                         }
-                        Key::Named(Named::Enter) => {
-                            if modifiers.logo() || modifiers.alt() || modifiers.control() {
-                                Task::none() // to avoid not wanted "enter" presses
-                            } else {
-                                if modifiers.shift() {
-                                    // for main view, also fired in cases where main text field is not focused
-                                    state
-                                        .global_state
-                                        .secondary(&state.client_context, &state.search_results)
-                                } else {
-                                    state.global_state.primary(&state.client_context, &state.search_results)
-                                }
-                            }
-                        }
+                        Key::Named(Named::Enter) => Task::none(), // to avoid not wanted "enter" presses
                         Key::Named(Named::Backspace) => {
                             match &mut state.global_state {
                                 GlobalState::MainView {
@@ -1180,6 +1387,16 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 let _ = fs::write(&window_position_file, format!("{}:{}", point.x, point.y));
             }
 
+            #[cfg(target_os = "linux")]
+            if !state.wayland && state.window_position_mode == WindowPositionMode::RememberPerMonitor {
+                return window::get_scale_factor(window_id).map(move |scale_factor| {
+                    AppMsg::SaveWindowPositionForMonitor {
+                        position: point,
+                        scale_factor,
+                    }
+                });
+            }
+
             Task::none()
         }
         AppMsg::IcedEvent(_, _) => Task::none(),
@@ -1190,7 +1407,11 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::WidgetEvent {
             widget_event: ComponentWidgetEvent::PreviousView,
             ..
-        } => state.global_state.back(&state.client_context),
+        } => {
+            state
+                .global_state
+                .back(&state.client_context, state.main_list_rows_per_view)
+        }
         AppMsg::WidgetEvent {
             widget_event,
             plugin_id,
@@ -1348,6 +1569,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::none()
         }
+        AppMsg::SubmitForm => state.client_context.submit_form().unwrap_or_else(|| Task::none()),
         AppMsg::OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus => {
             Task::done(AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index: 0 })
         }
@@ -1480,7 +1702,11 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             })
         }
         AppMsg::OpenPluginView(plugin_id, entrypoint_id) => state.open_plugin_view(plugin_id, entrypoint_id),
-        AppMsg::ClosePluginView(plugin_id) => state.close_plugin_view(plugin_id),
+        AppMsg::ClosePluginView(plugin_id) => {
+            state.clear_toasts(&plugin_id);
+
+            state.close_plugin_view(plugin_id)
+        }
         AppMsg::InlineViewShortcuts { shortcuts } => {
             state.client_context.set_inline_view_shortcuts(shortcuts);
 
@@ -1494,6 +1720,119 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 state.wayland,
             )
         }
+        AppMsg::ShowToast { plugin_id, toast } => {
+            state.toast_revision += 1;
+
+            let revision = state.toast_revision;
+
+            let hide_task = hide_toast_after_delay(&toast, revision);
+
+            state.toast = Some(ToastState {
+                plugin_id,
+                toast,
+                revision,
+            });
+
+            hide_task
+        }
+        AppMsg::HideToast { plugin_id, toast_id } => {
+            if let Some(toast) = &state.toast {
+                if toast.is_toast(&plugin_id, toast_id) {
+                    state.toast = None;
+                }
+            }
+
+            Task::none()
+        }
+        AppMsg::ClearToasts { plugin_id } => {
+            state.clear_toasts(&plugin_id);
+
+            Task::none()
+        }
+        AppMsg::ExpireToast { revision } => {
+            if let Some(toast) = &state.toast {
+                if toast.revision == revision {
+                    state.toast = None;
+                }
+            }
+
+            Task::none()
+        }
+        AppMsg::RunToastAction {
+            plugin_id,
+            toast_id,
+            action_index,
+        } => {
+            let mut backend_client = state.backend_api.clone();
+
+            Task::perform(
+                async move {
+                    backend_client
+                        .send_toast_action_event(plugin_id, toast_id, action_index)
+                        .await?;
+
+                    Ok(AppMsg::Noop)
+                },
+                |result| handle_backend_error(result, |msg| msg),
+            )
+        }
+        AppMsg::ShowConfirmAlert { plugin_id, alert } => {
+            // only one alert can be open at a time, previous one is dismissed
+            let previous = state.confirm_alert.replace(ConfirmAlertState { plugin_id, alert });
+
+            // text inputs underneath the alert would still receive typed text, so they are unfocused.
+            // there doesn't seem to be an unfocus command but focusing non-existing input will unfocus all
+            let unfocus = focus(text_input::Id::unique());
+
+            match previous {
+                Some(previous) => Task::batch([unfocus, state.send_confirm_alert_event(previous, false)]),
+                None => unfocus,
+            }
+        }
+        AppMsg::CloseConfirmAlert { confirmed } => {
+            match state.confirm_alert.take() {
+                Some(confirm_alert) => {
+                    let refocus = match &state.global_state {
+                        GlobalState::MainView { search_field_id, .. } => focus(search_field_id.clone()),
+                        _ => Task::none(),
+                    };
+
+                    Task::batch([refocus, state.send_confirm_alert_event(confirm_alert, confirmed)])
+                }
+                None => Task::none(),
+            }
+        }
+        AppMsg::LoadFilePickerBrowser {
+            plugin_id,
+            widget_id,
+            location,
+        } => {
+            // reading directory can be slow, e.g. on network mounts, so it is done outside of update
+            Task::perform(
+                async move {
+                    match tokio::task::spawn_blocking(move || FileBrowserState::load(location)).await {
+                        Ok(browser) => Some(browser),
+                        Err(err) => {
+                            tracing::error!("Unable to read directory for file picker: {:?}", err);
+                            None
+                        }
+                    }
+                },
+                move |browser| {
+                    match browser {
+                        Some(browser) => {
+                            AppMsg::WidgetEvent {
+                                plugin_id: plugin_id.clone(),
+                                // forms are only rendered in views
+                                render_location: UiRenderLocation::View,
+                                widget_event: ComponentWidgetEvent::FilePickerBrowserLoaded { widget_id, browser },
+                            }
+                        }
+                        None => AppMsg::Noop,
+                    }
+                },
+            )
+        }
         AppMsg::ResetMainViewState => {
             match &mut state.global_state {
                 GlobalState::MainView { sub_state, .. } => {
@@ -1588,6 +1927,55 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::none()
         }
+        AppMsg::PasteToActiveWindow {
+            cursor_offset_from_end,
+            responder,
+        } => {
+            let responder = responder
+                .lock()
+                .expect("lock is poisoned")
+                .take()
+                .expect("there should always be a responder here");
+
+            #[cfg(target_os = "linux")]
+            let paste = {
+                let wayland = state.wayland;
+                let previous_window = state.x11_previous_window;
+
+                move || {
+                    if wayland {
+                        wayland_send_paste_keystroke(cursor_offset_from_end)
+                    } else {
+                        x11_paste_to_window(previous_window, cursor_offset_from_end)
+                    }
+                }
+            };
+
+            #[cfg(not(target_os = "linux"))]
+            let paste =
+                move || -> anyhow::Result<()> { Err(anyhow!("Pasting is not supported on this operating system")) };
+
+            let paste = Task::perform(
+                async move {
+                    // window manager needs some time to return focus to previous window after gauntlet is hidden
+                    tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+
+                    let result = match tokio::task::spawn_blocking(paste).await {
+                        Ok(result) => result,
+                        Err(err) => Err(anyhow!("Paste task failed: {:?}", err)),
+                    };
+
+                    if let Err(err) = &result {
+                        tracing::warn!("Unable to paste to active window: {:?}", err);
+                    }
+
+                    responder.respond(result.map(|()| FrontendApiResponseData::PasteToActiveWindow { data: () }));
+                },
+                |()| AppMsg::Noop,
+            );
+
+            Task::batch([state.hide_window(true), paste])
+        }
         AppMsg::UpdateLoadingBar {
             plugin_id,
             entrypoint_id,
@@ -1706,6 +2094,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 state.x11_active_window = Some(window);
                 if let Some(wm_name) = &wm_name {
                     if wm_name != "gauntlet" {
+                        // window to return focus to when pasting
+                        state.x11_previous_window = Some(window);
+
                         Task::done(AppMsg::HideWindow)
                     } else {
                         Task::none()
@@ -1717,6 +2108,63 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 Task::none()
             }
         }
+        #[cfg(target_os = "linux")]
+        AppMsg::SaveWindowPositionForMonitor { position, scale_factor } => {
+            let window_size = state.window_size;
+            let find_monitor = move || x11_monitor_at(position, window_size, scale_factor);
+
+            // x11 requests are blocking, so they are done outside of update
+            Task::perform(
+                async move {
+                    match tokio::task::spawn_blocking(find_monitor).await {
+                        Ok(result) => result,
+                        Err(err) => Err(anyhow!("Monitor query task failed: {:?}", err)),
+                    }
+                },
+                move |monitor| {
+                    match monitor {
+                        Ok(monitor) => {
+                            AppMsg::SetWindowPositionForMonitor {
+                                offset: monitor.window_offset(position, scale_factor),
+                                monitor_name: monitor.name,
+                            }
+                        }
+                        Err(err) => {
+                            tracing::warn!("Unable to find monitor for window position: {:#}", err);
+
+                            AppMsg::Noop
+                        }
+                    }
+                },
+            )
+        }
+        #[cfg(target_os = "linux")]
+        AppMsg::SetWindowPositionForMonitor { monitor_name, offset } => {
+            state.window_position_per_monitor.insert(monitor_name, offset);
+
+            let Some(file) = state.window_position_per_monitor_file.clone() else {
+                return Task::none();
+            };
+
+            let positions = state.window_position_per_monitor.clone();
+            let write = move || write_window_position_per_monitor(&file, &positions);
+
+            Task::perform(
+                async move {
+                    match tokio::task::spawn_blocking(write).await {
+                        Ok(result) => result,
+                        Err(err) => Err(anyhow!("Window position write task failed: {:?}", err)),
+                    }
+                },
+                |result| {
+                    if let Err(err) = result {
+                        tracing::warn!("Unable to save window position for monitor: {:#}", err);
+                    }
+
+                    AppMsg::Noop
+                },
+            )
+        }
         AppMsg::HandleGlobalShortcut(id) => {
             if let Some(hotkey) = state.current_global_hotkey {
                 if hotkey.id == id {
@@ -1761,7 +2209,17 @@ fn view(state: &AppModel, window: window::Id) -> Element<'_, AppMsg> {
     if window != state.main_window_id {
         view_hud(state)
     } else {
-        view_main(state)
+        let mut layers = vec![view_main(state)];
+
+        if let Some(toast) = &state.toast {
+            layers.push(view_toast(toast));
+        }
+
+        if let Some(confirm_alert) = &state.confirm_alert {
+            layers.push(view_confirm_alert(confirm_alert));
+        }
+
+        stack(layers).into()
     }
 }
 
@@ -2036,24 +2494,12 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
             let content: Element<_> = column(vec![inline_view, list]).into();
 
-            let (primary_action, action_panel) =
-                if let Some(search_item) = focused_search_result.get(&state.search_results) {
-                    let primary_shortcut = PhysicalShortcut {
-                        physical_key: PhysicalKey::Enter,
-                        modifier_shift: false,
-                        modifier_control: false,
-                        modifier_alt: false,
-                        modifier_meta: false,
-                    };
+            let primary_shortcut = launcher_action_shortcut(&state.keybindings, LauncherAction::RunPrimaryAction);
 
-                    let secondary_shortcut = PhysicalShortcut {
-                        physical_key: PhysicalKey::Enter,
-                        modifier_shift: true,
-                        modifier_control: false,
-                        modifier_alt: false,
-                        modifier_meta: false,
-                    };
+            let secondary_shortcut = launcher_action_shortcut(&state.keybindings, LauncherAction::RunSecondaryAction);
 
+            let (primary_action, action_panel) =
+                if let Some(search_item) = focused_search_result.get(&state.search_results) {
                     let create_static =
                         |label: &str, primary_shortcut: PhysicalShortcut, secondary_shortcut: PhysicalShortcut| {
                             let mut actions: Vec<_> = search_item
@@ -2096,6 +2542,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                                 let action_panel = ActionPanel {
                                     title: Some(search_item.entrypoint_name.clone()),
                                     items: actions,
+                                    launcher_items: vec![],
                                 };
 
                                 (
@@ -2137,6 +2584,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                             let action_panel = ActionPanel {
                                 title: Some(search_item.entrypoint_name.clone()),
                                 items: actions,
+                                launcher_items: vec![],
                             };
 
                             (
@@ -2163,15 +2611,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                             match action_panel.find_first() {
                                 None => (None, None),
                                 Some((label, widget_id)) => {
-                                    let shortcut = PhysicalShortcut {
-                                        physical_key: PhysicalKey::Enter,
-                                        modifier_shift: false,
-                                        modifier_control: false,
-                                        modifier_alt: false,
-                                        modifier_meta: false,
-                                    };
-
-                                    (Some((label, widget_id, shortcut)), Some(action_panel))
+                                    (Some((label, widget_id, primary_shortcut)), Some(action_panel))
                                 }
                             }
                         }
@@ -2196,6 +2636,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         action_panel,
                         None::<&ScrollHandle>,
                         "",
+                        &state.keybindings,
                         || AppMsg::ToggleActionPanel { keyboard: false },
                         |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
                         |widget_id| AppMsg::Noop,
@@ -2215,6 +2656,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         action_panel,
                         Some(focused_action_item),
                         "",
+                        &state.keybindings,
                         || AppMsg::ToggleActionPanel { keyboard: false },
                         |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
                         |widget_id| AppMsg::OnAnyActionMainViewSearchResultPanelMouse { widget_id },
@@ -2234,6 +2676,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         action_panel,
                         Some(focused_action_item),
                         "",
+                        &state.keybindings,
                         || AppMsg::ToggleActionPanel { keyboard: false },
                         |widget_id| AppMsg::OnPrimaryActionMainViewActionPanelMouse { widget_id },
                         |widget_id| AppMsg::OnAnyActionMainViewInlineViewPanelKeyboardWithFocus { widget_id },
@@ -2263,7 +2706,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
             let view_container = state.client_context.get_view_container();
 
             let container_element = view_container
-                .render_root_widget(plugin_id.clone(), sub_state, action_shortcuts)
+                .render_root_widget(plugin_id.clone(), sub_state, action_shortcuts, &state.keybindings)
                 .map(|widget_event| {
                     AppMsg::WidgetEvent {
                         plugin_id: plugin_id.clone(),
@@ -2465,6 +2908,13 @@ impl AppModel {
             GlobalState::PendingPluginView { .. } => {}
         }
 
+        // hiding the window counts as dismissing the alert, so that plugin doesn't wait for answer forever
+        if let Some(confirm_alert) = self.confirm_alert.take() {
+            commands.push(self.send_confirm_alert_event(confirm_alert, false));
+        }
+
+        self.toast = None;
+
         if self.pending_window_state_reset {
             commands.push(self.reset_window_state());
         }
@@ -2479,12 +2929,15 @@ impl AppModel {
 
         self.opened = true;
 
+        let search_history_task = self.fetch_search_history();
+
         #[cfg(target_os = "linux")]
         let open_task = if self.wayland {
-            let (_, open_task) = open_main_window_wayland(self.main_window_id);
+            let (_, open_task) = open_main_window_wayland(self.main_window_id, self.window_size);
             open_task
         } else {
             Task::batch([
+                self.move_to_monitor_x11(),
                 window::gain_focus(self.main_window_id),
                 window::change_mode(self.main_window_id, Mode::Windowed),
             ])
@@ -2495,21 +2948,85 @@ impl AppModel {
             window::gain_focus(self.main_window_id),
             #[cfg(target_os = "macos")]
             match self.window_position_mode {
-                WindowPositionMode::Static => Task::none(),
                 WindowPositionMode::ActiveMonitor => window::move_to_active_monitor(self.main_window_id),
+                _ => Task::none(),
             },
             window::change_mode(self.main_window_id, Mode::Windowed),
         ]);
 
-        open_task
+        Task::batch([open_task, search_history_task])
+    }
+
+    #[cfg(target_os = "linux")]
+    fn move_to_monitor_x11(&self) -> Task<AppMsg> {
+        let find_monitor: fn() -> anyhow::Result<X11Monitor> = match self.window_position_mode {
+            WindowPositionMode::Static | WindowPositionMode::ActiveMonitor => return Task::none(),
+            WindowPositionMode::CursorMonitor | WindowPositionMode::RememberPerMonitor => x11_monitor_with_cursor,
+            WindowPositionMode::FocusedWindowMonitor => {
+                // fallback to cursor if there is no focused window
+                || x11_monitor_with_focused_window().or_else(|_| x11_monitor_with_cursor())
+            }
+        };
+
+        let window_positions = match self.window_position_mode {
+            WindowPositionMode::RememberPerMonitor => self.window_position_per_monitor.clone(),
+            _ => HashMap::new(),
+        };
+
+        let main_window_id = self.main_window_id;
+        let window_size = self.window_size;
+
+        // x11 requests are blocking, so they are done outside of update
+        let monitor = Task::perform(
+            async move {
+                match tokio::task::spawn_blocking(find_monitor).await {
+                    Ok(result) => result,
+                    Err(err) => Err(anyhow!("Monitor query task failed: {:?}", err)),
+                }
+            },
+            |monitor| monitor,
+        );
+
+        monitor.then(move |monitor| {
+            let monitor = match monitor {
+                Ok(monitor) => monitor,
+                Err(err) => {
+                    tracing::warn!("Unable to find monitor to position the window on: {:#}", err);
+
+                    return Task::none();
+                }
+            };
+
+            let offset = window_positions.get(&monitor.name).cloned();
+
+            window::get_scale_factor(main_window_id).then(move |scale_factor| {
+                let position = monitor.window_position(window_size, scale_factor, offset);
+
+                window::move_to(main_window_id, position)
+            })
+        })
     }
 
     fn reset_window_state(&mut self) -> Task<AppMsg> {
-        self.prompt = "".to_string();
+        self.search_history.reset();
 
         self.client_context.clear_all_inline_views();
 
-        GlobalState::initial(&mut self.global_state)
+        let initial_task = GlobalState::initial(&mut self.global_state, self.main_list_rows_per_view);
+
+        if !self.restore_last_query || self.prompt.is_empty() {
+            self.prompt = "".to_string();
+
+            return initial_task;
+        }
+
+        match &self.global_state {
+            GlobalState::MainView { search_field_id, .. } => {
+                // keep the query so it is shown on next open, selected so that typing replaces it
+                Task::batch([initial_task, text_input::select_all(search_field_id.clone())])
+            }
+            _ => initial_task,
+        }
     }
 
     fn open_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
@@ -2525,6 +3042,14 @@ impl AppModel {
         )
     }
 
+    fn clear_toasts(&mut self, plugin_id: &PluginId) {
+        if let Some(toast) = &self.toast {
+            if &toast.plugin_id == plugin_id {
+                self.toast = None;
+            }
+        }
+    }
+
     fn close_plugin_view(&self, plugin_id: PluginId) -> Task<AppMsg> {
         let mut backend_client = self.backend_api.clone();
 
@@ -2538,6 +3063,23 @@ impl AppModel {
         )
     }
 
+    fn send_confirm_alert_event(&self, confirm_alert: ConfirmAlertState, confirmed: bool) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        let ConfirmAlertState { plugin_id, alert } = confirm_alert;
+
+        Task::perform(
+            async move {
+                backend_client
+                    .send_confirm_alert_event(plugin_id, alert.id, confirmed)
+                    .await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| AppMsg::Noop),
+        )
+    }
+
     fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
         let mut backend_client = self.backend_api.clone();
 
@@ -2764,6 +3306,93 @@ impl AppModel {
         )
     }
 
+    fn fetch_search_history(&self) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move { backend_api.search_history().await }, |result| {
+            handle_backend_error(result, |search_history| AppMsg::SetSearchHistory(search_history))
+        })
+    }
+
+    fn save_search_history_entry(&mut self) -> Task<AppMsg> {
+        let query = self.prompt.trim().to_string();
+
+        if query.is_empty() {
+            return Task::none();
+        }
+
+        self.search_history.push(query.clone());
+
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(
+            async move {
+                backend_api.save_search_history_entry(query).await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| AppMsg::Noop),
+        )
+    }
+
+    fn recall_older_search_history(&mut self) -> Option<Task<AppMsg>> {
+        let GlobalState::MainView {
+            focused_search_result,
+            sub_state: MainViewState::None,
+            ..
+        } = &self.global_state
+        else {
+            return None;
+        };
+
+        // only start recalling if up arrow would not move the focus in the search list anyway
+        let list_at_top = focused_search_result.index.unwrap_or(0) == 0;
+
+        if !self.search_history.is_recalling() && !(self.prompt.is_empty() && list_at_top) {
+            return None;
+        }
+
+        let query = self.search_history.older()?;
+
+        Some(self.set_prompt_from_history(query))
+    }
+
+    fn recall_newer_search_history(&mut self) -> Option<Task<AppMsg>> {
+        let GlobalState::MainView {
+            sub_state: MainViewState::None,
+            ..
+        } = &self.global_state
+        else {
+            return None;
+        };
+
+        let query = self.search_history.newer()?;
+
+        Some(self.set_prompt_from_history(query))
+    }
+
+    fn set_prompt_from_history(&mut self, query: String) -> Task<AppMsg> {
+        let GlobalState::MainView {
+            focused_search_result,
+            search_field_id,
+            ..
+        } = &mut self.global_state
+        else {
+            return Task::none();
+        };
+
+        focused_search_result.reset(true);
+
+        let search_field_id = search_field_id.clone();
+
+        self.prompt = query.clone();
+
+        Task::batch([
+            text_input::move_cursor_to_end(search_field_id),
+            self.search(query, true),
+        ])
+    }
+
     fn inline_view_shortcuts(&self) -> Task<AppMsg> {
         let mut backend_api = self.backend_api.clone();
 
@@ -2772,6 +3401,74 @@ impl AppModel {
         })
     }
 
+    // returns None if action is not applicable in current state
+    fn run_launcher_action(&mut self, action: LauncherAction) -> Option<Task<AppMsg>> {
+        match action {
+            LauncherAction::ToggleActionPanel => {
+                match &self.global_state {
+                    GlobalState::MainView { .. } | GlobalState::PluginView { .. } => {
+                        Some(Task::perform(async {}, |_| {
+                            AppMsg::ToggleActionPanel { keyboard: true }
+                        }))
+                    }
+                    GlobalState::ErrorView { .. } | GlobalState::PendingPluginView { .. } => None,
+                }
+            }
+            LauncherAction::OpenSettings => {
+                match &self.global_state {
+                    GlobalState::MainView {
+                        sub_state: MainViewState::None,
+                        ..
+                    } => {
+                        crate::open_settings_window();
+
+                        Some(Task::none())
+                    }
+                    _ => None,
+                }
+            }
+            LauncherAction::RunPrimaryAction => {
+                Some(self.global_state.primary(&self.client_context, &self.search_results))
+            }
+            LauncherAction::RunSecondaryAction => {
+                // for main view, also fired in cases where main text field is not focused
+                Some(self.global_state.secondary(&self.client_context, &self.search_results))
+            }
+            LauncherAction::Back => {
+                Some(
+                    self.global_state
+                        .back(&self.client_context, self.main_list_rows_per_view),
+                )
+            }
+            LauncherAction::RecallOlderQuery => self.recall_older_search_history(),
+            LauncherAction::RecallNewerQuery => self.recall_newer_search_history(),
+            LauncherAction::ToggleDetail => {
+                match &self.global_state {
+                    GlobalState::PluginView { .. } => {
+                        if self.client_context.toggle_detail() {
+                            Some(Task::none())
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            LauncherAction::SubmitForm => {
+                match &self.global_state {
+                    GlobalState::PluginView { .. } => self.client_context.submit_form(),
+                    _ => None,
+                }
+            }
+            LauncherAction::CycleSearchBarAccessory => {
+                match &self.global_state {
+                    GlobalState::PluginView { .. } => self.client_context.cycle_search_bar_accessory(),
+                    _ => None,
+                }
+            }
+        }
+    }
+
     fn handle_shortcut_key(
         &mut self,
         physical_key: Physical,
@@ -2791,25 +3488,20 @@ impl AppModel {
             } => {
                 match sub_state {
                     MainViewState::None => {
-                        match physical_key_model(physical_key, modifiers) {
-                            Some(PhysicalShortcut {
-                                physical_key: PhysicalKey::Comma,
-                                modifier_shift: false,
-                                modifier_control: cfg!(any(target_os = "linux", target_os = "windows")),
-                                modifier_alt: false,
-                                modifier_meta: cfg!(target_os = "macos"),
-                            }) => {
-                                crate::open_settings_window();
+                        // quick pick is not applied when keyboard events go to inline view
+                        let quick_pick_enabled = focused_search_result.index.is_some();
 
-                                Task::none()
+                        match physical_key_model(physical_key, modifiers) {
+                            Some(shortcut) if quick_pick_enabled && quick_pick_index(&shortcut).is_some() => {
+                                match quick_pick_index(&shortcut).and_then(|index| self.search_results.get(index)) {
+                                    Some(search_result) => {
+                                        Task::done(AppMsg::OnPrimaryActionMainViewNoPanel {
+                                            search_result: search_result.clone(),
+                                        })
+                                    }
+                                    None => Task::none(),
+                                }
                             }
-                            Some(PhysicalShortcut {
-                                physical_key: PhysicalKey::KeyK,
-                                modifier_shift: false,
-                                modifier_control: false,
-                                modifier_alt: true,
-                                modifier_meta: false,
-                            }) => Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true }),
                             Some(PhysicalShortcut {
                                 physical_key,
                                 modifier_shift,
@@ -2850,13 +3542,6 @@ impl AppModel {
                     }
                     MainViewState::SearchResultActionPanel { .. } => {
                         match physical_key_model(physical_key, modifiers) {
-                            Some(PhysicalShortcut {
-                                physical_key: PhysicalKey::KeyK,
-                                modifier_shift: false,
-                                modifier_control: false,
-                                modifier_alt: true,
-                                modifier_meta: false,
-                            }) => Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true }),
                             Some(PhysicalShortcut {
                                 physical_key,
                                 modifier_shift,
@@ -2891,13 +3576,6 @@ impl AppModel {
                     }
                     MainViewState::InlineViewActionPanel { .. } => {
                         match physical_key_model(physical_key, modifiers) {
-                            Some(PhysicalShortcut {
-                                physical_key: PhysicalKey::KeyK,
-                                modifier_shift: false,
-                                modifier_control: false,
-                                modifier_alt: true,
-                                modifier_meta: false,
-                            }) => Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true }),
                             Some(PhysicalShortcut {
                                 physical_key,
                                 modifier_shift,
@@ -2925,13 +3603,6 @@ impl AppModel {
             GlobalState::ErrorView { .. } => Task::none(),
             GlobalState::PluginView { sub_state, .. } => {
                 match physical_key_model(physical_key, modifiers) {
-                    Some(PhysicalShortcut {
-                        physical_key: PhysicalKey::KeyK,
-                        modifier_shift: false,
-                        modifier_control: false,
-                        modifier_alt: true,
-                        modifier_meta: false,
-                    }) => Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true }),
                     Some(PhysicalShortcut {
                         physical_key,
                         modifier_shift,
@@ -3054,6 +3725,12 @@ async fn request_loop(
 
                 AppMsg::HideWindow
             }
+            FrontendApiRequestData::PasteToActiveWindow { cursor_offset_from_end } => {
+                AppMsg::PasteToActiveWindow {
+                    cursor_offset_from_end,
+                    responder: Arc::new(Mutex::new(Some(responder))),
+                }
+            }
             FrontendApiRequestData::ShowPreferenceRequiredView {
                 plugin_id,
                 entrypoint_id,
@@ -3092,6 +3769,26 @@ async fn request_loop(
 
                 AppMsg::ShowHud { display }
             }
+            FrontendApiRequestData::ShowToast { plugin_id, toast } => {
+                responder.respond(Ok(FrontendApiResponseData::ShowToast { data: () }));
+
+                AppMsg::ShowToast { plugin_id, toast }
+            }
+            FrontendApiRequestData::HideToast { plugin_id, toast_id } => {
+                responder.respond(Ok(FrontendApiResponseData::HideToast { data: () }));
+
+                AppMsg::HideToast { plugin_id, toast_id }
+            }
+            FrontendApiRequestData::ClearToasts { plugin_id } => {
+                responder.respond(Ok(FrontendApiResponseData::ClearToasts { data: () }));
+
+                AppMsg::ClearToasts { plugin_id }
+            }
+            FrontendApiRequestData::ShowConfirmAlert { plugin_id, alert } => {
+                responder.respond(Ok(FrontendApiResponseData::ShowConfirmAlert { data: () }));
+
+                AppMsg::ShowConfirmAlert { plugin_id, alert }
+            }
             FrontendApiRequestData::SetGlobalShortcut { shortcut } => {
                 AppMsg::SetGlobalShortcut {
                     shortcut,
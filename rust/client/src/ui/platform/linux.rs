@@ -1,16 +1,26 @@
 use std::convert::Infallible;
+use std::time::Duration;
+use std::time::Instant;
 
 use anyhow::anyhow;
 use iced::futures::channel::mpsc::Sender;
 use iced::futures::SinkExt;
+use iced::Point;
+use iced::Size;
 use tokio::runtime::Handle;
 use x11rb::connection::Connection;
 use x11rb::properties::WmClass;
+use x11rb::protocol::randr::ConnectionExt as RandrConnectionExt;
 use x11rb::protocol::xproto::AtomEnum;
 use x11rb::protocol::xproto::ChangeWindowAttributesAux;
+use x11rb::protocol::xproto::ClientMessageEvent;
 use x11rb::protocol::xproto::ConnectionExt;
 use x11rb::protocol::xproto::EventMask;
+use x11rb::protocol::xproto::Keysym;
 use x11rb::protocol::xproto::Window;
+use x11rb::protocol::xproto::KEY_PRESS_EVENT;
+use x11rb::protocol::xproto::KEY_RELEASE_EVENT;
+use x11rb::protocol::xtest::ConnectionExt as XTestConnectionExt;
 use x11rb::rust_connection::RustConnection;
 
 use crate::ui::AppMsg;
@@ -40,6 +50,191 @@ pub fn listen_on_x11_active_window_change(sender: Sender<AppMsg>, handle: Handle
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct X11Monitor {
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl X11Monitor {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+
+    /// Top-left position of the window in logical coordinates.
+    /// `offset` is position relative to top-left corner of the monitor in physical pixels,
+    /// if not specified window is centered
+    pub fn window_position(&self, window_size: Size, scale_factor: f32, offset: Option<(i32, i32)>) -> Point {
+        let (x, y) = match offset {
+            Some((offset_x, offset_y)) => (self.x + offset_x, self.y + offset_y),
+            None => {
+                let width = (window_size.width * scale_factor) as i32;
+                let height = (window_size.height * scale_factor) as i32;
+
+                (self.x + (self.width - width) / 2, self.y + (self.height - height) / 2)
+            }
+        };
+
+        Point::new(x as f32 / scale_factor, y as f32 / scale_factor)
+    }
+
+    /// Position of the window relative to top-left corner of the monitor in physical pixels
+    pub fn window_offset(&self, window_position: Point, scale_factor: f32) -> (i32, i32) {
+        let x = (window_position.x * scale_factor) as i32;
+        let y = (window_position.y * scale_factor) as i32;
+
+        (x - self.x, y - self.y)
+    }
+}
+
+pub fn x11_monitor_with_cursor() -> anyhow::Result<X11Monitor> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+
+    let pointer = conn.query_pointer(root)?.reply()?;
+
+    find_monitor(&conn, root, pointer.root_x as i32, pointer.root_y as i32)
+}
+
+pub fn x11_monitor_with_focused_window() -> anyhow::Result<X11Monitor> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    let window = fetch_window_id(&conn, root, &atoms)?;
+
+    let geometry = conn.get_geometry(window)?.reply()?;
+    let translated = conn.translate_coordinates(window, root, 0, 0)?.reply()?;
+
+    let center_x = translated.dst_x as i32 + geometry.width as i32 / 2;
+    let center_y = translated.dst_y as i32 + geometry.height as i32 / 2;
+
+    find_monitor(&conn, root, center_x, center_y)
+}
+
+pub fn x11_monitor_at(window_position: Point, window_size: Size, scale_factor: f32) -> anyhow::Result<X11Monitor> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+
+    let center_x = ((window_position.x + window_size.width / 2.0) * scale_factor) as i32;
+    let center_y = ((window_position.y + window_size.height / 2.0) * scale_factor) as i32;
+
+    find_monitor(&conn, root, center_x, center_y)
+}
+
+const XK_CONTROL_L: Keysym = 0xffe3;
+const XK_V: Keysym = 0x0076;
+const XK_LEFT: Keysym = 0xff51;
+
+const FOCUS_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Activates `window` if specified and sends Ctrl+V followed by `cursor_offset_from_end` Left key presses to it
+pub fn x11_paste_to_window(window: Option<Window>, cursor_offset_from_end: u32) -> anyhow::Result<()> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    if let Some(window) = window {
+        // source indication 2 means request comes from pager, which is what window managers expect
+        // from tools like this, requests from normal applications may be ignored by focus stealing prevention
+        let event = ClientMessageEvent::new(32, window, atoms._NET_ACTIVE_WINDOW, [2, x11rb::CURRENT_TIME, 0, 0, 0]);
+
+        conn.send_event(
+            false,
+            root,
+            EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+            event,
+        )?;
+        conn.flush()?;
+
+        let start = Instant::now();
+        while fetch_window_id(&conn, root, &atoms).ok() != Some(window) {
+            if start.elapsed() > FOCUS_TIMEOUT {
+                Err(anyhow!("window {} was not activated", window))?
+            }
+
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    let control = find_keycode(&conn, XK_CONTROL_L)?;
+    let v = find_keycode(&conn, XK_V)?;
+    let left = find_keycode(&conn, XK_LEFT)?;
+
+    let mut events = vec![
+        (KEY_PRESS_EVENT, control),
+        (KEY_PRESS_EVENT, v),
+        (KEY_RELEASE_EVENT, v),
+        (KEY_RELEASE_EVENT, control),
+    ];
+
+    for _ in 0..cursor_offset_from_end {
+        events.push((KEY_PRESS_EVENT, left));
+        events.push((KEY_RELEASE_EVENT, left));
+    }
+
+    for (event_type, keycode) in events {
+        conn.xtest_fake_input(event_type, keycode, x11rb::CURRENT_TIME, root, 0, 0, 0)?;
+    }
+
+    // make sure all events are processed before connection is closed
+    conn.get_input_focus()?.reply()?;
+
+    Ok(())
+}
+
+fn find_keycode(conn: &impl Connection, keysym: Keysym) -> anyhow::Result<u8> {
+    let setup = conn.setup();
+    let min_keycode = setup.min_keycode;
+    let max_keycode = setup.max_keycode;
+
+    let mapping = conn
+        .get_keyboard_mapping(min_keycode, max_keycode - min_keycode + 1)?
+        .reply()?;
+
+    let index = mapping
+        .keysyms
+        .chunks(mapping.keysyms_per_keycode as usize)
+        .position(|keysyms| keysyms.contains(&keysym))
+        .ok_or(anyhow!("no keycode found for keysym {:#x}", keysym))?;
+
+    Ok(min_keycode + index as u8)
+}
+
+fn find_monitor(conn: &impl Connection, root: Window, x: i32, y: i32) -> anyhow::Result<X11Monitor> {
+    let mut monitors = vec![];
+    let mut primary = None;
+
+    for info in conn.randr_get_monitors(root, true)?.reply()?.monitors {
+        let name = conn.get_atom_name(info.name)?.reply()?.name;
+
+        let monitor = X11Monitor {
+            name: String::from_utf8_lossy(&name).to_string(),
+            x: info.x as i32,
+            y: info.y as i32,
+            width: info.width as i32,
+            height: info.height as i32,
+        };
+
+        if info.primary {
+            primary = Some(monitor.clone());
+        }
+
+        monitors.push(monitor);
+    }
+
+    let fallback = primary.or_else(|| monitors.first().cloned());
+
+    monitors
+        .into_iter()
+        .find(|monitor| monitor.contains(x, y))
+        .or(fallback)
+        .ok_or(anyhow!("no monitors found"))
+}
+
 fn fetch_window_id(conn: &impl Connection, root: Window, atoms: &atoms::Atoms) -> anyhow::Result<Window> {
     let window = conn
         .get_property(false, root, atoms._NET_ACTIVE_WINDOW, AtomEnum::WINDOW, 0, 1)?
@@ -0,0 +1,156 @@
+use std::io::Write;
+use std::os::fd::AsFd;
+
+use anyhow::Context;
+use wayland_client::globals::registry_queue_init;
+use wayland_client::globals::GlobalListContents;
+use wayland_client::protocol::wl_keyboard::KeyState;
+use wayland_client::protocol::wl_keyboard::KeymapFormat;
+use wayland_client::protocol::wl_registry;
+use wayland_client::protocol::wl_seat::WlSeat;
+use wayland_client::Connection;
+use wayland_client::Dispatch;
+use wayland_client::QueueHandle;
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::zwp_virtual_keyboard_manager_v1::ZwpVirtualKeyboardManagerV1;
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1;
+
+// evdev key codes, xkb key codes in keymap below are offset by 8
+const KEY_LEFTCTRL: u32 = 29;
+const KEY_V: u32 = 47;
+const KEY_LEFT: u32 = 105;
+
+// mask of Control real modifier
+const MODIFIER_CONTROL: u32 = 1 << 2;
+
+// virtual keyboard has its own keymap, so keys used for pasting
+// are not affected by keyboard layout selected by user
+const KEYMAP: &str = r#"xkb_keymap {
+    xkb_keycodes "gauntlet" {
+        minimum = 8;
+        maximum = 255;
+        <LCTL> = 37;
+        <AB04> = 55;
+        <LEFT> = 113;
+    };
+    xkb_types "gauntlet" { include "complete" };
+    xkb_compatibility "gauntlet" { include "complete" };
+    xkb_symbols "gauntlet" {
+        key <LCTL> { [ Control_L ] };
+        key <AB04> { [ v, V ] };
+        key <LEFT> { [ Left ] };
+        modifier_map Control { <LCTL> };
+    };
+};
+"#;
+
+struct VirtualKeyboardState;
+
+/// Sends Ctrl+V followed by `cursor_offset_from_end` Left key presses to the focused window
+/// using virtual keyboard protocol, which is supported by wlroots-based compositors
+pub fn wayland_send_paste_keystroke(cursor_offset_from_end: u32) -> anyhow::Result<()> {
+    let conn = Connection::connect_to_env()?;
+
+    let (globals, mut event_queue) = registry_queue_init::<VirtualKeyboardState>(&conn)?;
+    let queue_handle = event_queue.handle();
+
+    let seat = globals.bind::<WlSeat, _, _>(&queue_handle, 1..=1, ())?;
+
+    let manager = globals
+        .bind::<ZwpVirtualKeyboardManagerV1, _, _>(&queue_handle, 1..=1, ())
+        .context("Compositor doesn't support virtual keyboard protocol")?;
+
+    let keyboard = manager.create_virtual_keyboard(&seat, &queue_handle, ());
+
+    let keymap = keymap_file()?;
+
+    keyboard.keymap(KeymapFormat::XkbV1.into(), keymap.as_fd(), KEYMAP.len() as u32 + 1);
+
+    event_queue.roundtrip(&mut VirtualKeyboardState)?;
+
+    let mut time = 0;
+    let mut key = |key: u32, state: KeyState| {
+        time += 1;
+        keyboard.key(time, key, state.into());
+    };
+
+    key(KEY_LEFTCTRL, KeyState::Pressed);
+    keyboard.modifiers(MODIFIER_CONTROL, 0, 0, 0);
+    key(KEY_V, KeyState::Pressed);
+    key(KEY_V, KeyState::Released);
+    key(KEY_LEFTCTRL, KeyState::Released);
+    keyboard.modifiers(0, 0, 0, 0);
+
+    for _ in 0..cursor_offset_from_end {
+        key(KEY_LEFT, KeyState::Pressed);
+        key(KEY_LEFT, KeyState::Released);
+    }
+
+    event_queue.roundtrip(&mut VirtualKeyboardState)?;
+
+    keyboard.destroy();
+    manager.destroy();
+
+    event_queue.roundtrip(&mut VirtualKeyboardState)?;
+
+    Ok(())
+}
+
+// keymap is passed to compositor as file descriptor,
+// file is anonymous so concurrent pastes don't share it
+fn keymap_file() -> anyhow::Result<std::fs::File> {
+    let mut file = tempfile::tempfile()?;
+
+    file.write_all(KEYMAP.as_bytes())?;
+    file.write_all(&[0])?;
+    file.flush()?;
+
+    Ok(file)
+}
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for VirtualKeyboardState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &wl_registry::WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<WlSeat, ()> for VirtualKeyboardState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WlSeat,
+        _event: <WlSeat as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpVirtualKeyboardManagerV1, ()> for VirtualKeyboardState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpVirtualKeyboardManagerV1,
+        _event: <ZwpVirtualKeyboardManagerV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpVirtualKeyboardV1, ()> for VirtualKeyboardState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpVirtualKeyboardV1,
+        _event: <ZwpVirtualKeyboardV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
@@ -1,14 +1,36 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use iced::widget::scrollable::scroll_to;
 use iced::widget::scrollable::AbsoluteOffset;
 use iced::widget::scrollable::Id;
+use iced::widget::scrollable::Viewport;
 use iced::Task;
 
 use crate::ui::AppMsg;
 
 pub const ESTIMATED_MAIN_LIST_ITEM_HEIGHT: f32 = 38.8;
 pub const ESTIMATED_ACTION_ITEM_HEIGHT: f32 = 38.8; // TODO
+pub const ESTIMATED_SECTION_TITLE_HEIGHT: f32 = 35.5;
+
+// how close to the end of list or grid, in rows, focus or scroll position needs to be to request more items
+pub const LOAD_MORE_THRESHOLD_ROWS: usize = 5;
+
+// amount of rows that fit into main list with default window height
+const DEFAULT_MAIN_LIST_ROWS_PER_VIEW: usize = 7;
+const DEFAULT_MAIN_WINDOW_HEIGHT: f32 = 450.0;
+
+pub fn main_list_rows_per_view(window_height: f32) -> usize {
+    let extra_rows = ((window_height - DEFAULT_MAIN_WINDOW_HEIGHT) / ESTIMATED_MAIN_LIST_ITEM_HEIGHT).floor() as isize;
+
+    (DEFAULT_MAIN_LIST_ROWS_PER_VIEW as isize + extra_rows).max(1) as usize
+}
+
+pub fn is_viewport_near_end(viewport: &Viewport, item_height: f32) -> bool {
+    let remaining = viewport.content_bounds().height - viewport.bounds().height - viewport.absolute_offset().y;
+
+    remaining < LOAD_MORE_THRESHOLD_ROWS as f32 * item_height
+}
 
 #[derive(Clone, Debug)]
 pub struct ScrollHandle {
@@ -17,6 +39,10 @@ pub struct ScrollHandle {
     offset: usize,
     rows_per_view: usize,
     item_height: f32,
+    // last known vertical scroll position, in pixels
+    position: f32,
+    // indexes of rows that have section title rendered above them, sorted
+    section_title_rows: Vec<usize>,
 }
 
 impl ScrollHandle {
@@ -27,12 +53,15 @@ impl ScrollHandle {
             offset: 0,
             rows_per_view,
             item_height,
+            position: 0.0,
+            section_title_rows: vec![],
         }
     }
 
     pub fn reset(&mut self, first_focused: bool) {
         self.index = if first_focused { Some(0) } else { None };
         self.offset = 0;
+        self.position = 0.0;
     }
 
     pub fn unfocus(&mut self) {
@@ -49,7 +78,11 @@ impl ScrollHandle {
     pub fn focus_next(&mut self, total_item_amount: usize) -> Option<Task<AppMsg>> {
         match self.focus_next_in(total_item_amount, 1) {
             None => None,
-            Some(index) => Some(self.scroll_to(index)),
+            Some(index) => {
+                self.position = self.scroll_position(index);
+
+                Some(self.scroll_to(index))
+            }
         }
     }
 
@@ -88,7 +121,11 @@ impl ScrollHandle {
     pub fn focus_previous(&mut self) -> Option<Task<AppMsg>> {
         match self.focus_previous_in(1) {
             None => None,
-            Some(index) => Some(self.scroll_to(index)),
+            Some(index) => {
+                self.position = self.scroll_position(index);
+
+                Some(self.scroll_to(index))
+            }
         }
     }
 
@@ -112,8 +149,59 @@ impl ScrollHandle {
     }
 
     pub fn scroll_to<Message: 'static>(&self, row_index: usize) -> Task<Message> {
-        let pos_y = row_index as f32 * self.item_height - (self.offset as f32 * self.item_height);
+        let pos_y = self.scroll_position(row_index);
 
         scroll_to(self.scrollable_id.clone(), AbsoluteOffset { x: 0.0, y: pos_y })
     }
+
+    fn scroll_position(&self, row_index: usize) -> f32 {
+        // section title of the row itself is not counted, so that it is visible when the row is scrolled to
+        let section_titles = self
+            .section_title_rows
+            .iter()
+            .filter(|title_row| **title_row < row_index)
+            .count();
+
+        let row_position = row_index as f32 * self.item_height + section_titles as f32 * ESTIMATED_SECTION_TITLE_HEIGHT;
+
+        row_position - (self.offset as f32 * self.item_height)
+    }
+
+    pub fn set_section_title_rows(&mut self, section_title_rows: Vec<usize>) {
+        self.section_title_rows = section_title_rows;
+    }
+
+    pub fn set_position(&mut self, position: f32) {
+        self.position = position;
+    }
+
+    pub fn item_height(&self) -> f32 {
+        self.item_height
+    }
+
+    // rows that are close enough to the viewport to be worth laying out,
+    // position is converted to rows using estimated item height so some overscan is needed
+    pub fn visible_rows(&self, total_item_amount: usize, overscan: usize) -> Range<usize> {
+        let position = self.position.max(0.0);
+
+        // height of section titles above the position is not part of any row
+        let mut section_titles_height = 0.0;
+
+        for title_row in &self.section_title_rows {
+            let title_position = *title_row as f32 * self.item_height + section_titles_height;
+
+            if title_position > position {
+                break;
+            }
+
+            section_titles_height += ESTIMATED_SECTION_TITLE_HEIGHT;
+        }
+
+        let first_row = ((position - section_titles_height).max(0.0) / self.item_height).floor() as usize;
+
+        let start = first_row.saturating_sub(overscan);
+        let end = first_row + self.rows_per_view + overscan;
+
+        start.min(total_item_amount)..end.min(total_item_amount)
+    }
 }
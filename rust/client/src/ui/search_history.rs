@@ -0,0 +1,59 @@
+#[derive(Clone, Debug, Default)]
+pub struct SearchHistory {
+    // most recent first
+    entries: Vec<String>,
+    index: Option<usize>,
+}
+
+impl SearchHistory {
+    pub fn set_entries(&mut self, entries: Vec<String>) {
+        self.entries = entries;
+        self.index = None;
+    }
+
+    pub fn push(&mut self, query: String) {
+        self.entries.retain(|entry| entry != &query);
+        self.entries.insert(0, query);
+        self.index = None;
+    }
+
+    pub fn is_recalling(&self) -> bool {
+        self.index.is_some()
+    }
+
+    pub fn reset(&mut self) {
+        self.index = None;
+    }
+
+    pub fn older(&mut self) -> Option<String> {
+        let new_index = match self.index {
+            None => 0,
+            Some(index) => index + 1,
+        };
+
+        let entry = self.entries.get(new_index)?;
+
+        self.index = Some(new_index);
+
+        Some(entry.clone())
+    }
+
+    pub fn newer(&mut self) -> Option<String> {
+        match self.index {
+            None => None,
+            Some(0) => {
+                // going past the most recent entry returns to the empty prompt
+                self.index = None;
+
+                Some("".to_string())
+            }
+            Some(index) => {
+                let new_index = index - 1;
+
+                self.index = Some(new_index);
+
+                self.entries.get(new_index).cloned()
+            }
+        }
+    }
+}
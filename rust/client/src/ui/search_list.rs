@@ -20,10 +20,12 @@ use iced::Alignment;
 use iced::Font;
 use iced::Length;
 
+use crate::ui::keymap::quick_pick_label;
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::image::ImageStyle;
+use crate::ui::theme::row::RowStyle;
 use crate::ui::theme::space::ThemeKindSpace;
 use crate::ui::theme::text::TextStyle;
 use crate::ui::theme::Element;
@@ -35,7 +37,7 @@ pub fn search_list<'a>(
     search_results: &'a [SearchResult],
     focused_search_result: &ScrollHandle,
 ) -> Element<'a, SearchResult> {
-    let items: Vec<Element<_>> = search_results
+    let search_result_items: Vec<Element<_>> = search_results
         .iter()
         .enumerate()
         .map(|(index, search_result)| {
@@ -136,6 +138,17 @@ pub fn search_list<'a>(
 
             button_content.push(type_text);
 
+            if let Some(quick_pick_label) = quick_pick_label(index) {
+                let quick_pick_text: Element<_> = text(quick_pick_label)
+                    .shaping(Shaping::Advanced)
+                    .themed(TextStyle::MainListItemSubtext);
+
+                let quick_pick_text: Element<_> =
+                    container(quick_pick_text).themed(ContainerStyle::MainListItemSubText);
+
+                button_content.push(quick_pick_text);
+            }
+
             let button_content: Element<_> = row(button_content).align_y(Alignment::Center).into();
 
             let style = match focused_search_result.index {
@@ -156,5 +169,51 @@ pub fn search_list<'a>(
         })
         .collect();
 
+    let mut items = vec![];
+    let mut current_section = None;
+
+    for (search_result, search_result_item) in search_results.iter().zip(search_result_items) {
+        if search_result.section != current_section {
+            if let Some(section) = &search_result.section {
+                let section_title_style = if current_section.is_none() {
+                    RowStyle::ListFirstSectionTitle
+                } else {
+                    RowStyle::ListSectionTitle
+                };
+
+                let section_title: Element<_> = text(section.to_string())
+                    .shaping(Shaping::Advanced)
+                    .size(15)
+                    .themed(TextStyle::ListSectionTitle);
+
+                let section_title: Element<_> = row([section_title]).themed(section_title_style);
+
+                items.push(section_title);
+            }
+
+            current_section = search_result.section.clone();
+        }
+
+        items.push(search_result_item);
+    }
+
     column(items).into()
 }
+
+// indexes of search results that have section title rendered above them
+pub fn search_list_section_title_rows(search_results: &[SearchResult]) -> Vec<usize> {
+    let mut rows = vec![];
+    let mut current_section = None;
+
+    for (index, search_result) in search_results.iter().enumerate() {
+        if search_result.section != current_section {
+            if search_result.section.is_some() {
+                rows.push(index);
+            }
+
+            current_section = search_result.section.clone();
+        }
+    }
+
+    rows
+}
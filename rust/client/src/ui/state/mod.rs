@@ -14,6 +14,7 @@ use iced::Task;
 use crate::ui::client_context::ClientContext;
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::scroll_handle::ESTIMATED_MAIN_LIST_ITEM_HEIGHT;
+use crate::ui::search_list::search_list_section_title_rows;
 pub use crate::ui::state::main_view::MainViewState;
 pub use crate::ui::state::plugin_view::PluginViewState;
 use crate::ui::AppMsg;
@@ -80,10 +81,10 @@ pub enum LoadingBarState {
 }
 
 impl GlobalState {
-    pub fn new(search_field_id: text_input::Id) -> GlobalState {
+    pub fn new(search_field_id: text_input::Id, main_list_rows_per_view: usize) -> GlobalState {
         GlobalState::MainView {
             search_field_id,
-            focused_search_result: ScrollHandle::new(true, ESTIMATED_MAIN_LIST_ITEM_HEIGHT, 7),
+            focused_search_result: ScrollHandle::new(true, ESTIMATED_MAIN_LIST_ITEM_HEIGHT, main_list_rows_per_view),
             sub_state: MainViewState::new(),
             pending_plugin_view_data: None,
             pending_plugin_view_loading_bar: LoadingBarState::Off,
@@ -109,10 +110,10 @@ impl GlobalState {
         }
     }
 
-    pub fn initial(prev_global_state: &mut GlobalState) -> Task<AppMsg> {
+    pub fn initial(prev_global_state: &mut GlobalState, main_list_rows_per_view: usize) -> Task<AppMsg> {
         let search_field_id = text_input::Id::unique();
 
-        *prev_global_state = GlobalState::new(search_field_id.clone());
+        *prev_global_state = GlobalState::new(search_field_id.clone(), main_list_rows_per_view);
 
         Task::batch([focus(search_field_id), Task::done(AppMsg::UpdateSearchResults)])
     }
@@ -143,7 +144,7 @@ impl GlobalState {
 pub trait Focus<T> {
     fn primary(&mut self, client_context: &ClientContext, focus_list: &[T]) -> Task<AppMsg>;
     fn secondary(&mut self, client_context: &ClientContext, focus_list: &[T]) -> Task<AppMsg>;
-    fn back(&mut self, client_context: &ClientContext) -> Task<AppMsg>;
+    fn back(&mut self, client_context: &ClientContext, main_list_rows_per_view: usize) -> Task<AppMsg>;
     fn next(&mut self, client_context: &ClientContext) -> Task<AppMsg>;
     fn previous(&mut self, client_context: &ClientContext) -> Task<AppMsg>;
     fn up(&mut self, client_context: &mut ClientContext, focus_list: &[T]) -> Task<AppMsg>;
@@ -199,11 +200,12 @@ impl Focus<SearchResult> for GlobalState {
             }
             GlobalState::PluginView { sub_state, .. } => {
                 let action_ids = client_context.get_action_ids();
+                let plugin_action_ids = client_context.get_plugin_action_ids();
                 let focused_item_id = client_context.get_focused_item_id();
 
                 match sub_state {
                     PluginViewState::None => {
-                        if let Some(widget_id) = action_ids.get(0) {
+                        if let Some(widget_id) = plugin_action_ids.get(0) {
                             let widget_id = *widget_id;
                             Task::done(AppMsg::OnAnyActionPluginViewNoPanelKeyboardWithFocus {
                                 widget_id,
@@ -256,7 +258,7 @@ impl Focus<SearchResult> for GlobalState {
                 }
             }
             GlobalState::PluginView { sub_state, .. } => {
-                let action_ids = client_context.get_action_ids();
+                let action_ids = client_context.get_plugin_action_ids();
                 let focused_item_id = client_context.get_focused_item_id();
 
                 match sub_state {
@@ -282,7 +284,7 @@ impl Focus<SearchResult> for GlobalState {
         }
     }
 
-    fn back(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
+    fn back(&mut self, _client_context: &ClientContext, main_list_rows_per_view: usize) -> Task<AppMsg> {
         match self {
             GlobalState::MainView { sub_state, .. } => {
                 match sub_state {
@@ -321,7 +323,7 @@ impl Focus<SearchResult> for GlobalState {
                             } else {
                                 Task::batch([
                                     Task::done(AppMsg::ClosePluginView(plugin_id)),
-                                    GlobalState::initial(self),
+                                    GlobalState::initial(self, main_list_rows_per_view),
                                 ])
                             }
                         } else {
@@ -353,7 +355,7 @@ impl Focus<SearchResult> for GlobalState {
             GlobalState::PendingPluginView { .. } => Task::none(),
         }
     }
-    fn up(&mut self, client_context: &mut ClientContext, _focus_list: &[SearchResult]) -> Task<AppMsg> {
+    fn up(&mut self, client_context: &mut ClientContext, focus_list: &[SearchResult]) -> Task<AppMsg> {
         match self {
             GlobalState::MainView {
                 focused_search_result,
@@ -361,7 +363,11 @@ impl Focus<SearchResult> for GlobalState {
                 ..
             } => {
                 match sub_state {
-                    MainViewState::None => focused_search_result.focus_previous().unwrap_or_else(|| Task::none()),
+                    MainViewState::None => {
+                        focused_search_result.set_section_title_rows(search_list_section_title_rows(focus_list));
+
+                        focused_search_result.focus_previous().unwrap_or_else(|| Task::none())
+                    }
                     MainViewState::SearchResultActionPanel { focused_action_item } => {
                         focused_action_item.focus_previous().unwrap_or_else(|| Task::none())
                     }
@@ -392,6 +398,8 @@ impl Focus<SearchResult> for GlobalState {
                 match sub_state {
                     MainViewState::None => {
                         if focus_list.len() != 0 {
+                            focused_search_result.set_section_title_rows(search_list_section_title_rows(focus_list));
+
                             focused_search_result
                                 .focus_next(focus_list.len())
                                 .unwrap_or_else(|| Task::none())
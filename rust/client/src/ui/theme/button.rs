@@ -22,6 +22,9 @@ pub enum ButtonStyle {
 
     Action,
     ActionFocused,
+    FormInputButton,
+    FormInputFileBrowserEntry,
+    FormInputTagPickerItem,
     GridItem,
     GridItemFocused,
     ListItem,
@@ -33,6 +36,10 @@ pub enum ButtonStyle {
     RootBottomPanelPrimaryActionButton,
     RootTopPanelBackButton,
     MetadataTagItem,
+    ToastAction,
+    ConfirmAlertAction,
+    ConfirmAlertDestructiveAction,
+    TableHeaderCell,
 }
 
 impl ButtonStyle {
@@ -76,12 +83,32 @@ impl ButtonStyle {
                 theme.padding.to_iced()
             }
             ButtonStyle::MetadataLink => padding_all(0.0).to_iced(),
-            ButtonStyle::MetadataTagItem => {
+            ButtonStyle::MetadataTagItem | ButtonStyle::FormInputTagPickerItem | ButtonStyle::ToastAction => {
                 let theme = &theme.metadata_tag_item_button;
                 theme.padding.to_iced()
             }
+            ButtonStyle::FormInputFileBrowserEntry => {
+                let theme = &theme.list_item;
+
+                theme.padding.to_iced()
+            }
+            ButtonStyle::ConfirmAlertAction => {
+                let theme = &theme.confirm_alert_action;
+
+                theme.padding.to_iced()
+            }
+            ButtonStyle::ConfirmAlertDestructiveAction => {
+                let theme = &theme.confirm_alert_destructive_action;
+
+                theme.padding.to_iced()
+            }
+            ButtonStyle::TableHeaderCell => {
+                let theme = &theme.table_header_cell;
+
+                theme.padding.to_iced()
+            }
             ButtonStyle::ShouldNotBeUsed => padding_all(5.0).to_iced(),
-            ButtonStyle::DatePicker => padding_all(5.0).to_iced(),
+            ButtonStyle::DatePicker | ButtonStyle::FormInputButton => padding_all(5.0).to_iced(),
         }
     }
 
@@ -109,6 +136,45 @@ impl ButtonStyle {
                     &theme.border_color,
                 )
             }
+            ButtonStyle::ConfirmAlertAction => {
+                let theme = &theme.confirm_alert_action;
+                (
+                    Some(&theme.background_color),
+                    Some(&theme.background_color_hovered),
+                    Some(&theme.background_color_focused),
+                    &theme.text_color,
+                    &theme.text_color_hovered,
+                    &theme.border_radius,
+                    &theme.border_width,
+                    &theme.border_color,
+                )
+            }
+            ButtonStyle::ConfirmAlertDestructiveAction => {
+                let theme = &theme.confirm_alert_destructive_action;
+                (
+                    Some(&theme.background_color),
+                    Some(&theme.background_color_hovered),
+                    Some(&theme.background_color_focused),
+                    &theme.text_color,
+                    &theme.text_color_hovered,
+                    &theme.border_radius,
+                    &theme.border_width,
+                    &theme.border_color,
+                )
+            }
+            ButtonStyle::TableHeaderCell => {
+                let theme = &theme.table_header_cell;
+                (
+                    Some(&theme.background_color),
+                    Some(&theme.background_color_hovered),
+                    Some(&theme.background_color_focused),
+                    &theme.text_color,
+                    &theme.text_color_hovered,
+                    &theme.border_radius,
+                    &theme.border_width,
+                    &theme.border_color,
+                )
+            }
             ButtonStyle::RootTopPanelBackButton => {
                 let theme = &theme.root_top_panel_button;
                 (
@@ -239,7 +305,7 @@ impl ButtonStyle {
                     &Color::TRANSPARENT,
                 )
             }
-            ButtonStyle::MetadataTagItem => {
+            ButtonStyle::MetadataTagItem | ButtonStyle::FormInputTagPickerItem | ButtonStyle::ToastAction => {
                 let theme = &theme.metadata_tag_item_button;
                 (
                     Some(&theme.background_color),
@@ -264,7 +330,20 @@ impl ButtonStyle {
                     &Color::TRANSPARENT,
                 )
             }
-            ButtonStyle::DatePicker => {
+            ButtonStyle::FormInputFileBrowserEntry => {
+                let theme = &theme.list_item;
+                (
+                    Some(&theme.background_color),
+                    Some(&theme.background_color_hovered),
+                    Some(&theme.background_color),
+                    &theme.text_color,
+                    &theme.text_color_hovered,
+                    &theme.border_radius,
+                    &theme.border_width,
+                    &theme.border_color,
+                )
+            }
+            ButtonStyle::DatePicker | ButtonStyle::FormInputButton => {
                 let theme = &theme.form_input_date_picker_buttons;
                 (
                     Some(&theme.background_color),
@@ -21,6 +21,7 @@ pub enum ContainerStyle {
     ActionShortcutModifiersInit, // "init" means every item on list except last one
     ContentCodeBlock,
     ContentCodeBlockText,
+    ContentCodeBlockLineNumber,
     ContentHorizontalBreak,
     ContentImage,
     ContentParagraph,
@@ -29,10 +30,13 @@ pub enum ContainerStyle {
     DetailMetadata,
     EmptyViewImage,
     FormInputLabel,
+    FormInputError,
+    FormInputInfo,
     Inline,
     ListItemSubtitle,
     ListItemTitle,
     ListItemIcon,
+    LoadingFooter,
     Main,
     MainList,
     MainListInner,
@@ -71,6 +75,19 @@ pub enum ContainerStyle {
     HudInner,
     Hud,
     RootBottomPanelPrimaryActionButton,
+    Toast,
+    ToastIcon,
+    ToastMessage,
+    ToastLoadingBar,
+    ConfirmAlert,
+    ConfirmAlertBackdrop,
+    ConfirmAlertIcon,
+    ConfirmAlertTitle,
+    Table,
+    TableInner,
+    TableHeader,
+    TableCell,
+    TableCellIcon,
 }
 
 pub enum ContainerStyleInner {
@@ -88,6 +105,9 @@ pub enum ContainerStyleInner {
     RootBottomPanel,
     InlineInner,
     Hud,
+    Toast,
+    ConfirmAlert,
+    ConfirmAlertBackdrop,
 }
 
 impl container::Catalog for GauntletComplexTheme {
@@ -259,6 +279,54 @@ impl container::Catalog for GauntletComplexTheme {
                     shadow: Default::default(),
                 }
             }
+            ContainerStyleInner::Toast => {
+                let root_theme = &self.popup;
+                let toast_theme = &self.toast;
+                let background_color = &toast_theme.background_color;
+
+                Style {
+                    text_color: None,
+                    background: Some(background_color.clone().into()),
+                    border: Border {
+                        radius: root_theme.border_radius.into(),
+                        width: root_theme.border_width,
+                        color: root_theme.border_color,
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba8(0, 0, 0, 0.50),
+                        offset: Vector::new(0.0, 5.0),
+                        blur_radius: 25.0,
+                    },
+                }
+            }
+            ContainerStyleInner::ConfirmAlert => {
+                let root_theme = &self.popup;
+                let alert_theme = &self.confirm_alert;
+                let background_color = &alert_theme.background_color;
+
+                Style {
+                    text_color: None,
+                    background: Some(background_color.clone().into()),
+                    border: Border {
+                        radius: root_theme.border_radius.into(),
+                        width: root_theme.border_width,
+                        color: root_theme.border_color,
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba8(0, 0, 0, 0.50),
+                        offset: Vector::new(0.0, 5.0),
+                        blur_radius: 25.0,
+                    },
+                }
+            }
+            ContainerStyleInner::ConfirmAlertBackdrop => {
+                let theme = &self.confirm_alert_backdrop;
+
+                Style {
+                    background: Some(theme.background_color.into()),
+                    ..Style::default()
+                }
+            }
             ContainerStyleInner::MainListItemAlias => {
                 let theme = &self.main_list_item_alias;
 
@@ -316,6 +384,7 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::ListItemSubtitle => self.padding(theme.list_item_subtitle.padding.to_iced()),
             ContainerStyle::ListItemTitle => self.padding(theme.list_item_title.padding.to_iced()),
             ContainerStyle::ListItemIcon => self.padding(theme.list_item_icon.padding.to_iced()),
+            ContainerStyle::LoadingFooter => self.padding(theme.loading_footer.padding.to_iced()),
             ContainerStyle::ContentParagraph => self.padding(theme.content_paragraph.padding.to_iced()),
             ContainerStyle::ContentHorizontalBreak => self.padding(theme.content_horizontal_break.padding.to_iced()),
             ContainerStyle::ContentCodeBlock => self.padding(theme.content_code_block.padding.to_iced()),
@@ -323,6 +392,9 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
                 self.class(ContainerStyleInner::ContentCodeBlockText)
                     .padding(theme.content_code_block_text.padding.to_iced())
             }
+            ContainerStyle::ContentCodeBlockLineNumber => {
+                self.padding(theme.content_code_block_line_number.padding.to_iced())
+            }
             ContainerStyle::ContentImage => {
                 self.class(ContainerStyleInner::ContentImage)
                     .padding(theme.content_image.padding.to_iced())
@@ -333,6 +405,8 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::DetailMetadata => self.padding(theme.detail_metadata.padding.to_iced()),
             ContainerStyle::DetailContent => self.padding(theme.detail_content.padding.to_iced()),
             ContainerStyle::FormInputLabel => self.padding(theme.form_input_label.padding.to_iced()),
+            ContainerStyle::FormInputError => self.padding(theme.form_input_error.padding.to_iced()),
+            ContainerStyle::FormInputInfo => self.padding(theme.form_input_info.padding.to_iced()),
             ContainerStyle::Inline => self.padding(theme.inline.padding.to_iced()),
             ContainerStyle::InlineInner => {
                 self.height(120)
@@ -393,6 +467,27 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::IconAccessory => self.padding(theme.icon_accessory.padding.to_iced()),
             ContainerStyle::HudInner => self.padding(theme.hud_content.padding.to_iced()),
             ContainerStyle::Hud => self.class(ContainerStyleInner::Hud),
+            ContainerStyle::Toast => {
+                self.class(ContainerStyleInner::Toast)
+                    .padding(theme.toast.padding.to_iced())
+                    .width(Length::Fixed(350.0))
+            }
+            ContainerStyle::ToastIcon => self.padding(theme.toast_icon.padding.to_iced()),
+            ContainerStyle::ToastMessage => self.padding(theme.toast_message.padding.to_iced()),
+            ContainerStyle::ToastLoadingBar => self.padding(theme.toast_loading_bar.padding.to_iced()),
+            ContainerStyle::ConfirmAlert => {
+                self.class(ContainerStyleInner::ConfirmAlert)
+                    .padding(theme.confirm_alert.padding.to_iced())
+                    .width(Length::Fixed(320.0))
+            }
+            ContainerStyle::ConfirmAlertBackdrop => self.class(ContainerStyleInner::ConfirmAlertBackdrop),
+            ContainerStyle::ConfirmAlertIcon => self.padding(theme.confirm_alert_icon.padding.to_iced()),
+            ContainerStyle::ConfirmAlertTitle => self.padding(theme.confirm_alert_title.padding.to_iced()),
+            ContainerStyle::Table => self.padding(theme.table.padding.to_iced()),
+            ContainerStyle::TableInner => self.padding(theme.table_inner.padding.to_iced()),
+            ContainerStyle::TableHeader => self.padding(theme.table_header.padding.to_iced()),
+            ContainerStyle::TableCell => self.padding(theme.table_cell.padding.to_iced()),
+            ContainerStyle::TableCellIcon => self.padding(theme.table_cell_icon.padding.to_iced()),
         }
         .into()
     }
@@ -17,6 +17,7 @@ pub mod date_picker;
 pub mod grid;
 pub mod image;
 mod loading_bar;
+pub mod number_input;
 pub mod pick_list;
 pub mod row;
 pub mod rule;
@@ -24,6 +25,7 @@ pub mod scrollable;
 pub mod space;
 pub mod svg;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
 pub mod tooltip;
 
@@ -42,6 +44,8 @@ pub struct GauntletComplexTheme {
     action_shortcut_modifier: ThemeActionShortcutModifier,
     content_code_block: ThemePaddingOnly,
     content_code_block_text: ThemeCode,
+    content_code_block_line_number: ThemePaddingTextColor,
+    content_code_block_syntax: ThemeSyntax,
     content_horizontal_break: ThemePaddingOnly,
     content_image: ThemeImage,
     content_paragraph: ThemePaddingOnly,
@@ -53,6 +57,8 @@ pub struct GauntletComplexTheme {
     form_inner: ThemePaddingOnly,
     form_input: ThemePaddingOnly,
     form_input_label: ThemePaddingOnly,
+    form_input_error: ThemePaddingTextColor,
+    form_input_info: ThemePaddingTextColor,
     form_input_date_picker: ThemeDatePicker,
     form_input_date_picker_buttons: ThemeButton,
     form_input_checkbox: ThemeCheckbox,
@@ -78,6 +84,13 @@ pub struct GauntletComplexTheme {
     list_item_icon: ThemePaddingOnly,
     list_section_title: ThemePaddingTextColorSpacing,
     list_section_subtitle: ThemeTextColor,
+    table: ThemePaddingOnly,
+    table_inner: ThemePaddingOnly,
+    table_header: ThemePaddingTextColor,
+    table_header_cell: ThemeButton,
+    table_cell: ThemePaddingOnly,
+    table_cell_icon: ThemePaddingOnly,
+    loading_footer: ThemePaddingTextColor,
     main_list: ThemePaddingOnly,
     main_list_inner: ThemePaddingOnly,
     main_list_item: ThemeButton,
@@ -113,6 +126,21 @@ pub struct GauntletComplexTheme {
     icon_accessory: ThemeIconAccessory,
     hud: ThemeRoot,
     hud_content: ThemePaddingOnly,
+    toast: ThemePaddingBackgroundColor,
+    toast_icon: ThemePaddingOnly,
+    toast_success_icon: ThemeTextColor,
+    toast_failure_icon: ThemeTextColor,
+    toast_message: ThemePaddingTextColor,
+    toast_loading_bar: ThemePaddingOnly,
+    toast_actions: ThemePaddingSpacing,
+    confirm_alert: ThemePaddingBackgroundColor,
+    confirm_alert_backdrop: ThemeBackgroundColor,
+    confirm_alert_icon: ThemePaddingOnly,
+    confirm_alert_title: ThemePaddingOnly,
+    confirm_alert_message: ThemeTextColor,
+    confirm_alert_actions: ThemePaddingSpacing,
+    confirm_alert_action: ThemeButton,
+    confirm_alert_destructive_action: ThemeButton,
 }
 
 impl Default for GauntletComplexTheme {
@@ -323,6 +351,34 @@ impl GauntletComplexTheme {
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             },
+            content_code_block_line_number: ThemePaddingTextColor {
+                padding: padding(0.0, 12.0, 0.0, 0.0),
+                text_color: text_300,
+            },
+            content_code_block_syntax: match mode {
+                UiThemeMode::Light => {
+                    ThemeSyntax {
+                        comment: text_300,
+                        keyword: Color::from_rgb8(0xA6, 0x26, 0xA4),
+                        string: Color::from_rgb8(0x50, 0xA1, 0x4F),
+                        number: Color::from_rgb8(0x98, 0x68, 0x01),
+                        constant: Color::from_rgb8(0x98, 0x68, 0x01),
+                        function: Color::from_rgb8(0x40, 0x78, 0xF2),
+                        type_name: Color::from_rgb8(0xC1, 0x84, 0x01),
+                    }
+                }
+                UiThemeMode::Dark => {
+                    ThemeSyntax {
+                        comment: text_300,
+                        keyword: Color::from_rgb8(0xC6, 0x78, 0xDD),
+                        string: Color::from_rgb8(0x98, 0xC3, 0x79),
+                        number: Color::from_rgb8(0xD1, 0x9A, 0x66),
+                        constant: Color::from_rgb8(0xD1, 0x9A, 0x66),
+                        function: Color::from_rgb8(0x61, 0xAF, 0xEF),
+                        type_name: Color::from_rgb8(0xE5, 0xC0, 0x7B),
+                    }
+                }
+            },
             metadata_separator: ThemePaddingOnly {
                 padding: padding_axis(8.0, 0.0),
             },
@@ -419,15 +475,57 @@ impl GauntletComplexTheme {
             list_inner: ThemePaddingOnly {
                 padding: padding_axis(8.0, 0.0),
             },
+            table: ThemePaddingOnly {
+                padding: padding_axis(0.0, 8.0),
+            },
+            table_inner: ThemePaddingOnly {
+                padding: padding(0.0, 0.0, 8.0, 0.0),
+            },
+            table_header: ThemePaddingTextColor {
+                padding: padding(8.0, 5.0, 4.0, 5.0),
+                text_color: text_300,
+            },
+            table_header_cell: ThemeButton {
+                padding: padding_all(0.0),
+                background_color: Color::TRANSPARENT,
+                background_color_focused: Color::TRANSPARENT,
+                background_color_hovered: Color::TRANSPARENT,
+                text_color: text_300,
+                text_color_hovered: text_100,
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            table_cell: ThemePaddingOnly {
+                padding: padding_all(4.0),
+            },
+            table_cell_icon: ThemePaddingOnly {
+                padding: padding_axis(0.0, 4.0),
+            },
             form_input_label: ThemePaddingOnly {
                 padding: padding_axis(4.0, 12.0),
             },
+            form_input_error: ThemePaddingTextColor {
+                padding: padding_axis(4.0, 0.0),
+                text_color: match mode {
+                    UiThemeMode::Light => Color::from_rgb8(0xC0, 0x39, 0x2B),
+                    UiThemeMode::Dark => Color::from_rgb8(0xE0, 0x6C, 0x75),
+                },
+            },
+            form_input_info: ThemePaddingTextColor {
+                padding: padding_axis(4.0, 0.0),
+                text_color: text_300,
+            },
             list_section_title: ThemePaddingTextColorSpacing {
                 padding: padding(12.0, 8.0, 4.0, 8.0),
                 text_color: text_200,
                 spacing: 8.0,
             },
             list_section_subtitle: ThemeTextColor { text_color: text_300 },
+            loading_footer: ThemePaddingTextColor {
+                padding: padding_all(8.0),
+                text_color: text_300,
+            },
             grid_section_title: ThemePaddingTextColorSpacing {
                 padding: padding(12.0, 0.0, 4.0, 0.0),
                 text_color: text_200,
@@ -582,6 +680,88 @@ impl GauntletComplexTheme {
             hud_content: ThemePaddingOnly {
                 padding: padding_axis(8.0, 16.0),
             },
+            toast: ThemePaddingBackgroundColor {
+                padding: padding_axis(8.0, 12.0),
+                background_color: background_400,
+            },
+            toast_icon: ThemePaddingOnly {
+                padding: padding(0.0, 8.0, 0.0, 0.0),
+            },
+            toast_success_icon: ThemeTextColor {
+                text_color: match mode {
+                    UiThemeMode::Light => Color::from_rgb8(0x2E, 0x8B, 0x57),
+                    UiThemeMode::Dark => Color::from_rgb8(0x98, 0xC3, 0x79),
+                },
+            },
+            toast_failure_icon: ThemeTextColor {
+                text_color: match mode {
+                    UiThemeMode::Light => Color::from_rgb8(0xC0, 0x39, 0x2B),
+                    UiThemeMode::Dark => Color::from_rgb8(0xE0, 0x6C, 0x75),
+                },
+            },
+            toast_message: ThemePaddingTextColor {
+                padding: padding(4.0, 0.0, 0.0, 0.0),
+                text_color: text_300,
+            },
+            toast_loading_bar: ThemePaddingOnly {
+                padding: padding(8.0, 0.0, 0.0, 0.0),
+            },
+            toast_actions: ThemePaddingSpacing {
+                padding: padding(8.0, 0.0, 0.0, 0.0),
+                spacing: 8.0,
+            },
+            confirm_alert: ThemePaddingBackgroundColor {
+                padding: padding_all(16.0),
+                background_color: background_400,
+            },
+            confirm_alert_backdrop: ThemeBackgroundColor {
+                background_color: Color::from_rgba8(0, 0, 0, 0.4),
+            },
+            confirm_alert_icon: ThemePaddingOnly {
+                padding: padding(0.0, 0.0, 12.0, 0.0),
+            },
+            confirm_alert_title: ThemePaddingOnly {
+                padding: padding(0.0, 0.0, 4.0, 0.0),
+            },
+            confirm_alert_message: ThemeTextColor { text_color: text_300 },
+            confirm_alert_actions: ThemePaddingSpacing {
+                padding: padding(16.0, 0.0, 0.0, 0.0),
+                spacing: 8.0,
+            },
+            confirm_alert_action: ThemeButton {
+                padding: padding_axis(6.0, 12.0),
+                background_color: match mode {
+                    UiThemeMode::Light => background_300,
+                    UiThemeMode::Dark => background_200,
+                },
+                background_color_focused: background_100,
+                background_color_hovered: background_100,
+                text_color: text_100,
+                text_color_hovered: text_100,
+                border_radius: content.border.radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            confirm_alert_destructive_action: ThemeButton {
+                padding: padding_axis(6.0, 12.0),
+                background_color: match mode {
+                    UiThemeMode::Light => Color::from_rgb8(0xC0, 0x39, 0x2B),
+                    UiThemeMode::Dark => Color::from_rgb8(0xBE, 0x50, 0x46),
+                },
+                background_color_focused: match mode {
+                    UiThemeMode::Light => Color::from_rgb8(0xA9, 0x32, 0x26),
+                    UiThemeMode::Dark => Color::from_rgb8(0xE0, 0x6C, 0x75),
+                },
+                background_color_hovered: match mode {
+                    UiThemeMode::Light => Color::from_rgb8(0xA9, 0x32, 0x26),
+                    UiThemeMode::Dark => Color::from_rgb8(0xE0, 0x6C, 0x75),
+                },
+                text_color: Color::WHITE,
+                text_color_hovered: Color::WHITE,
+                border_radius: content.border.radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
         }
     }
 }
@@ -744,6 +924,17 @@ pub struct ThemeCode {
     border_color: Color,
 }
 
+#[derive(Debug, Clone)]
+pub struct ThemeSyntax {
+    comment: Color,
+    keyword: Color,
+    string: Color,
+    number: Color,
+    constant: Color,
+    function: Color,
+    type_name: Color,
+}
+
 #[derive(Debug, Clone)]
 pub struct ThemeInline {
     padding: ThemePadding,
@@ -824,6 +1015,11 @@ pub struct ThemeTextColor {
     text_color: Color,
 }
 
+#[derive(Debug, Clone)]
+pub struct ThemeBackgroundColor {
+    background_color: Color,
+}
+
 #[derive(Debug, Clone)]
 pub struct ThemePaddingSize {
     padding: ThemePadding,
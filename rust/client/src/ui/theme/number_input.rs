@@ -0,0 +1,63 @@
+use iced::widget::text_input;
+use iced_aw::number_input::Style;
+use iced_aw::style::number_input::Catalog;
+use iced_aw::style::number_input::ExtendedCatalog;
+use iced_aw::style::Status;
+use iced_aw::NumberInput;
+
+use crate::ui::theme::text_input::TextInputStyle;
+use crate::ui::theme::Element;
+use crate::ui::theme::GauntletComplexTheme;
+use crate::ui::theme::ThemableWidget;
+
+#[derive(Clone, Default)]
+pub enum NumberInputStyle {
+    #[default]
+    Default,
+}
+
+impl Catalog for GauntletComplexTheme {
+    type Class<'a> = NumberInputStyle;
+
+    fn default<'a>() -> Self::Class<'a> {
+        NumberInputStyle::Default
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        let theme = &self.form_input_date_picker_buttons;
+
+        match status {
+            Status::Hovered | Status::Pressed => {
+                Style {
+                    button_background: Some(theme.background_color_hovered.into()),
+                    icon_color: theme.text_color_hovered,
+                }
+            }
+            _ => {
+                Style {
+                    button_background: Some(theme.background_color.into()),
+                    icon_color: theme.text_color,
+                }
+            }
+        }
+    }
+}
+
+impl ExtendedCatalog for GauntletComplexTheme {
+    // input part of number input should look the same as text field
+    fn default_input<'a>() -> <Self as text_input::Catalog>::Class<'a> {
+        TextInputStyle::FormInput
+    }
+
+    fn style(&self, class: &<Self as Catalog>::Class<'_>, status: Status) -> Style {
+        Catalog::style(self, class, status)
+    }
+}
+
+impl<'a, Message: 'a + Clone> ThemableWidget<'a, Message> for NumberInput<'a, f64, Message, GauntletComplexTheme> {
+    type Kind = NumberInputStyle;
+
+    fn themed(self, kind: NumberInputStyle) -> Element<'a, Message> {
+        self.class(kind).into()
+    }
+}
@@ -17,6 +17,8 @@ pub enum RowStyle {
     GridItemTitle,
     RootBottomPanel,
     RootTopPanel,
+    ToastActions,
+    ConfirmAlertActions,
 }
 
 impl<'a, Message: 'a> ThemableWidget<'a, Message> for Row<'a, Message, GauntletComplexTheme, Renderer> {
@@ -58,6 +60,14 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Row<'a, Message, GauntletC
             }
             RowStyle::GridItemTitle => self.padding(theme.grid_item_title.padding.to_iced()),
             RowStyle::RootBottomPanel => self.spacing(theme.root_bottom_panel.spacing),
+            RowStyle::ToastActions => {
+                self.padding(theme.toast_actions.padding.to_iced())
+                    .spacing(theme.toast_actions.spacing)
+            }
+            RowStyle::ConfirmAlertActions => {
+                self.padding(theme.confirm_alert_actions.padding.to_iced())
+                    .spacing(theme.confirm_alert_actions.spacing)
+            }
             RowStyle::RootTopPanel => self.spacing(theme.root_top_panel.spacing),
         }
         .into()
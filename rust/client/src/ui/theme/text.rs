@@ -1,6 +1,7 @@
 use iced::widget::text;
 use iced::widget::text::Style;
 use iced::widget::Text;
+use iced::Color;
 use iced::Renderer;
 
 use crate::ui::theme::get_theme;
@@ -14,10 +15,14 @@ pub enum TextStyle {
     Default, // TODO is this used?
 
     ActionSectionTitle,
+    ContentCodeBlockLineNumber,
     EmptyViewSubtitle,
+    FormInputError,
+    FormInputInfo,
     ListItemSubtitle,
     ListSectionTitle,
     ListSectionSubtitle,
+    LoadingFooter,
     GridSectionTitle,
     GridSectionSubtitle,
     MainListItemSubtext,
@@ -30,6 +35,11 @@ pub enum TextStyle {
     InlineSeparator,
     RootBottomPanelPrimaryActionText,
     RootBottomPanelActionToggleText,
+    ToastSuccessIcon,
+    ToastFailureIcon,
+    ToastMessage,
+    ConfirmAlertMessage,
+    TableHeader,
 }
 
 impl<'a, Message: 'a> ThemableWidget<'a, Message> for Text<'a, GauntletComplexTheme, Renderer> {
@@ -64,6 +74,11 @@ impl text::Catalog for GauntletComplexTheme {
                     color: Some(self.action_section_title.text_color),
                 }
             }
+            TextStyle::ContentCodeBlockLineNumber => {
+                Style {
+                    color: Some(self.content_code_block_line_number.text_color),
+                }
+            }
             TextStyle::EmptyViewSubtitle => {
                 Style {
                     color: Some(self.empty_view_subtitle.text_color),
@@ -84,6 +99,46 @@ impl text::Catalog for GauntletComplexTheme {
                     color: Some(self.list_section_subtitle.text_color),
                 }
             }
+            TextStyle::FormInputError => {
+                Style {
+                    color: Some(self.form_input_error.text_color),
+                }
+            }
+            TextStyle::FormInputInfo => {
+                Style {
+                    color: Some(self.form_input_info.text_color),
+                }
+            }
+            TextStyle::ToastSuccessIcon => {
+                Style {
+                    color: Some(self.toast_success_icon.text_color),
+                }
+            }
+            TextStyle::ToastFailureIcon => {
+                Style {
+                    color: Some(self.toast_failure_icon.text_color),
+                }
+            }
+            TextStyle::ToastMessage => {
+                Style {
+                    color: Some(self.toast_message.text_color),
+                }
+            }
+            TextStyle::ConfirmAlertMessage => {
+                Style {
+                    color: Some(self.confirm_alert_message.text_color),
+                }
+            }
+            TextStyle::TableHeader => {
+                Style {
+                    color: Some(self.table_header.text_color),
+                }
+            }
+            TextStyle::LoadingFooter => {
+                Style {
+                    color: Some(self.loading_footer.text_color),
+                }
+            }
             TextStyle::GridSectionTitle => {
                 Style {
                     color: Some(self.grid_section_title.text_color),
@@ -147,3 +202,31 @@ impl text::Catalog for GauntletComplexTheme {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightKind {
+    Plain,
+    Comment,
+    Keyword,
+    String,
+    Number,
+    Constant,
+    Function,
+    Type,
+}
+
+pub fn code_block_syntax_color(kind: HighlightKind) -> Option<Color> {
+    let theme = get_theme();
+    let theme = &theme.content_code_block_syntax;
+
+    match kind {
+        HighlightKind::Plain => None,
+        HighlightKind::Comment => Some(theme.comment),
+        HighlightKind::Keyword => Some(theme.keyword),
+        HighlightKind::String => Some(theme.string),
+        HighlightKind::Number => Some(theme.number),
+        HighlightKind::Constant => Some(theme.constant),
+        HighlightKind::Function => Some(theme.function),
+        HighlightKind::Type => Some(theme.type_name),
+    }
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::UiToast;
+use gauntlet_common::model::UiToastStyle;
+use iced::advanced::text::Shaping;
+use iced::alignment::Horizontal;
+use iced::widget::button;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::row;
+use iced::widget::text;
+use iced::widget::value;
+use iced::Alignment;
+use iced::Length;
+use iced::Task;
+use iced_fonts::Bootstrap;
+use iced_fonts::BOOTSTRAP_FONT;
+
+use crate::ui::custom_widgets::loading_bar::LoadingBar;
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::Element;
+use crate::ui::theme::ThemableWidget;
+use crate::ui::AppMsg;
+
+// success and failure toasts hide by themselves, animated toast stays until it is updated or hidden by plugin
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+pub struct ToastState {
+    pub plugin_id: PluginId,
+    pub toast: UiToast,
+    // incremented every time toast is shown or updated, so that hiding of previous toast doesn't hide the new one
+    pub revision: usize,
+}
+
+impl ToastState {
+    pub fn is_toast(&self, plugin_id: &PluginId, toast_id: u32) -> bool {
+        &self.plugin_id == plugin_id && self.toast.id == toast_id
+    }
+}
+
+pub fn hide_toast_after_delay(toast: &UiToast, revision: usize) -> Task<AppMsg> {
+    if let UiToastStyle::Animated = toast.style {
+        return Task::none();
+    }
+
+    Task::perform(
+        async move {
+            tokio::time::sleep(TOAST_DURATION).await;
+
+            AppMsg::ExpireToast { revision }
+        },
+        std::convert::identity,
+    )
+}
+
+pub fn view_toast<'a>(state: &ToastState) -> Element<'a, AppMsg> {
+    let ToastState { plugin_id, toast, .. } = state;
+
+    let mut header_content = vec![];
+
+    let icon = match toast.style {
+        UiToastStyle::Success => Some((Bootstrap::CheckCircleFill, TextStyle::ToastSuccessIcon)),
+        UiToastStyle::Failure => Some((Bootstrap::XCircleFill, TextStyle::ToastFailureIcon)),
+        UiToastStyle::Animated => None,
+    };
+
+    if let Some((icon, style)) = icon {
+        let icon: Element<_> = value(icon).font(BOOTSTRAP_FONT).themed(style);
+
+        let icon: Element<_> = container(icon).themed(ContainerStyle::ToastIcon);
+
+        header_content.push(icon);
+    }
+
+    let title: Element<_> = text(toast.title.to_string()).shaping(Shaping::Advanced).into();
+
+    header_content.push(title);
+
+    let header: Element<_> = row(header_content).align_y(Alignment::Center).into();
+
+    let mut content = vec![header];
+
+    if let Some(message) = &toast.message {
+        let message: Element<_> = text(message.to_string())
+            .shaping(Shaping::Advanced)
+            .themed(TextStyle::ToastMessage);
+
+        let message: Element<_> = container(message).themed(ContainerStyle::ToastMessage);
+
+        content.push(message);
+    }
+
+    let actions: Vec<Element<_>> = [&toast.primary_action, &toast.secondary_action]
+        .into_iter()
+        .enumerate()
+        .filter_map(|(action_index, label)| label.as_ref().map(|label| (action_index, label)))
+        .map(|(action_index, label)| {
+            let label: Element<_> = text(label.to_string()).shaping(Shaping::Advanced).into();
+
+            button(label)
+                .on_press(AppMsg::RunToastAction {
+                    plugin_id: plugin_id.clone(),
+                    toast_id: toast.id,
+                    action_index,
+                })
+                .themed(ButtonStyle::ToastAction)
+        })
+        .collect();
+
+    if !actions.is_empty() {
+        let actions: Element<_> = row(actions).themed(RowStyle::ToastActions);
+
+        content.push(actions);
+    }
+
+    if let UiToastStyle::Animated = toast.style {
+        let loading_bar: Element<_> = LoadingBar::new().into();
+
+        let loading_bar: Element<_> = container(loading_bar).themed(ContainerStyle::ToastLoadingBar);
+
+        content.push(loading_bar);
+    }
+
+    let toast: Element<_> = container(column(content)).themed(ContainerStyle::Toast);
+
+    // positioned above bottom panel, same as action panel
+    container(toast)
+        .padding(gauntlet_common_ui::padding(0.0, 0.0, 48.0, 0.0))
+        .align_x(Horizontal::Center)
+        .align_bottom(Length::Fill)
+        .width(Length::Fill)
+        .into()
+}
@@ -6,7 +6,7 @@ use gauntlet_common::model::ActionPanelSectionWidgetOrderedMembers;
 use gauntlet_common::model::ActionPanelWidget;
 use gauntlet_common::model::ActionPanelWidgetOrderedMembers;
 use gauntlet_common::model::ActionWidget;
-use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::LauncherAction;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::UiWidgetId;
 use gauntlet_common_ui::shortcut_to_text;
@@ -24,6 +24,7 @@ use iced::Alignment;
 use iced::Font;
 use iced::Length;
 
+use crate::ui::keymap::launcher_action_shortcut;
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
@@ -33,20 +34,32 @@ use crate::ui::theme::text::TextStyle;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ActionPanel {
     pub title: Option<String>,
     pub items: Vec<ActionPanelItem>,
+    // rendered in separate section after actions of the plugin, never used as primary or secondary action
+    pub launcher_items: Vec<ActionPanelItem>,
 }
 
 impl ActionPanel {
     pub fn action_count(&self) -> usize {
-        self.items.iter().map(|item| item.action_count()).sum()
+        self.items
+            .iter()
+            .chain(self.launcher_items.iter())
+            .map(|item| item.action_count())
+            .sum()
     }
 
     pub fn find_first(&self) -> Option<(String, UiWidgetId)> {
         ActionPanelItem::find_first(&self.items)
     }
+
+    pub fn with_launcher_action(mut self, action: ActionPanelItem) -> ActionPanel {
+        self.launcher_items.push(action);
+
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -143,6 +156,7 @@ pub fn convert_action_panel(
             Some(ActionPanel {
                 title: title.clone(),
                 items,
+                launcher_items: vec![],
             })
         }
         _ => None,
@@ -153,9 +167,11 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
     root: bool,
     title: Option<String>,
     items: Vec<ActionPanelItem>,
+    run_action_shortcuts: bool,
     action_panel_focus_index: Option<usize>,
     on_action_click: &dyn Fn(UiWidgetId) -> T,
     index_counter: &Cell<usize>,
+    keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
 ) -> Vec<Element<'a, T>> {
     let mut columns = vec![];
 
@@ -193,25 +209,14 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
                 physical_shortcut,
             } => {
                 let physical_shortcut = match index_counter.get() {
-                    0 => {
-                        Some(PhysicalShortcut {
-                            // primary
-                            physical_key: PhysicalKey::Enter,
-                            modifier_shift: false,
-                            modifier_control: false,
-                            modifier_alt: false,
-                            modifier_meta: false,
-                        })
+                    0 if run_action_shortcuts => {
+                        Some(launcher_action_shortcut(keybindings, LauncherAction::RunPrimaryAction))
                     }
-                    1 => {
-                        Some(PhysicalShortcut {
-                            // secondary
-                            physical_key: PhysicalKey::Enter,
-                            modifier_shift: true,
-                            modifier_control: false,
-                            modifier_alt: false,
-                            modifier_meta: false,
-                        })
+                    1 if run_action_shortcuts => {
+                        Some(launcher_action_shortcut(
+                            keybindings,
+                            LauncherAction::RunSecondaryAction,
+                        ))
                     }
                     _ => physical_shortcut,
                 };
@@ -254,9 +259,11 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
                     false,
                     title,
                     items,
+                    run_action_shortcuts,
                     action_panel_focus_index,
                     on_action_click,
                     index_counter,
+                    keybindings,
                 );
 
                 for content in content {
@@ -273,16 +280,38 @@ pub fn render_action_panel<'a, T: 'a + Clone, F: Fn(UiWidgetId) -> T>(
     action_panel: ActionPanel,
     on_action_click: F,
     action_panel_scroll_handle: &ScrollHandle,
+    keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
 ) -> Element<'a, T> {
-    let columns = render_action_panel_items(
+    let index_counter = &Cell::new(0);
+
+    let has_plugin_actions = !action_panel.items.is_empty();
+
+    let mut columns = render_action_panel_items(
         true,
         action_panel.title,
         action_panel.items,
+        true,
         action_panel_scroll_handle.index,
         &on_action_click,
-        &Cell::new(0),
+        index_counter,
+        keybindings,
     );
 
+    if !action_panel.launcher_items.is_empty() {
+        let launcher_columns = render_action_panel_items(
+            !has_plugin_actions,
+            None,
+            action_panel.launcher_items,
+            false,
+            action_panel_scroll_handle.index,
+            &on_action_click,
+            index_counter,
+            keybindings,
+        );
+
+        columns.extend(launcher_columns);
+    }
+
     let actions: Element<_> = column(columns).into();
 
     let actions: Element<_> = scrollable(actions)
@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 use gauntlet_common::model::CodeBlockWidget;
 use gauntlet_common::model::ContentWidget;
 use gauntlet_common::model::ContentWidgetOrderedMembers;
@@ -9,22 +11,45 @@ use gauntlet_common::model::H5Widget;
 use gauntlet_common::model::H6Widget;
 use gauntlet_common::model::HorizontalBreakWidget;
 use gauntlet_common::model::ImageWidget;
+use gauntlet_common::model::MarkdownWidget;
 use gauntlet_common::model::ParagraphWidget;
 use gauntlet_common::model::SvgWidget;
+use gauntlet_common::model::UiWidgetId;
+use iced::advanced::text::Shaping;
 use iced::alignment::Horizontal;
 use iced::alignment::Vertical;
+use iced::font::Family;
+use iced::font::Style;
+use iced::font::Weight;
 use iced::widget::column;
 use iced::widget::container;
 use iced::widget::horizontal_rule;
+use iced::widget::rich_text;
+use iced::widget::row;
+use iced::widget::scrollable;
+use iced::widget::scrollable::Direction;
+use iced::widget::scrollable::Scrollbar;
+use iced::widget::span;
+use iced::widget::text;
+use iced::Font;
 use iced::Length;
+use itertools::Itertools;
+use pulldown_cmark::HeadingLevel;
 
 use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::code_block_syntax_color;
+use crate::ui::theme::text::TextStyle;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::highlight::HighlightedLine;
 use crate::ui::widget::images::render_image;
 use crate::ui::widget::images::render_svg;
+use crate::ui::widget::markdown::MarkdownBlock;
+use crate::ui::widget::markdown::MarkdownSpan;
+use crate::ui::widget::state::CodeBlockState;
+use crate::ui::widget::state::MarkdownState;
 use crate::ui::widget::text::TextRenderType;
 
 impl<'b> ComponentWidgets<'b> {
@@ -95,23 +120,118 @@ impl<'b> ComponentWidgets<'b> {
     }
 
     fn render_horizontal_break_widget<'a>(&self, _widget: &HorizontalBreakWidget) -> Element<'a, ComponentWidgetEvent> {
-        let separator: Element<_> = horizontal_rule(1).into();
-
-        container(separator)
-            .width(Length::Fill)
-            .themed(ContainerStyle::ContentHorizontalBreak)
+        render_horizontal_break()
     }
 
     fn render_code_block_widget<'a>(&self, widget: &CodeBlockWidget) -> Element<'a, ComponentWidgetEvent> {
-        let content: Element<_> = self.render_text(&widget.content.text, TextRenderType::None);
+        let CodeBlockState { lines } = self.code_block_state(widget.__id__);
+
+        render_code_block(lines)
+    }
+
+    fn render_markdown_widget<'a>(&self, widget: &MarkdownWidget) -> Element<'a, ComponentWidgetEvent> {
+        self.render_markdown(widget.__id__, self.markdown_state(widget.__id__))
+    }
+
+    pub fn render_markdown<'a>(
+        &self,
+        widget_id: UiWidgetId,
+        state: &MarkdownState,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let code_block_index = &Cell::new(0);
+
+        self.render_markdown_blocks(widget_id, &state.blocks, &state.code_blocks, code_block_index)
+    }
+
+    fn render_markdown_blocks<'a>(
+        &self,
+        widget_id: UiWidgetId,
+        blocks: &[MarkdownBlock],
+        code_blocks: &[Vec<HighlightedLine>],
+        code_block_index: &Cell<usize>,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let content: Vec<_> = blocks
+            .iter()
+            .map(|block| self.render_markdown_block(widget_id, block, code_blocks, code_block_index))
+            .collect();
+
+        column(content).into()
+    }
+
+    fn render_markdown_block<'a>(
+        &self,
+        widget_id: UiWidgetId,
+        block: &MarkdownBlock,
+        code_blocks: &[Vec<HighlightedLine>],
+        code_block_index: &Cell<usize>,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        match block {
+            MarkdownBlock::Heading { level, spans } => {
+                let context = match level {
+                    HeadingLevel::H1 => TextRenderType::H1,
+                    HeadingLevel::H2 => TextRenderType::H2,
+                    HeadingLevel::H3 => TextRenderType::H3,
+                    HeadingLevel::H4 => TextRenderType::H4,
+                    HeadingLevel::H5 => TextRenderType::H5,
+                    HeadingLevel::H6 => TextRenderType::H6,
+                };
+
+                render_markdown_spans(widget_id, spans, context)
+            }
+            MarkdownBlock::Paragraph { spans } => {
+                let content = render_markdown_spans(widget_id, spans, TextRenderType::None);
+
+                container(content)
+                    .width(Length::Fill)
+                    .themed(ContainerStyle::ContentParagraph)
+            }
+            MarkdownBlock::CodeBlock { .. } => {
+                let index = code_block_index.get();
+
+                code_block_index.set(index + 1);
+
+                render_code_block(&code_blocks[index])
+            }
+            MarkdownBlock::HorizontalBreak => render_horizontal_break(),
+            MarkdownBlock::Quote { blocks } => {
+                let content = self.render_markdown_blocks(widget_id, blocks, code_blocks, code_block_index);
+
+                container(content)
+                    .width(Length::Fill)
+                    .themed(ContainerStyle::ContentParagraph)
+            }
+            MarkdownBlock::List { start, items } => {
+                let items: Vec<Element<_>> = items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        let marker = match start {
+                            Some(start) => format!("{}.", start + index as u64),
+                            None => "•".to_string(),
+                        };
 
-        let content = container(content)
-            .width(Length::Fill)
-            .themed(ContainerStyle::ContentCodeBlockText);
+                        let marker: Element<_> = text(marker).shaping(Shaping::Advanced).into();
 
-        container(content)
-            .width(Length::Fill)
-            .themed(ContainerStyle::ContentCodeBlock)
+                        let marker = container(marker).themed(ContainerStyle::ContentParagraph);
+
+                        let content = self.render_markdown_blocks(widget_id, item, code_blocks, code_block_index);
+
+                        row([marker, content]).into()
+                    })
+                    .collect();
+
+                column(items).into()
+            }
+            MarkdownBlock::Table { head, rows } => {
+                let head = render_markdown_table_row(widget_id, head, true);
+
+                let separator: Element<_> = horizontal_rule(1).into();
+
+                let rows = rows.iter().map(|row| render_markdown_table_row(widget_id, row, false));
+
+                column([head, separator].into_iter().chain(rows)).into()
+            }
+        }
     }
 
     pub fn render_content_widget<'a>(
@@ -135,6 +255,7 @@ impl<'b> ComponentWidgets<'b> {
                     ContentWidgetOrderedMembers::H6(widget) => self.render_h6_widget(widget),
                     ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.render_horizontal_break_widget(widget),
                     ContentWidgetOrderedMembers::CodeBlock(widget) => self.render_code_block_widget(widget),
+                    ContentWidgetOrderedMembers::Markdown(widget) => self.render_markdown_widget(widget),
                     ContentWidgetOrderedMembers::Svg(widget) => self.render_svg_widget(widget, centered),
                 }
             })
@@ -154,3 +275,141 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 }
+
+fn render_horizontal_break<'a>() -> Element<'a, ComponentWidgetEvent> {
+    let separator: Element<_> = horizontal_rule(1).into();
+
+    container(separator)
+        .width(Length::Fill)
+        .themed(ContainerStyle::ContentHorizontalBreak)
+}
+
+fn render_code_block<'a>(lines: &[HighlightedLine]) -> Element<'a, ComponentWidgetEvent> {
+    let mut spans = vec![];
+
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            spans.push(span("\n").font(Font::MONOSPACE));
+        }
+
+        for (kind, value) in line {
+            let mut result = span(value.clone()).font(Font::MONOSPACE);
+
+            if let Some(color) = code_block_syntax_color(*kind) {
+                result = result.color(color)
+            }
+
+            spans.push(result);
+        }
+    }
+
+    let code: Element<_> = rich_text(spans).into();
+
+    // long lines are not wrapped, code can be scrolled horizontally instead
+    let code: Element<_> = scrollable(code)
+        .direction(Direction::Horizontal(Scrollbar::new()))
+        .width(Length::Fill)
+        .into();
+
+    // line numbers are not useful for single line snippets like calculator results
+    let content: Element<_> = if lines.len() > 1 {
+        let line_numbers = (1..=lines.len()).map(|line| line.to_string()).join("\n");
+
+        let line_numbers: Element<_> = text(line_numbers)
+            .font(Font::MONOSPACE)
+            .themed(TextStyle::ContentCodeBlockLineNumber);
+
+        let line_numbers = container(line_numbers).themed(ContainerStyle::ContentCodeBlockLineNumber);
+
+        row([line_numbers, code]).into()
+    } else {
+        code
+    };
+
+    let content = container(content)
+        .width(Length::Fill)
+        .themed(ContainerStyle::ContentCodeBlockText);
+
+    container(content)
+        .width(Length::Fill)
+        .themed(ContainerStyle::ContentCodeBlock)
+}
+
+fn render_markdown_spans<'a>(
+    widget_id: UiWidgetId,
+    spans: &[MarkdownSpan],
+    context: TextRenderType,
+) -> Element<'a, ComponentWidgetEvent> {
+    let header_size = context.header_size();
+
+    let spans: Vec<_> = spans
+        .iter()
+        .map(|value| {
+            let font = Font {
+                family: if value.code {
+                    Family::Monospace
+                } else {
+                    Font::DEFAULT.family
+                },
+                weight: if value.strong || header_size.is_some() {
+                    Weight::Bold
+                } else {
+                    Weight::Normal
+                },
+                style: if value.emphasis { Style::Italic } else { Style::Normal },
+                ..Font::DEFAULT
+            };
+
+            let mut result = span(value.text.clone()).font(font).strikethrough(value.strikethrough);
+
+            if let Some(size) = header_size {
+                result = result.size(size)
+            }
+
+            // links are opened the same way as metadata links
+            if let Some(href) = &value.link {
+                result = result.underline(true).link(ComponentWidgetEvent::LinkClick {
+                    widget_id,
+                    href: href.to_owned(),
+                })
+            }
+
+            result
+        })
+        .collect();
+
+    rich_text(spans).into()
+}
+
+fn render_markdown_table_row<'a>(
+    widget_id: UiWidgetId,
+    cells: &[Vec<MarkdownSpan>],
+    is_head: bool,
+) -> Element<'a, ComponentWidgetEvent> {
+    let cells: Vec<Element<_>> = cells
+        .iter()
+        .map(|cell| {
+            let content = if is_head {
+                let cell: Vec<_> = cell
+                    .iter()
+                    .map(|value| {
+                        MarkdownSpan {
+                            strong: true,
+                            ..value.clone()
+                        }
+                    })
+                    .collect();
+
+                render_markdown_spans(widget_id, &cell, TextRenderType::None)
+            } else {
+                render_markdown_spans(widget_id, cell, TextRenderType::None)
+            };
+
+            container(content)
+                .width(Length::Fill)
+                .themed(ContainerStyle::ContentParagraph)
+        })
+        .collect();
+
+    row(cells).into()
+}
@@ -6,6 +6,7 @@ use gauntlet_common::model::ActionPanelWidgetOrderedMembers;
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidget;
 use gauntlet_common::model::GridWidgetOrderedMembers;
+use gauntlet_common::model::ListItemWidget;
 use gauntlet_common::model::ListSectionWidgetOrderedMembers;
 use gauntlet_common::model::ListWidget;
 use gauntlet_common::model::ListWidgetOrderedMembers;
@@ -13,6 +14,10 @@ use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::RootWidgetMembers;
+use gauntlet_common::model::TableColumnWidget;
+use gauntlet_common::model::TableRowWidget;
+use gauntlet_common::model::TableWidget;
+use gauntlet_common::model::TableWidgetOrderedMembers;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiWidgetId;
 use iced::widget::text_input;
@@ -20,16 +25,27 @@ use iced::Task;
 
 use crate::ui::grid_navigation::GridSectionData;
 use crate::ui::scroll_handle::ScrollHandle;
+use crate::ui::scroll_handle::LOAD_MORE_THRESHOLD_ROWS;
 use crate::ui::widget::action_panel::convert_action_panel;
 use crate::ui::widget::action_panel::ActionPanel;
 use crate::ui::widget::events::ComponentWidgetEvent;
 use crate::ui::widget::grid::grid_width;
+use crate::ui::widget::search_bar::ItemFilter;
 use crate::ui::widget::state::CheckboxState;
+use crate::ui::widget::state::CodeBlockState;
 use crate::ui::widget::state::ComponentWidgetState;
 use crate::ui::widget::state::DatePickerState;
+use crate::ui::widget::state::FilePickerState;
+use crate::ui::widget::state::MarkdownState;
+use crate::ui::widget::state::NumberFieldState;
 use crate::ui::widget::state::RootState;
 use crate::ui::widget::state::SelectState;
+use crate::ui::widget::state::TableSort;
+use crate::ui::widget::state::TagPickerState;
+use crate::ui::widget::state::TextAreaState;
 use crate::ui::widget::state::TextFieldState;
+use crate::ui::widget::table::compare_cell_text;
+use crate::ui::widget::table::table_cell;
 use crate::ui::AppMsg;
 
 #[derive(Debug)]
@@ -67,6 +83,31 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
+    // text editor borrows its content for the lifetime of the element, so the state is returned with lifetime of the map
+    pub fn text_area_state(&self, widget_id: UiWidgetId) -> &'b TextAreaState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::TextArea(state) => state,
+            _ => panic!("TextAreaState expected, {:?} found", state),
+        }
+    }
+
+    pub fn number_field_state(&self, widget_id: UiWidgetId) -> &NumberFieldState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::NumberField(state) => state,
+            _ => panic!("NumberFieldState expected, {:?} found", state),
+        }
+    }
+
     pub fn checkbox_state(&self, widget_id: UiWidgetId) -> &CheckboxState {
         let state = self.state.get(&widget_id).expect(&format!(
             "requested state should always be present for id: {}",
@@ -103,6 +144,54 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
+    pub fn tag_picker_state(&self, widget_id: UiWidgetId) -> &TagPickerState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::TagPicker(state) => state,
+            _ => panic!("TagPickerState expected, {:?} found", state),
+        }
+    }
+
+    pub fn file_picker_state(&self, widget_id: UiWidgetId) -> &FilePickerState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::FilePicker(state) => state,
+            _ => panic!("FilePickerState expected, {:?} found", state),
+        }
+    }
+
+    pub fn code_block_state(&self, widget_id: UiWidgetId) -> &CodeBlockState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::CodeBlock(state) => state,
+            _ => panic!("CodeBlockState expected, {:?} found", state),
+        }
+    }
+
+    pub fn markdown_state(&self, widget_id: UiWidgetId) -> &MarkdownState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::Markdown(state) => state,
+            _ => panic!("MarkdownState expected, {:?} found", state),
+        }
+    }
+
     pub fn root_state(&self, widget_id: UiWidgetId) -> &RootState {
         let state = self.state.get(&widget_id).expect(&format!(
             "requested state should always be present for id: {}",
@@ -118,6 +207,23 @@ impl<'b> ComponentWidgets<'b> {
 
 impl<'b> ComponentWidgets<'b> {
     pub fn get_action_ids(&self) -> Vec<UiWidgetId> {
+        let mut result = self.get_plugin_action_ids();
+
+        let Some(RootWidgetMembers::List(widget)) = self.root_widget.as_ref().and_then(|root| root.content.as_ref())
+        else {
+            return result;
+        };
+
+        // launcher-provided "Toggle Detail" action is always the last one in action panel
+        if ComponentWidgets::list_has_detail(widget) {
+            result.push(widget.__id__)
+        }
+
+        result
+    }
+
+    // actions defined by plugin, only these can be run as primary or secondary action
+    pub fn get_plugin_action_ids(&self) -> Vec<UiWidgetId> {
         let Some(root_widget) = &self.root_widget else {
             return vec![];
         };
@@ -132,6 +238,7 @@ impl<'b> ComponentWidgets<'b> {
             RootWidgetMembers::Inline(widget) => &widget.content.actions,
             RootWidgetMembers::List(widget) => &widget.content.actions,
             RootWidgetMembers::Grid(widget) => &widget.content.actions,
+            RootWidgetMembers::Table(widget) => &widget.content.actions,
         };
 
         let mut result = vec![];
@@ -174,12 +281,21 @@ impl<'b> ComponentWidgets<'b> {
             RootWidgetMembers::List(widget) => {
                 let RootState { focused_item, .. } = self.root_state(widget.__id__);
 
-                ComponentWidgets::list_focused_item_id(focused_item, widget)
+                let filter = self.list_item_filter(widget);
+
+                ComponentWidgets::list_focused_item_id(focused_item, widget, &filter)
             }
             RootWidgetMembers::Grid(widget) => {
                 let RootState { focused_item, .. } = self.root_state(widget.__id__);
 
-                ComponentWidgets::grid_focused_item_id(focused_item, widget)
+                let filter = self.grid_item_filter(widget);
+
+                ComponentWidgets::grid_focused_item_id(focused_item, widget, &filter)
+            }
+            RootWidgetMembers::Table(widget) => {
+                let RootState { focused_item, sort, .. } = self.root_state(widget.__id__);
+
+                ComponentWidgets::table_focused_item_id(focused_item, widget, sort)
             }
         }
     }
@@ -190,7 +306,7 @@ impl<'b> ComponentWidgets<'b> {
         text_input::focus(text_input_id.clone())
     }
 
-    pub fn grid_section_sizes(grid_widget: &GridWidget) -> Vec<GridSectionData> {
+    pub fn grid_section_sizes(grid_widget: &GridWidget, filter: &ItemFilter) -> Vec<GridSectionData> {
         let mut amount_per_section: Vec<GridSectionData> = vec![];
         let mut pending_section_size = 0;
 
@@ -202,8 +318,10 @@ impl<'b> ComponentWidgets<'b> {
 
         for members in &grid_widget.content.ordered_members {
             match &members {
-                GridWidgetOrderedMembers::GridItem(_) => {
-                    pending_section_size = pending_section_size + 1;
+                GridWidgetOrderedMembers::GridItem(widget) => {
+                    if filter.matches(widget.title.as_deref(), &widget.keywords) {
+                        pending_section_size = pending_section_size + 1;
+                    }
                 }
                 GridWidgetOrderedMembers::GridSection(widget) => {
                     if pending_section_size > 0 {
@@ -229,9 +347,20 @@ impl<'b> ComponentWidgets<'b> {
                         .content
                         .ordered_members
                         .iter()
-                        .filter(|members| matches!(members, GridSectionWidgetOrderedMembers::GridItem(_)))
+                        .filter(|members| {
+                            match members {
+                                GridSectionWidgetOrderedMembers::GridItem(widget) => {
+                                    filter.matches(widget.title.as_deref(), &widget.keywords)
+                                }
+                            }
+                        })
                         .count();
 
+                    // sections without matching items are not shown
+                    if section_amount == 0 && filter.is_active() {
+                        continue;
+                    }
+
                     let width = grid_width(&widget.columns);
                     amount_per_section.push(GridSectionData {
                         start_index: cumulative_item_index_at_start,
@@ -285,25 +414,31 @@ impl<'b> ComponentWidgets<'b> {
                     Some(widget) => widget.__id__,
                 }
             }
+            RootWidgetMembers::Table(widget) => {
+                match &widget.content.search_bar {
+                    None => return AppMsg::Noop,
+                    Some(widget) => widget.__id__,
+                }
+            }
             _ => return AppMsg::Noop,
         };
 
         AppMsg::FocusPluginViewSearchBar { widget_id }
     }
 
-    pub fn list_focused_item_id(focused_item: &ScrollHandle, widget: &ListWidget) -> Option<String> {
+    pub fn list_items<'c>(widget: &'c ListWidget, filter: &ItemFilter) -> Vec<&'c ListItemWidget> {
         let mut items = vec![];
 
         for members in &widget.content.ordered_members {
             match &members {
                 ListWidgetOrderedMembers::ListItem(item) => {
-                    items.push(&item.id);
+                    items.push(item);
                 }
                 ListWidgetOrderedMembers::ListSection(section) => {
                     for members in &section.content.ordered_members {
                         match &members {
                             ListSectionWidgetOrderedMembers::ListItem(item) => {
-                                items.push(&item.id);
+                                items.push(item);
                             }
                         }
                     }
@@ -311,18 +446,94 @@ impl<'b> ComponentWidgets<'b> {
             }
         }
 
-        match focused_item.get(&items) {
-            None => None,
-            Some(item_id) => Some(item_id.to_string()),
+        items.retain(|item| filter.matches(Some(&item.title), &item.keywords));
+
+        items
+    }
+
+    // indexes of items that have section title rendered above them, sections without matching items are not shown
+    pub fn list_section_title_rows(widget: &ListWidget, filter: &ItemFilter) -> Vec<usize> {
+        let mut rows = vec![];
+        let mut row_index = 0;
+
+        for members in &widget.content.ordered_members {
+            match &members {
+                ListWidgetOrderedMembers::ListItem(item) => {
+                    if filter.matches(Some(&item.title), &item.keywords) {
+                        row_index += 1;
+                    }
+                }
+                ListWidgetOrderedMembers::ListSection(section) => {
+                    let amount = section
+                        .content
+                        .ordered_members
+                        .iter()
+                        .filter(|members| {
+                            match members {
+                                ListSectionWidgetOrderedMembers::ListItem(item) => {
+                                    filter.matches(Some(&item.title), &item.keywords)
+                                }
+                            }
+                        })
+                        .count();
+
+                    if amount == 0 && filter.is_active() {
+                        continue;
+                    }
+
+                    rows.push(row_index);
+
+                    row_index += amount;
+                }
+            }
         }
+
+        rows
+    }
+
+    // detail can be toggled if either list itself or any of its items has detail
+    pub fn list_has_detail(widget: &ListWidget) -> bool {
+        let item_has_detail = widget.content.ordered_members.iter().any(|members| {
+            match members {
+                ListWidgetOrderedMembers::ListItem(item) => item.content.detail.is_some(),
+                ListWidgetOrderedMembers::ListSection(section) => {
+                    section.content.ordered_members.iter().any(|members| {
+                        match members {
+                            ListSectionWidgetOrderedMembers::ListItem(item) => item.content.detail.is_some(),
+                        }
+                    })
+                }
+            }
+        });
+
+        widget.content.detail.is_some() || item_has_detail
+    }
+
+    pub fn list_focused_item<'c>(
+        focused_item: &ScrollHandle,
+        widget: &'c ListWidget,
+        filter: &ItemFilter,
+    ) -> Option<&'c ListItemWidget> {
+        let items = ComponentWidgets::list_items(widget, filter);
+
+        focused_item.get(&items).copied()
+    }
+
+    pub fn list_focused_item_id(
+        focused_item: &ScrollHandle,
+        widget: &ListWidget,
+        filter: &ItemFilter,
+    ) -> Option<String> {
+        ComponentWidgets::list_focused_item(focused_item, widget, filter).map(|item| item.id.to_string())
     }
 
     pub fn list_item_focus_event(
         plugin_id: PluginId,
         focused_item: &ScrollHandle,
         widget: &ListWidget,
+        filter: &ItemFilter,
     ) -> Task<AppMsg> {
-        let widget_event = match ComponentWidgets::list_focused_item_id(focused_item, widget) {
+        let widget_event = match ComponentWidgets::list_focused_item_id(focused_item, widget, filter) {
             None => {
                 ComponentWidgetEvent::FocusListItem {
                     list_widget_id: widget.__id__,
@@ -344,19 +555,23 @@ impl<'b> ComponentWidgets<'b> {
         })
     }
 
-    pub fn grid_focused_item_id(focused_item: &ScrollHandle, widget: &GridWidget) -> Option<String> {
+    pub fn grid_focused_item_id(
+        focused_item: &ScrollHandle,
+        widget: &GridWidget,
+        filter: &ItemFilter,
+    ) -> Option<String> {
         let mut items = vec![];
 
         for members in &widget.content.ordered_members {
             match &members {
                 GridWidgetOrderedMembers::GridItem(item) => {
-                    items.push(&item.id);
+                    items.push(item);
                 }
                 GridWidgetOrderedMembers::GridSection(section) => {
                     for members in &section.content.ordered_members {
                         match &members {
                             GridSectionWidgetOrderedMembers::GridItem(item) => {
-                                items.push(&item.id);
+                                items.push(item);
                             }
                         }
                     }
@@ -364,9 +579,11 @@ impl<'b> ComponentWidgets<'b> {
             }
         }
 
+        items.retain(|item| filter.matches(item.title.as_deref(), &item.keywords));
+
         match focused_item.get(&items) {
             None => None,
-            Some(item_id) => Some(item_id.to_string()),
+            Some(item) => Some(item.id.to_string()),
         }
     }
 
@@ -374,8 +591,9 @@ impl<'b> ComponentWidgets<'b> {
         plugin_id: PluginId,
         focused_item: &ScrollHandle,
         widget: &GridWidget,
+        filter: &ItemFilter,
     ) -> Task<AppMsg> {
-        let widget_event = match ComponentWidgets::grid_focused_item_id(focused_item, widget) {
+        let widget_event = match ComponentWidgets::grid_focused_item_id(focused_item, widget, filter) {
             None => {
                 ComponentWidgetEvent::FocusGridItem {
                     grid_widget_id: widget.__id__,
@@ -397,6 +615,149 @@ impl<'b> ComponentWidgets<'b> {
         })
     }
 
+    pub fn list_load_more_event(
+        plugin_id: PluginId,
+        focused_item: &ScrollHandle,
+        widget: &ListWidget,
+        filter: &ItemFilter,
+    ) -> Task<AppMsg> {
+        let item_amount = ComponentWidgets::list_items(widget, filter).len();
+
+        if !ComponentWidgets::is_near_end(focused_item, item_amount, 1, widget.is_loading) {
+            return Task::none();
+        }
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id,
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::LoadMoreList {
+                list_widget_id: widget.__id__,
+                item_amount,
+            },
+        })
+    }
+
+    pub fn grid_load_more_event(
+        plugin_id: PluginId,
+        focused_item: &ScrollHandle,
+        widget: &GridWidget,
+        filter: &ItemFilter,
+    ) -> Task<AppMsg> {
+        let item_amount = ComponentWidgets::grid_section_sizes(widget, filter)
+            .iter()
+            .map(|data| data.amount_in_section)
+            .sum();
+
+        let items_per_row = grid_width(&widget.columns);
+
+        if !ComponentWidgets::is_near_end(focused_item, item_amount, items_per_row, widget.is_loading) {
+            return Task::none();
+        }
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id,
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::LoadMoreGrid {
+                grid_widget_id: widget.__id__,
+                item_amount,
+            },
+        })
+    }
+
+    pub fn table_columns(widget: &TableWidget) -> Vec<&TableColumnWidget> {
+        widget
+            .content
+            .ordered_members
+            .iter()
+            .filter_map(|members| {
+                match members {
+                    TableWidgetOrderedMembers::TableColumn(widget) => Some(widget),
+                    TableWidgetOrderedMembers::TableRow(_) => None,
+                }
+            })
+            .collect()
+    }
+
+    // rows in the order they are shown, sorting is stable so rows with equal values keep the order defined by plugin
+    pub fn table_rows<'c>(widget: &'c TableWidget, sort: &Option<TableSort>) -> Vec<&'c TableRowWidget> {
+        let mut rows: Vec<_> = widget
+            .content
+            .ordered_members
+            .iter()
+            .filter_map(|members| {
+                match members {
+                    TableWidgetOrderedMembers::TableColumn(_) => None,
+                    TableWidgetOrderedMembers::TableRow(widget) => Some(widget),
+                }
+            })
+            .collect();
+
+        if let Some(TableSort {
+            column_index,
+            descending,
+        }) = sort
+        {
+            rows.sort_by(|row_a, row_b| {
+                let text_a = table_cell(row_a, *column_index).and_then(|cell| cell.text.as_deref());
+                let text_b = table_cell(row_b, *column_index).and_then(|cell| cell.text.as_deref());
+
+                let ordering = compare_cell_text(text_a, text_b);
+
+                if *descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+
+        rows
+    }
+
+    pub fn table_focused_item_id(
+        focused_item: &ScrollHandle,
+        widget: &TableWidget,
+        sort: &Option<TableSort>,
+    ) -> Option<String> {
+        let rows = ComponentWidgets::table_rows(widget, sort);
+
+        focused_item.get(&rows).map(|row| row.id.to_string())
+    }
+
+    pub fn table_item_focus_event(
+        plugin_id: PluginId,
+        focused_item: &ScrollHandle,
+        widget: &TableWidget,
+        sort: &Option<TableSort>,
+    ) -> Task<AppMsg> {
+        let item_id = ComponentWidgets::table_focused_item_id(focused_item, widget, sort);
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id,
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::FocusTableItem {
+                table_widget_id: widget.__id__,
+                item_id,
+            },
+        })
+    }
+
+    fn is_near_end(
+        focused_item: &ScrollHandle,
+        item_amount: usize,
+        items_per_row: usize,
+        is_loading: Option<bool>,
+    ) -> bool {
+        if is_loading.unwrap_or(false) {
+            return false;
+        }
+
+        match focused_item.index {
+            None => false,
+            Some(index) => index + LOAD_MORE_THRESHOLD_ROWS * items_per_row >= item_amount,
+        }
+    }
+
     pub fn get_action_panel(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> Option<ActionPanel> {
         let Some(root_widget) = &self.root_widget else {
             return None;
@@ -412,6 +773,7 @@ impl<'b> ComponentWidgets<'b> {
             RootWidgetMembers::Inline(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
             RootWidgetMembers::List(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
             RootWidgetMembers::Grid(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+            RootWidgetMembers::Table(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
         }
     }
 }
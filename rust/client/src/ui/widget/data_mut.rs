@@ -3,8 +3,6 @@ use std::sync::Arc;
 
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidgetOrderedMembers;
-use gauntlet_common::model::ListSectionWidgetOrderedMembers;
-use gauntlet_common::model::ListWidgetOrderedMembers;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::RootWidgetMembers;
@@ -98,6 +96,7 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Inline(widget) => widget.__id__,
             RootWidgetMembers::List(widget) => widget.__id__,
             RootWidgetMembers::Grid(widget) => widget.__id__,
+            RootWidgetMembers::Table(widget) => widget.__id__,
         };
 
         let state = self.root_state_mut(widget_id);
@@ -105,6 +104,68 @@ impl<'b> ComponentWidgetsMut<'b> {
         state.show_action_panel = !state.show_action_panel;
     }
 
+    // returns false if current view doesn't have detail which can be toggled
+    pub fn toggle_detail(&mut self) -> bool {
+        let Some(root_widget) = &self.root_widget else {
+            return false;
+        };
+
+        let Some(RootWidgetMembers::List(widget)) = &root_widget.content else {
+            return false;
+        };
+
+        if !ComponentWidgets::list_has_detail(widget) {
+            return false;
+        }
+
+        let widget_id = widget.__id__;
+
+        let state = self.root_state_mut(widget_id);
+
+        state.show_detail = !state.show_detail;
+
+        true
+    }
+
+    // with built-in filtering, focused index doesn't point to the same item after search text changes
+    pub fn reset_filtered_focus(&mut self) {
+        let Some(root_widget) = &self.root_widget else {
+            return;
+        };
+
+        let (widget_id, filtering) = match &root_widget.content {
+            Some(RootWidgetMembers::List(widget)) => (widget.__id__, widget.filtering),
+            Some(RootWidgetMembers::Grid(widget)) => (widget.__id__, widget.filtering),
+            _ => return,
+        };
+
+        if !filtering.unwrap_or(false) {
+            return;
+        }
+
+        let RootState { focused_item, .. } = self.root_state_mut(widget_id);
+
+        focused_item.reset(false);
+    }
+
+    // returns None if current view is not a form
+    pub fn submit_form(&mut self) -> Option<Task<AppMsg>> {
+        let root_widget = self.root_widget.clone()?;
+
+        let Some(RootWidgetMembers::Form(widget)) = &root_widget.content else {
+            return None;
+        };
+
+        let state = self.root_state_mut(widget.__id__);
+
+        state.submit_attempted = true;
+
+        let task = ComponentWidgets::new(&self.root_widget, &self.state, self.plugin_id.clone(), &self.data)
+            .submit_form(widget);
+
+        Some(task)
+    }
+
     pub fn append_text(&mut self, text: &str) -> Task<AppMsg> {
         let Some(root_widget) = &self.root_widget else {
             return Task::none();
@@ -127,6 +188,12 @@ impl<'b> ComponentWidgetsMut<'b> {
                     Some(widget) => widget.__id__,
                 }
             }
+            RootWidgetMembers::Table(widget) => {
+                match &widget.content.search_bar {
+                    None => return Task::none(),
+                    Some(widget) => widget.__id__,
+                }
+            }
             _ => return Task::none(),
         };
 
@@ -166,6 +233,12 @@ impl<'b> ComponentWidgetsMut<'b> {
                     Some(widget) => widget.__id__,
                 }
             }
+            RootWidgetMembers::Table(widget) => {
+                match &widget.content.search_bar {
+                    None => return Task::none(),
+                    Some(widget) => widget.__id__,
+                }
+            }
             _ => return Task::none(),
         };
 
@@ -195,17 +268,31 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Form(_) => Task::none(),
             RootWidgetMembers::Inline(_) => Task::none(),
             RootWidgetMembers::List(list_widget) => {
+                let filter = ComponentWidgets::item_filter_on_state(
+                    &self.state,
+                    list_widget.filtering,
+                    &list_widget.content.search_bar,
+                );
+
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, list_widget.__id__);
 
+                focused_item.set_section_title_rows(ComponentWidgets::list_section_title_rows(list_widget, &filter));
+
                 let focus_task = focused_item.focus_previous().unwrap_or_else(|| Task::none());
 
                 let item_focus_event =
-                    ComponentWidgets::list_item_focus_event(self.plugin_id.clone(), focused_item, list_widget);
+                    ComponentWidgets::list_item_focus_event(self.plugin_id.clone(), focused_item, list_widget, &filter);
 
                 Task::batch([item_focus_event, focus_task])
             }
             RootWidgetMembers::Grid(grid_widget) => {
+                let filter = ComponentWidgets::item_filter_on_state(
+                    &self.state,
+                    grid_widget.filtering,
+                    &grid_widget.content.search_bar,
+                );
+
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, grid_widget.__id__);
 
@@ -213,7 +300,7 @@ impl<'b> ComponentWidgetsMut<'b> {
                     return Task::none();
                 };
 
-                let amount_per_section_total = ComponentWidgets::grid_section_sizes(grid_widget);
+                let amount_per_section_total = ComponentWidgets::grid_section_sizes(grid_widget, &filter);
 
                 let focus_task = match grid_up_offset(*current_index, amount_per_section_total) {
                     None => Task::none(),
@@ -226,7 +313,18 @@ impl<'b> ComponentWidgetsMut<'b> {
                 };
 
                 let item_focus_event =
-                    ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget);
+                    ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget, &filter);
+
+                Task::batch([item_focus_event, focus_task])
+            }
+            RootWidgetMembers::Table(table_widget) => {
+                let RootState { focused_item, sort, .. } =
+                    ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, table_widget.__id__);
+
+                let focus_task = focused_item.focus_previous().unwrap_or_else(|| Task::none());
+
+                let item_focus_event =
+                    ComponentWidgets::table_item_focus_event(self.plugin_id.clone(), focused_item, table_widget, sort);
 
                 Task::batch([item_focus_event, focus_task])
             }
@@ -247,44 +345,37 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Form(_) => Task::none(),
             RootWidgetMembers::Inline(_) => Task::none(),
             RootWidgetMembers::List(widget) => {
+                let filter =
+                    ComponentWidgets::item_filter_on_state(&self.state, widget.filtering, &widget.content.search_bar);
+
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, widget.__id__);
 
-                let total = widget
-                    .content
-                    .ordered_members
-                    .iter()
-                    .flat_map(|members| {
-                        match members {
-                            ListWidgetOrderedMembers::ListItem(widget) => vec![widget],
-                            ListWidgetOrderedMembers::ListSection(widget) => {
-                                widget
-                                    .content
-                                    .ordered_members
-                                    .iter()
-                                    .map(|members| {
-                                        match members {
-                                            ListSectionWidgetOrderedMembers::ListItem(widget) => widget,
-                                        }
-                                    })
-                                    .collect()
-                            }
-                        }
-                    })
-                    .count();
+                let total = ComponentWidgets::list_items(widget, &filter).len();
+
+                focused_item.set_section_title_rows(ComponentWidgets::list_section_title_rows(widget, &filter));
 
                 let focus_task = focused_item.focus_next(total).unwrap_or_else(|| Task::none());
 
                 let item_focus_event =
-                    ComponentWidgets::list_item_focus_event(self.plugin_id.clone(), focused_item, widget);
+                    ComponentWidgets::list_item_focus_event(self.plugin_id.clone(), focused_item, widget, &filter);
 
-                Task::batch([item_focus_event, focus_task])
+                let load_more_event =
+                    ComponentWidgets::list_load_more_event(self.plugin_id.clone(), focused_item, widget, &filter);
+
+                Task::batch([item_focus_event, focus_task, load_more_event])
             }
             RootWidgetMembers::Grid(grid_widget) => {
+                let filter = ComponentWidgets::item_filter_on_state(
+                    &self.state,
+                    grid_widget.filtering,
+                    &grid_widget.content.search_bar,
+                );
+
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, grid_widget.__id__);
 
-                let amount_per_section_total = ComponentWidgets::grid_section_sizes(grid_widget);
+                let amount_per_section_total = ComponentWidgets::grid_section_sizes(grid_widget, &filter);
 
                 let total = amount_per_section_total.iter().map(|data| data.amount_in_section).sum();
 
@@ -299,10 +390,21 @@ impl<'b> ComponentWidgetsMut<'b> {
 
                     let _ = focused_item.focus_next(total);
 
-                    let item_focus_event =
-                        ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget);
-
-                    return Task::batch([unfocus, focused_item.scroll_to(0), item_focus_event]);
+                    let item_focus_event = ComponentWidgets::grid_item_focus_event(
+                        self.plugin_id.clone(),
+                        focused_item,
+                        grid_widget,
+                        &filter,
+                    );
+
+                    let load_more_event = ComponentWidgets::grid_load_more_event(
+                        self.plugin_id.clone(),
+                        focused_item,
+                        grid_widget,
+                        &filter,
+                    );
+
+                    return Task::batch([unfocus, focused_item.scroll_to(0), item_focus_event, load_more_event]);
                 };
 
                 let focus_task = match grid_down_offset(*current_index, amount_per_section_total) {
@@ -316,7 +418,23 @@ impl<'b> ComponentWidgetsMut<'b> {
                 };
 
                 let item_focus_event =
-                    ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget);
+                    ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget, &filter);
+
+                let load_more_event =
+                    ComponentWidgets::grid_load_more_event(self.plugin_id.clone(), focused_item, grid_widget, &filter);
+
+                Task::batch([item_focus_event, focus_task, load_more_event])
+            }
+            RootWidgetMembers::Table(table_widget) => {
+                let RootState { focused_item, sort, .. } =
+                    ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, table_widget.__id__);
+
+                let total = ComponentWidgets::table_rows(table_widget, sort).len();
+
+                let focus_task = focused_item.focus_next(total).unwrap_or_else(|| Task::none());
+
+                let item_focus_event =
+                    ComponentWidgets::table_item_focus_event(self.plugin_id.clone(), focused_item, table_widget, sort);
 
                 Task::batch([item_focus_event, focus_task])
             }
@@ -337,7 +455,14 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Form(_) => Task::none(),
             RootWidgetMembers::Inline(_) => Task::none(),
             RootWidgetMembers::List(_) => Task::none(),
+            RootWidgetMembers::Table(_) => Task::none(),
             RootWidgetMembers::Grid(grid_widget) => {
+                let filter = ComponentWidgets::item_filter_on_state(
+                    &self.state,
+                    grid_widget.filtering,
+                    &grid_widget.content.search_bar,
+                );
+
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, grid_widget.__id__);
 
@@ -345,7 +470,7 @@ impl<'b> ComponentWidgetsMut<'b> {
 
                 // focused_item.scroll_to(0)
 
-                ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget)
+                ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget, &filter)
             }
         }
     }
@@ -364,7 +489,14 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Form(_) => Task::none(),
             RootWidgetMembers::Inline(_) => Task::none(),
             RootWidgetMembers::List(_) => Task::none(),
+            RootWidgetMembers::Table(_) => Task::none(),
             RootWidgetMembers::Grid(grid_widget) => {
+                let filter = ComponentWidgets::item_filter_on_state(
+                    &self.state,
+                    grid_widget.filtering,
+                    &grid_widget.content.search_bar,
+                );
+
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, grid_widget.__id__);
 
@@ -389,13 +521,20 @@ impl<'b> ComponentWidgetsMut<'b> {
                             }
                         }
                     })
+                    .filter(|widget| filter.matches(widget.title.as_deref(), &widget.keywords))
                     .count();
 
                 let _ = focused_item.focus_next(total);
 
                 // focused_item.scroll_to(0)
 
-                ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget)
+                let item_focus_event =
+                    ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget, &filter);
+
+                let load_more_event =
+                    ComponentWidgets::grid_load_more_event(self.plugin_id.clone(), focused_item, grid_widget, &filter);
+
+                Task::batch([item_focus_event, load_more_event])
             }
         }
     }
@@ -1,11 +1,26 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiWidgetId;
+use iced::widget::text_editor;
 
 use crate::model::UiViewEvent;
+use crate::ui::widget::file_browser::FileBrowserLocation;
+use crate::ui::widget::file_browser::FileBrowserState;
+use crate::ui::widget::file_browser::PathKind;
 use crate::ui::widget::state::CheckboxState;
 use crate::ui::widget::state::ComponentWidgetState;
 use crate::ui::widget::state::DatePickerState;
+use crate::ui::widget::state::FilePickerState;
+use crate::ui::widget::state::MarkdownState;
+use crate::ui::widget::state::NumberFieldState;
+use crate::ui::widget::state::RootState;
 use crate::ui::widget::state::SelectState;
+use crate::ui::widget::state::TableSort;
+use crate::ui::widget::state::TagPickerState;
+use crate::ui::widget::state::TextAreaState;
 use crate::ui::widget::state::TextFieldState;
 use crate::ui::AppMsg;
 
@@ -43,6 +58,14 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String,
     },
+    TextAreaAction {
+        widget_id: UiWidgetId,
+        action: text_editor::Action,
+    },
+    OnChangeNumberField {
+        widget_id: UiWidgetId,
+        value: f64,
+    },
     SubmitDatePicker {
         widget_id: UiWidgetId,
         value: String,
@@ -58,9 +81,47 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String,
     },
+    SelectDropdown {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    AddTagPickerItem {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    RemoveTagPickerItem {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    OnChangeFilePicker {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    ToggleFilePicker {
+        widget_id: UiWidgetId,
+    },
+    NavigateFilePicker {
+        widget_id: UiWidgetId,
+        path: PathBuf,
+    },
+    FilePickerBrowserLoaded {
+        widget_id: UiWidgetId,
+        browser: FileBrowserState,
+    },
+    SubmitFilePicker {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    SubmitForm {
+        widget_id: UiWidgetId,
+        values: HashMap<String, UiPropertyValue>,
+    },
     ToggleActionPanel {
         widget_id: UiWidgetId,
     },
+    SubmitFormClick {
+        widget_id: UiWidgetId,
+    },
     FocusListItem {
         list_widget_id: UiWidgetId,
         item_id: Option<String>,
@@ -69,6 +130,38 @@ pub enum ComponentWidgetEvent {
         grid_widget_id: UiWidgetId,
         item_id: Option<String>,
     },
+    ScrollList {
+        list_widget_id: UiWidgetId,
+        position: f32,
+        section_title_rows: Vec<usize>,
+        load_more_at: Option<usize>,
+    },
+    ScrollGrid {
+        grid_widget_id: UiWidgetId,
+        position: f32,
+        load_more_at: Option<usize>,
+    },
+    LoadMoreList {
+        list_widget_id: UiWidgetId,
+        item_amount: usize,
+    },
+    LoadMoreGrid {
+        grid_widget_id: UiWidgetId,
+        item_amount: usize,
+    },
+    FocusTableItem {
+        table_widget_id: UiWidgetId,
+        item_id: Option<String>,
+    },
+    ScrollTable {
+        table_widget_id: UiWidgetId,
+        position: f32,
+    },
+    SortTable {
+        table_widget_id: UiWidgetId,
+        column_index: usize,
+        column_id: String,
+    },
     PreviousView,
     RunPrimaryAction {
         widget_id: UiWidgetId,
@@ -78,11 +171,29 @@ pub enum ComponentWidgetEvent {
 }
 
 impl ComponentWidgetEvent {
-    pub fn handle(self, _plugin_id: PluginId, state: Option<&mut ComponentWidgetState>) -> Option<UiViewEvent> {
+    pub fn handle(self, plugin_id: PluginId, state: Option<&mut ComponentWidgetState>) -> Option<UiViewEvent> {
         match self {
             ComponentWidgetEvent::LinkClick { widget_id: _, href } => Some(UiViewEvent::Open { href }),
             ComponentWidgetEvent::TagClick { widget_id } => Some(create_metadata_tag_item_on_click_event(widget_id)),
-            ComponentWidgetEvent::RunAction { widget_id, id } | ComponentWidgetEvent::ActionClick { widget_id, id } => {
+            ComponentWidgetEvent::RunAction { widget_id, id } => {
+                // root widget id is used by launcher-provided "Toggle Detail" action
+                if let Some(state) = root_state(widget_id, state) {
+                    state.show_detail = !state.show_detail;
+
+                    return None;
+                }
+
+                Some(create_action_on_action_event(widget_id, id))
+            }
+            ComponentWidgetEvent::ActionClick { widget_id, id } => {
+                if let Some(state) = root_state(widget_id, state) {
+                    state.show_detail = !state.show_detail;
+
+                    return Some(UiViewEvent::AppEvent {
+                        event: AppMsg::ToggleActionPanel { keyboard: false },
+                    });
+                }
+
                 Some(create_action_on_action_event(widget_id, id))
             }
             ComponentWidgetEvent::ToggleDatePicker { widget_id } => {
@@ -150,6 +261,19 @@ impl ComponentWidgetEvent {
 
                 Some(create_select_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::SelectDropdown { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::Select(SelectState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *state_value = Some(value.clone());
+
+                Some(create_dropdown_on_change_event(widget_id, value))
+            }
             ComponentWidgetEvent::OnChangeTextField { widget_id, value } => {
                 let Some(state) = state else {
                     return None;
@@ -189,11 +313,180 @@ impl ComponentWidgetEvent {
 
                 Some(create_search_bar_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::TextAreaAction { widget_id, action } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::TextArea(TextAreaState { content, preview }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                // cursor movement and selection are not changes of value
+                let is_edit = action.is_edit();
+
+                content.perform(action);
+
+                if is_edit {
+                    let value = content.text();
+
+                    if let Some(preview) = preview {
+                        *preview = MarkdownState::new(&value);
+                    }
+
+                    Some(create_text_area_on_change_event(widget_id, Some(value)))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::OnChangeNumberField { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::NumberField(NumberFieldState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *state_value = value;
+
+                Some(create_number_field_on_change_event(widget_id, Some(value)))
+            }
+            ComponentWidgetEvent::AddTagPickerItem { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::TagPicker(TagPickerState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                if state_value.contains(&value) {
+                    return None;
+                }
+
+                state_value.push(value);
+
+                Some(create_tag_picker_on_change_event(widget_id, state_value.clone()))
+            }
+            ComponentWidgetEvent::RemoveTagPickerItem { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::TagPicker(TagPickerState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                state_value.retain(|item| item != &value);
+
+                Some(create_tag_picker_on_change_event(widget_id, state_value.clone()))
+            }
+            ComponentWidgetEvent::OnChangeFilePicker { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::FilePicker(FilePickerState {
+                    state_value, path_kind, ..
+                }) = state
+                else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *state_value = value.clone();
+                *path_kind = PathKind::of(&value);
+
+                Some(create_file_picker_on_change_event(widget_id, Some(value)))
+            }
+            ComponentWidgetEvent::ToggleFilePicker { widget_id } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::FilePicker(FilePickerState {
+                    state_value, browser, ..
+                }) = state
+                else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                match browser {
+                    None => {
+                        Some(UiViewEvent::AppEvent {
+                            event: AppMsg::LoadFilePickerBrowser {
+                                plugin_id,
+                                widget_id,
+                                location: FileBrowserLocation::Value(state_value.clone()),
+                            },
+                        })
+                    }
+                    Some(_) => {
+                        *browser = None;
+
+                        None
+                    }
+                }
+            }
+            ComponentWidgetEvent::NavigateFilePicker { widget_id, path } => {
+                Some(UiViewEvent::AppEvent {
+                    event: AppMsg::LoadFilePickerBrowser {
+                        plugin_id,
+                        widget_id,
+                        location: FileBrowserLocation::Directory(path),
+                    },
+                })
+            }
+            ComponentWidgetEvent::FilePickerBrowserLoaded {
+                widget_id,
+                browser: loaded_browser,
+            } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::FilePicker(FilePickerState { browser, .. }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *browser = Some(loaded_browser);
+
+                None
+            }
+            ComponentWidgetEvent::SubmitFilePicker { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::FilePicker(FilePickerState {
+                    state_value,
+                    path_kind,
+                    browser,
+                    ..
+                }) = state
+                else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *state_value = value.clone();
+                *path_kind = PathKind::of(&value);
+                *browser = None;
+
+                Some(create_file_picker_on_change_event(widget_id, Some(value)))
+            }
+            ComponentWidgetEvent::SubmitForm { widget_id, values } => {
+                Some(create_form_on_submit_event(widget_id, values))
+            }
             ComponentWidgetEvent::ToggleActionPanel { .. } => {
                 Some(UiViewEvent::AppEvent {
                     event: AppMsg::ToggleActionPanel { keyboard: false },
                 })
             }
+            ComponentWidgetEvent::SubmitFormClick { .. } => {
+                Some(UiViewEvent::AppEvent {
+                    event: AppMsg::SubmitForm,
+                })
+            }
             ComponentWidgetEvent::FocusListItem {
                 list_widget_id,
                 item_id,
@@ -202,6 +495,103 @@ impl ComponentWidgetEvent {
                 grid_widget_id,
                 item_id,
             } => Some(create_grid_on_item_focus_change_event(grid_widget_id, item_id)),
+            ComponentWidgetEvent::ScrollList {
+                list_widget_id,
+                position,
+                section_title_rows,
+                load_more_at,
+            } => {
+                let root_state = root_state(list_widget_id, state)?;
+
+                root_state.focused_item.set_position(position);
+                root_state.focused_item.set_section_title_rows(section_title_rows);
+
+                if request_load_more(root_state, load_more_at?) {
+                    Some(create_list_on_load_more_event(list_widget_id))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::ScrollGrid {
+                grid_widget_id,
+                position,
+                load_more_at,
+            } => {
+                let root_state = root_state(grid_widget_id, state)?;
+
+                root_state.focused_item.set_position(position);
+
+                if request_load_more(root_state, load_more_at?) {
+                    Some(create_grid_on_load_more_event(grid_widget_id))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::LoadMoreList {
+                list_widget_id,
+                item_amount,
+            } => {
+                let root_state = root_state(list_widget_id, state)?;
+
+                if request_load_more(root_state, item_amount) {
+                    Some(create_list_on_load_more_event(list_widget_id))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::LoadMoreGrid {
+                grid_widget_id,
+                item_amount,
+            } => {
+                let root_state = root_state(grid_widget_id, state)?;
+
+                if request_load_more(root_state, item_amount) {
+                    Some(create_grid_on_load_more_event(grid_widget_id))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::FocusTableItem {
+                table_widget_id,
+                item_id,
+            } => Some(create_table_on_item_focus_change_event(table_widget_id, item_id)),
+            ComponentWidgetEvent::ScrollTable {
+                table_widget_id,
+                position,
+            } => {
+                let root_state = root_state(table_widget_id, state)?;
+
+                root_state.focused_item.set_position(position);
+
+                None
+            }
+            ComponentWidgetEvent::SortTable {
+                table_widget_id,
+                column_index,
+                column_id,
+            } => {
+                let root_state = root_state(table_widget_id, state)?;
+
+                // first click sorts ascending, next clicks on the same column flip the direction
+                let descending = match &root_state.sort {
+                    Some(sort) if sort.column_index == column_index => !sort.descending,
+                    _ => false,
+                };
+
+                root_state.sort = Some(TableSort {
+                    column_index,
+                    descending,
+                });
+
+                // focused index doesn't point to the same row after rows are reordered
+                root_state.focused_item.reset(false);
+
+                Some(create_table_on_sort_change_event(
+                    table_widget_id,
+                    column_id,
+                    descending,
+                ))
+            }
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
                 panic!("widget_id on these events is not supposed to be called")
             }
@@ -224,12 +614,31 @@ impl ComponentWidgetEvent {
             ComponentWidgetEvent::CancelDatePicker { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleCheckbox { widget_id, .. } => widget_id,
             ComponentWidgetEvent::SelectPickList { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SelectDropdown { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeTextField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangePasswordField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeSearchBar { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::TextAreaAction { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::OnChangeNumberField { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::AddTagPickerItem { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::RemoveTagPickerItem { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::OnChangeFilePicker { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::ToggleFilePicker { widget_id } => widget_id,
+            ComponentWidgetEvent::NavigateFilePicker { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::FilePickerBrowserLoaded { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SubmitFilePicker { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SubmitForm { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleActionPanel { widget_id } => widget_id,
+            ComponentWidgetEvent::SubmitFormClick { widget_id } => widget_id,
             ComponentWidgetEvent::FocusListItem { list_widget_id, .. } => list_widget_id,
             ComponentWidgetEvent::FocusGridItem { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::ScrollList { list_widget_id, .. } => list_widget_id,
+            ComponentWidgetEvent::ScrollGrid { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::LoadMoreList { list_widget_id, .. } => list_widget_id,
+            ComponentWidgetEvent::LoadMoreGrid { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::FocusTableItem { table_widget_id, .. } => table_widget_id,
+            ComponentWidgetEvent::ScrollTable { table_widget_id, .. } => table_widget_id,
+            ComponentWidgetEvent::SortTable { table_widget_id, .. } => table_widget_id,
             ComponentWidgetEvent::RunPrimaryAction { widget_id, .. } => widget_id,
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
                 panic!("widget_id on these events is not supposed to be called")
@@ -238,3 +647,27 @@ impl ComponentWidgetEvent {
         .to_owned()
     }
 }
+
+fn root_state(widget_id: UiWidgetId, state: Option<&mut ComponentWidgetState>) -> Option<&mut RootState> {
+    let Some(state) = state else {
+        return None;
+    };
+
+    let ComponentWidgetState::Root(root_state) = state else {
+        panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+    };
+
+    Some(root_state)
+}
+
+// onLoadMore is sent only once per amount of items,
+// next one is sent only after plugin renders more items
+fn request_load_more(root_state: &mut RootState, item_amount: usize) -> bool {
+    if root_state.load_more_requested_at == Some(item_amount) {
+        false
+    } else {
+        root_state.load_more_requested_at = Some(item_amount);
+
+        true
+    }
+}
@@ -0,0 +1,172 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use gauntlet_common::dirs::Dirs;
+
+#[derive(Debug, Clone)]
+pub struct FileBrowserState {
+    pub directory: PathBuf,
+    pub entries: Vec<FileBrowserEntry>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileBrowserEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_dir: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum FileBrowserLocation {
+    // current value of file picker
+    Value(String),
+    Directory(PathBuf),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathKind {
+    Missing,
+    File,
+    Directory,
+}
+
+impl FileBrowserState {
+    // blocking, so it is not called directly in update
+    pub fn load(location: FileBrowserLocation) -> FileBrowserState {
+        match location {
+            FileBrowserLocation::Value(value) => FileBrowserState::open(&value),
+            FileBrowserLocation::Directory(directory) => FileBrowserState::read(directory),
+        }
+    }
+
+    // starts in directory of current value if there is one, home directory otherwise
+    pub fn open(value: &str) -> FileBrowserState {
+        let path = Path::new(value);
+
+        let directory = if path.is_dir() {
+            path.to_path_buf()
+        } else {
+            match path.parent() {
+                Some(parent) if parent.is_dir() => parent.to_path_buf(),
+                _ => Dirs::new().home_dir(),
+            }
+        };
+
+        FileBrowserState::read(directory)
+    }
+
+    pub fn read(directory: PathBuf) -> FileBrowserState {
+        match list_directory(&directory) {
+            Ok(entries) => {
+                FileBrowserState {
+                    directory,
+                    entries,
+                    error: None,
+                }
+            }
+            Err(err) => {
+                FileBrowserState {
+                    directory,
+                    entries: vec![],
+                    error: Some(format!("{:#}", err)),
+                }
+            }
+        }
+    }
+}
+
+fn list_directory(directory: &Path) -> anyhow::Result<Vec<FileBrowserEntry>> {
+    let mut entries = vec![];
+
+    for entry in std::fs::read_dir(directory)? {
+        let entry = entry?;
+
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if name.starts_with('.') {
+            continue;
+        }
+
+        let path = entry.path();
+
+        // follows symlinks, so link to directory can be entered
+        let is_dir = path.is_dir();
+
+        entries.push(FileBrowserEntry { name, path, is_dir })
+    }
+
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    Ok(entries)
+}
+
+impl PathKind {
+    // empty value is not checked
+    pub fn of(value: &str) -> Option<PathKind> {
+        if value.is_empty() {
+            return None;
+        }
+
+        let path = Path::new(value);
+
+        if path.is_dir() {
+            Some(PathKind::Directory)
+        } else if path.exists() {
+            Some(PathKind::File)
+        } else {
+            Some(PathKind::Missing)
+        }
+    }
+}
+
+pub fn validate_path(path_kind: Option<PathKind>, directory: bool) -> Option<String> {
+    match path_kind? {
+        PathKind::Missing => Some("Path does not exist".to_string()),
+        PathKind::File if directory => Some("Path is not a directory".to_string()),
+        PathKind::Directory if !directory => Some("Path is a directory".to_string()),
+        PathKind::File | PathKind::Directory => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directories_first_hidden_skipped() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let directory = temp_dir.path().to_path_buf();
+
+        std::fs::create_dir_all(directory.join("b-dir")).unwrap();
+        std::fs::write(directory.join("a-file"), "").unwrap();
+        std::fs::write(directory.join("C-file"), "").unwrap();
+        std::fs::write(directory.join(".hidden"), "").unwrap();
+
+        let state = FileBrowserState::read(directory.clone());
+
+        let names: Vec<_> = state.entries.iter().map(|entry| entry.name.as_str()).collect();
+
+        assert_eq!(names, vec!["b-dir", "a-file", "C-file"]);
+        assert_eq!(state.error, None);
+
+        let file_kind = PathKind::of(&directory.join("a-file").to_string_lossy());
+        let missing_kind = PathKind::of(&directory.join("missing").to_string_lossy());
+
+        assert_eq!(file_kind, Some(PathKind::File));
+        assert_eq!(PathKind::of(""), None);
+        assert_eq!(validate_path(file_kind, false), None);
+        assert_eq!(
+            validate_path(file_kind, true),
+            Some("Path is not a directory".to_string())
+        );
+        assert_eq!(
+            validate_path(missing_kind, false),
+            Some("Path does not exist".to_string())
+        );
+    }
+}
@@ -3,14 +3,23 @@ use std::fmt::Display;
 
 use gauntlet_common::model::CheckboxWidget;
 use gauntlet_common::model::DatePickerWidget;
+use gauntlet_common::model::FilePickerWidget;
 use gauntlet_common::model::FormWidget;
 use gauntlet_common::model::FormWidgetOrderedMembers;
+use gauntlet_common::model::LauncherAction;
+use gauntlet_common::model::NumberFieldWidget;
 use gauntlet_common::model::PasswordFieldWidget;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::SelectWidget;
 use gauntlet_common::model::SelectWidgetOrderedMembers;
 use gauntlet_common::model::SeparatorWidget;
+use gauntlet_common::model::TagPickerWidget;
+use gauntlet_common::model::TagPickerWidgetOrderedMembers;
+use gauntlet_common::model::TextAreaWidget;
 use gauntlet_common::model::TextFieldWidget;
+use gauntlet_common::model::UiPropertyValue;
+use gauntlet_common::model::UiRenderLocation;
+use gauntlet_common::model::UiWidgetId;
 use iced::advanced::text::Shaping;
 use iced::alignment::Horizontal;
 use iced::widget::button;
@@ -22,48 +31,120 @@ use iced::widget::pick_list;
 use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::text;
+use iced::widget::text_editor;
 use iced::widget::text_input;
 use iced::widget::Space;
 use iced::Alignment;
 use iced::Length;
+use iced::Task;
 use iced_aw::date_picker;
+use iced_aw::NumberInput;
 
 use crate::ui::state::PluginViewState;
+use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::date_picker::DatePickerStyle;
+use crate::ui::theme::number_input::NumberInputStyle;
 use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::text_editor::TextEditorStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::file_browser::validate_path;
+use crate::ui::widget::file_browser::FileBrowserState;
 use crate::ui::widget::state::CheckboxState;
 use crate::ui::widget::state::DatePickerState;
+use crate::ui::widget::state::FilePickerState;
+use crate::ui::widget::state::NumberFieldState;
 use crate::ui::widget::state::RootState;
 use crate::ui::widget::state::SelectState;
+use crate::ui::widget::state::TagPickerState;
+use crate::ui::widget::state::TextAreaState;
 use crate::ui::widget::state::TextFieldState;
+use crate::ui::AppMsg;
 
 impl<'b> ComponentWidgets<'b> {
     fn render_text_field_widget<'a>(&self, widget: &TextFieldWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
-        let TextFieldState { state_value, .. } = self.text_field_state(widget.__id__);
+        let TextFieldState {
+            text_input_id,
+            state_value,
+        } = self.text_field_state(widget.__id__);
 
         text_input("", state_value)
+            .id(text_input_id.clone())
             .on_input(move |value| ComponentWidgetEvent::OnChangeTextField { widget_id, value })
             .themed(TextInputStyle::FormInput)
     }
 
     fn render_password_field_widget<'a>(&self, widget: &PasswordFieldWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
-        let TextFieldState { state_value, .. } = self.text_field_state(widget_id);
+        let TextFieldState {
+            text_input_id,
+            state_value,
+        } = self.text_field_state(widget_id);
 
         text_input("", state_value)
+            .id(text_input_id.clone())
             .secure(true)
             .on_input(move |value| ComponentWidgetEvent::OnChangePasswordField { widget_id, value })
             .themed(TextInputStyle::FormInput)
     }
 
+    fn render_text_area_widget<'a>(&self, widget: &TextAreaWidget) -> Element<'a, ComponentWidgetEvent>
+    where
+        'b: 'a,
+    {
+        let widget_id = widget.__id__;
+        let TextAreaState { content, preview } = self.text_area_state(widget_id);
+
+        let editor: Element<_> = text_editor(content)
+            .height(Length::Fixed(120.0))
+            .on_action(move |action| ComponentWidgetEvent::TextAreaAction { widget_id, action })
+            .themed(TextEditorStyle::FormInput);
+
+        match preview {
+            Some(preview) => {
+                let preview = self.render_markdown(widget_id, preview);
+
+                column([editor, preview]).into()
+            }
+            None => editor,
+        }
+    }
+
+    fn render_number_field_widget<'a>(&self, widget: &NumberFieldWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let NumberFieldState { state_value } = self.number_field_state(widget_id);
+
+        let min = widget.min.unwrap_or(f64::MIN);
+        let max = widget.max.unwrap_or(f64::MAX);
+
+        NumberInput::new(state_value, min..=max, move |value| {
+            ComponentWidgetEvent::OnChangeNumberField { widget_id, value }
+        })
+        .step(widget.step.unwrap_or(1.0))
+        .themed(NumberInputStyle::Default)
+    }
+
+    fn number_field_error(&self, widget: &NumberFieldWidget) -> Option<String> {
+        let NumberFieldState { state_value } = self.number_field_state(widget.__id__);
+
+        // number input itself doesn't allow going out of bounds, but value set by plugin can be anything
+        match (widget.min, widget.max) {
+            (Some(min), Some(max)) if *state_value < min || *state_value > max => {
+                Some(format!("Value must be between {} and {}", min, max))
+            }
+            (Some(min), None) if *state_value < min => Some(format!("Value must be at least {}", min)),
+            (None, Some(max)) if *state_value > max => Some(format!("Value must be at most {}", max)),
+            _ => None,
+        }
+    }
+
     fn render_checkbox_widget<'a>(&self, widget: &CheckboxWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
         let CheckboxState { state_value } = self.checkbox_state(widget_id);
@@ -140,33 +221,293 @@ impl<'b> ComponentWidgets<'b> {
         .themed(PickListStyle::Default)
     }
 
+    fn render_tag_picker_widget<'a>(&self, widget: &TagPickerWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let TagPickerState { state_value } = self.tag_picker_state(widget_id);
+
+        let items = tag_picker_items(widget);
+
+        let selected: Vec<Element<_>> = state_value
+            .iter()
+            .map(|value| {
+                let label = items
+                    .iter()
+                    .find(|item| &item.value == value)
+                    .map(|item| item.label.clone())
+                    .unwrap_or_else(|| value.clone());
+
+                let content = text(format!("{}  ✕", label)).shaping(Shaping::Advanced);
+
+                let tag: Element<_> = button(content)
+                    .on_press(ComponentWidgetEvent::RemoveTagPickerItem {
+                        widget_id,
+                        value: value.clone(),
+                    })
+                    .themed(ButtonStyle::FormInputTagPickerItem);
+
+                container(tag).themed(ContainerStyle::MetadataTagItem)
+            })
+            .collect();
+
+        let available: Vec<_> = items
+            .into_iter()
+            .filter(|item| !state_value.contains(&item.value))
+            .collect();
+
+        let pick_list: Element<_> = pick_list(available, None::<SelectItem>, move |item| {
+            ComponentWidgetEvent::AddTagPickerItem {
+                widget_id,
+                value: item.value,
+            }
+        })
+        .placeholder("Add...")
+        .themed(PickListStyle::Default);
+
+        if selected.is_empty() {
+            pick_list
+        } else {
+            column([row(selected).wrap().into(), pick_list]).into()
+        }
+    }
+
+    fn tag_picker_error(&self, widget: &TagPickerWidget) -> Option<String> {
+        let TagPickerState { state_value } = self.tag_picker_state(widget.__id__);
+
+        let items = tag_picker_items(widget);
+
+        state_value
+            .iter()
+            .find(|value| !items.iter().any(|item| &item.value == *value))
+            .map(|value| format!("Unknown value: {}", value))
+    }
+
+    fn render_file_picker_widget<'a>(&self, widget: &FilePickerWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let FilePickerState {
+            text_input_id,
+            state_value,
+            browser,
+            ..
+        } = self.file_picker_state(widget_id);
+
+        let input: Element<_> = text_input("", state_value)
+            .id(text_input_id.clone())
+            .on_input(move |value| ComponentWidgetEvent::OnChangeFilePicker { widget_id, value })
+            .themed(TextInputStyle::FormInput);
+
+        let browse_text = match browser {
+            None => "Browse",
+            Some(_) => "Close",
+        };
+
+        let browse: Element<_> = button(text(browse_text))
+            .on_press(ComponentWidgetEvent::ToggleFilePicker { widget_id })
+            .themed(ButtonStyle::FormInputButton);
+
+        let input: Element<_> = row([input, browse]).align_y(Alignment::Center).into();
+
+        match browser {
+            None => input,
+            Some(browser) => {
+                let browser = render_file_browser(widget_id, browser, widget.directory.unwrap_or(false));
+
+                column([input, browser]).into()
+            }
+        }
+    }
+
+    fn file_picker_error(&self, widget: &FilePickerWidget) -> Option<String> {
+        let FilePickerState { path_kind, .. } = self.file_picker_state(widget.__id__);
+
+        validate_path(*path_kind, widget.directory.unwrap_or(false))
+    }
+
     fn render_separator_widget<'a>(&self, _widget: &SeparatorWidget) -> Element<'a, ComponentWidgetEvent> {
         horizontal_rule(1).into()
     }
 
+    // validation done by launcher itself, on top of errors provided by plugin
+    fn form_field_validation_error(&self, members: &FormWidgetOrderedMembers) -> Option<String> {
+        match members {
+            FormWidgetOrderedMembers::NumberField(widget) => self.number_field_error(widget),
+            FormWidgetOrderedMembers::TagPicker(widget) => self.tag_picker_error(widget),
+            FormWidgetOrderedMembers::FilePicker(widget) => self.file_picker_error(widget),
+            _ => None,
+        }
+    }
+
+    fn form_field_is_empty(&self, members: &FormWidgetOrderedMembers) -> bool {
+        match members {
+            FormWidgetOrderedMembers::Separator(_) => false,
+            FormWidgetOrderedMembers::TextField(widget) => self.text_field_state(widget.__id__).state_value.is_empty(),
+            FormWidgetOrderedMembers::PasswordField(widget) => {
+                self.text_field_state(widget.__id__).state_value.is_empty()
+            }
+            FormWidgetOrderedMembers::TextArea(widget) => {
+                self.text_area_state(widget.__id__).content.text().trim().is_empty()
+            }
+            FormWidgetOrderedMembers::NumberField(_) => false,
+            FormWidgetOrderedMembers::Checkbox(widget) => !self.checkbox_state(widget.__id__).state_value,
+            FormWidgetOrderedMembers::DatePicker(_) => false,
+            FormWidgetOrderedMembers::Select(widget) => self.select_state(widget.__id__).state_value.is_none(),
+            FormWidgetOrderedMembers::TagPicker(widget) => self.tag_picker_state(widget.__id__).state_value.is_empty(),
+            FormWidgetOrderedMembers::FilePicker(widget) => {
+                self.file_picker_state(widget.__id__).state_value.is_empty()
+            }
+        }
+    }
+
+    // missing required values are only reported after user tried to submit the form
+    fn form_field_error(&self, members: &FormWidgetOrderedMembers, show_required: bool) -> Option<String> {
+        let field = form_field(members)?;
+
+        if let Some(error) = field.error {
+            return Some(error.to_string());
+        }
+
+        if let Some(error) = self.form_field_validation_error(members) {
+            return Some(error);
+        }
+
+        if show_required && field.required && self.form_field_is_empty(members) {
+            Some("Required".to_string())
+        } else {
+            None
+        }
+    }
+
+    fn form_field_value(&self, members: &FormWidgetOrderedMembers) -> UiPropertyValue {
+        match members {
+            FormWidgetOrderedMembers::Separator(_) => UiPropertyValue::Undefined,
+            FormWidgetOrderedMembers::TextField(widget) => {
+                UiPropertyValue::String(self.text_field_state(widget.__id__).state_value.clone())
+            }
+            FormWidgetOrderedMembers::PasswordField(widget) => {
+                UiPropertyValue::String(self.text_field_state(widget.__id__).state_value.clone())
+            }
+            FormWidgetOrderedMembers::TextArea(widget) => {
+                UiPropertyValue::String(self.text_area_state(widget.__id__).content.text())
+            }
+            FormWidgetOrderedMembers::NumberField(widget) => {
+                UiPropertyValue::Number(self.number_field_state(widget.__id__).state_value)
+            }
+            FormWidgetOrderedMembers::Checkbox(widget) => {
+                UiPropertyValue::Bool(self.checkbox_state(widget.__id__).state_value)
+            }
+            FormWidgetOrderedMembers::DatePicker(widget) => {
+                UiPropertyValue::String(self.date_picker_state(widget.__id__).state_value.to_string())
+            }
+            FormWidgetOrderedMembers::Select(widget) => {
+                match &self.select_state(widget.__id__).state_value {
+                    None => UiPropertyValue::Undefined,
+                    Some(value) => UiPropertyValue::String(value.clone()),
+                }
+            }
+            FormWidgetOrderedMembers::TagPicker(widget) => {
+                let values = self
+                    .tag_picker_state(widget.__id__)
+                    .state_value
+                    .iter()
+                    .map(|value| UiPropertyValue::String(value.clone()))
+                    .collect();
+
+                UiPropertyValue::Array(values)
+            }
+            FormWidgetOrderedMembers::FilePicker(widget) => {
+                UiPropertyValue::String(self.file_picker_state(widget.__id__).state_value.clone())
+            }
+        }
+    }
+
+    fn form_field_text_input_id(&self, members: &FormWidgetOrderedMembers) -> Option<text_input::Id> {
+        match members {
+            FormWidgetOrderedMembers::TextField(widget) => {
+                Some(self.text_field_state(widget.__id__).text_input_id.clone())
+            }
+            FormWidgetOrderedMembers::PasswordField(widget) => {
+                Some(self.text_field_state(widget.__id__).text_input_id.clone())
+            }
+            FormWidgetOrderedMembers::FilePicker(widget) => {
+                Some(self.file_picker_state(widget.__id__).text_input_id.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn submit_form(&self, widget: &FormWidget) -> Task<AppMsg> {
+        let invalid_field = widget
+            .content
+            .ordered_members
+            .iter()
+            .find(|members| self.form_field_error(members, true).is_some());
+
+        if let Some(members) = invalid_field {
+            // not every input can be focused, but error is shown under the field in any case
+            return match self.form_field_text_input_id(members) {
+                None => Task::none(),
+                Some(text_input_id) => text_input::focus(text_input_id),
+            };
+        }
+
+        let values = widget
+            .content
+            .ordered_members
+            .iter()
+            .filter_map(|members| {
+                let id = form_field(members)?.id.as_ref()?;
+
+                Some((id.to_string(), self.form_field_value(members)))
+            })
+            .collect();
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id: self.plugin_id.clone(),
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::SubmitForm {
+                widget_id: widget.__id__,
+                values,
+            },
+        })
+    }
+
     pub fn render_form_widget<'a>(
         &self,
         widget: &FormWidget,
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
-    ) -> Element<'a, ComponentWidgetEvent> {
+        keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
+    ) -> Element<'a, ComponentWidgetEvent>
+    where
+        'b: 'a,
+    {
         let widget_id = widget.__id__;
-        let RootState { show_action_panel, .. } = self.root_state(widget_id);
+        let RootState {
+            show_action_panel,
+            submit_attempted,
+            ..
+        } = self.root_state(widget_id);
 
         let items: Vec<Element<_>> = widget
             .content
             .ordered_members
             .iter()
             .map(|members| {
-                fn render_field<'c, 'd>(
-                    field: Element<'c, ComponentWidgetEvent>,
-                    label: &'d Option<String>,
+                fn render_field<'c>(
+                    input: Element<'c, ComponentWidgetEvent>,
+                    field: FormField,
+                    error: Option<String>,
                 ) -> Element<'c, ComponentWidgetEvent> {
-                    let before_or_label: Element<_> = match label {
+                    let before_or_label: Element<_> = match field.label {
                         None => Space::with_width(Length::FillPortion(2)).into(),
                         Some(label) => {
-                            let label: Element<_> = text(label.to_string())
+                            let label = if field.required {
+                                format!("{} *", label)
+                            } else {
+                                label.to_string()
+                            };
+
+                            let label: Element<_> = text(label)
                                 .shaping(Shaping::Advanced)
                                 .align_x(Horizontal::Right)
                                 .width(Length::Fill)
@@ -178,7 +519,29 @@ impl<'b> ComponentWidgets<'b> {
                         }
                     };
 
-                    let form_input = container(field).width(Length::FillPortion(3)).into();
+                    // error takes place of info, there is no reason to show both
+                    let input = match (error, field.info) {
+                        (Some(error), _) => {
+                            let error: Element<_> =
+                                text(error).shaping(Shaping::Advanced).themed(TextStyle::FormInputError);
+
+                            let error: Element<_> = container(error).themed(ContainerStyle::FormInputError);
+
+                            column([input, error]).into()
+                        }
+                        (None, Some(info)) => {
+                            let info: Element<_> = text(info.to_string())
+                                .shaping(Shaping::Advanced)
+                                .themed(TextStyle::FormInputInfo);
+
+                            let info: Element<_> = container(info).themed(ContainerStyle::FormInputInfo);
+
+                            column([input, info]).into()
+                        }
+                        (None, None) => input,
+                    };
+
+                    let form_input = container(input).width(Length::FillPortion(3)).into();
 
                     let after = Space::with_width(Length::FillPortion(2)).into();
 
@@ -189,24 +552,24 @@ impl<'b> ComponentWidgets<'b> {
                     row
                 }
 
-                match members {
-                    FormWidgetOrderedMembers::Separator(widget) => self.render_separator_widget(widget),
-                    FormWidgetOrderedMembers::TextField(widget) => {
-                        render_field(self.render_text_field_widget(widget), &widget.label)
-                    }
-                    FormWidgetOrderedMembers::PasswordField(widget) => {
-                        render_field(self.render_password_field_widget(widget), &widget.label)
-                    }
-                    FormWidgetOrderedMembers::Checkbox(widget) => {
-                        render_field(self.render_checkbox_widget(widget), &widget.label)
-                    }
-                    FormWidgetOrderedMembers::DatePicker(widget) => {
-                        render_field(self.render_date_picker_widget(widget), &widget.label)
-                    }
-                    FormWidgetOrderedMembers::Select(widget) => {
-                        render_field(self.render_select_widget(widget), &widget.label)
-                    }
-                }
+                let input = match members {
+                    FormWidgetOrderedMembers::Separator(widget) => return self.render_separator_widget(widget),
+                    FormWidgetOrderedMembers::TextField(widget) => self.render_text_field_widget(widget),
+                    FormWidgetOrderedMembers::PasswordField(widget) => self.render_password_field_widget(widget),
+                    FormWidgetOrderedMembers::TextArea(widget) => self.render_text_area_widget(widget),
+                    FormWidgetOrderedMembers::NumberField(widget) => self.render_number_field_widget(widget),
+                    FormWidgetOrderedMembers::Checkbox(widget) => self.render_checkbox_widget(widget),
+                    FormWidgetOrderedMembers::DatePicker(widget) => self.render_date_picker_widget(widget),
+                    FormWidgetOrderedMembers::Select(widget) => self.render_select_widget(widget),
+                    FormWidgetOrderedMembers::TagPicker(widget) => self.render_tag_picker_widget(widget),
+                    FormWidgetOrderedMembers::FilePicker(widget) => self.render_file_picker_widget(widget),
+                };
+
+                let field = form_field(members).expect("every form member except separator should be a field");
+
+                let error = self.form_field_error(members, *submit_attempted);
+
+                render_field(input, field, error)
             })
             .collect();
 
@@ -223,20 +586,60 @@ impl<'b> ComponentWidgets<'b> {
             widget_id,
             None,
             &None,
+            &None,
             &widget.content.actions,
             content,
             widget.is_loading.unwrap_or(false),
             plugin_view_state,
             entrypoint_name,
             action_shortcuts,
+            keybindings,
         )
     }
 }
 
+struct FormField<'c> {
+    id: &'c Option<String>,
+    label: &'c Option<String>,
+    required: bool,
+    error: &'c Option<String>,
+    info: &'c Option<String>,
+}
+
+fn form_field(members: &FormWidgetOrderedMembers) -> Option<FormField<'_>> {
+    // every form input widget has the same common properties, but widget types are generated separately
+    macro_rules! form_field {
+        ($widget:expr) => {
+            FormField {
+                id: &$widget.id,
+                label: &$widget.label,
+                required: $widget.required.unwrap_or(false),
+                error: &$widget.error,
+                info: &$widget.info,
+            }
+        };
+    }
+
+    let field = match members {
+        FormWidgetOrderedMembers::Separator(_) => return None,
+        FormWidgetOrderedMembers::TextField(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::PasswordField(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::TextArea(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::NumberField(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::Checkbox(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::DatePicker(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::Select(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::TagPicker(widget) => form_field!(widget),
+        FormWidgetOrderedMembers::FilePicker(widget) => form_field!(widget),
+    };
+
+    Some(field)
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
-struct SelectItem {
-    value: String,
-    label: String,
+pub struct SelectItem {
+    pub value: String,
+    pub label: String,
 }
 
 impl Display for SelectItem {
@@ -244,3 +647,108 @@ impl Display for SelectItem {
         write!(f, "{}", self.label)
     }
 }
+
+fn tag_picker_items(widget: &TagPickerWidget) -> Vec<SelectItem> {
+    widget
+        .content
+        .ordered_members
+        .iter()
+        .map(|members| {
+            match members {
+                TagPickerWidgetOrderedMembers::TagPickerItem(widget) => {
+                    SelectItem {
+                        value: widget.value.to_owned(),
+                        label: widget.content.text.join(""),
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+fn render_file_browser<'a>(
+    widget_id: UiWidgetId,
+    browser: &FileBrowserState,
+    directory: bool,
+) -> Element<'a, ComponentWidgetEvent> {
+    let current_directory = browser.directory.to_string_lossy().to_string();
+
+    let mut entries: Vec<Element<_>> = vec![];
+
+    if let Some(parent) = browser.directory.parent() {
+        entries.push(render_file_browser_entry(
+            "..".to_string(),
+            ComponentWidgetEvent::NavigateFilePicker {
+                widget_id,
+                path: parent.to_path_buf(),
+            },
+        ));
+    }
+
+    for entry in &browser.entries {
+        let element = if entry.is_dir {
+            render_file_browser_entry(
+                format!("{}/", entry.name),
+                ComponentWidgetEvent::NavigateFilePicker {
+                    widget_id,
+                    path: entry.path.clone(),
+                },
+            )
+        } else if directory {
+            // files can't be selected when picking a directory
+            continue;
+        } else {
+            render_file_browser_entry(
+                entry.name.clone(),
+                ComponentWidgetEvent::SubmitFilePicker {
+                    widget_id,
+                    value: entry.path.to_string_lossy().to_string(),
+                },
+            )
+        };
+
+        entries.push(element);
+    }
+
+    if let Some(error) = &browser.error {
+        let error: Element<_> = text(error.to_string())
+            .shaping(Shaping::Advanced)
+            .themed(TextStyle::FormInputError);
+
+        entries.push(container(error).themed(ContainerStyle::FormInputError));
+    }
+
+    let entries: Element<_> = scrollable(column(entries))
+        .height(Length::Fixed(200.0))
+        .width(Length::Fill)
+        .into();
+
+    let current_directory: Element<_> = text(current_directory)
+        .shaping(Shaping::Advanced)
+        .width(Length::Fill)
+        .into();
+
+    let header: Element<_> = if directory {
+        let select: Element<_> = button(text("Select"))
+            .on_press(ComponentWidgetEvent::SubmitFilePicker {
+                widget_id,
+                value: browser.directory.to_string_lossy().to_string(),
+            })
+            .themed(ButtonStyle::FormInputButton);
+
+        row([current_directory, select]).align_y(Alignment::Center).into()
+    } else {
+        current_directory
+    };
+
+    column([header, entries]).into()
+}
+
+fn render_file_browser_entry<'a>(name: String, on_press: ComponentWidgetEvent) -> Element<'a, ComponentWidgetEvent> {
+    let content: Element<_> = text(name).shaping(Shaping::Advanced).into();
+
+    button(content)
+        .width(Length::Fill)
+        .on_press(on_press)
+        .themed(ButtonStyle::FormInputFileBrowserEntry)
+}
@@ -6,8 +6,10 @@ use gauntlet_common::model::GridSectionWidget;
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidget;
 use gauntlet_common::model::GridWidgetOrderedMembers;
+use gauntlet_common::model::LauncherAction;
 use gauntlet_common::model::PhysicalShortcut;
 use iced::advanced::text::Shaping;
+use iced::alignment::Horizontal;
 use iced::alignment::Vertical;
 use iced::widget::button;
 use iced::widget::column;
@@ -22,6 +24,7 @@ use iced_aw::grid_row;
 use iced_aw::GridRow;
 use itertools::Itertools;
 
+use crate::ui::scroll_handle::is_viewport_near_end;
 use crate::ui::state::PluginViewState;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
@@ -42,13 +45,24 @@ impl<'b> ComponentWidgets<'b> {
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
+        keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
         let RootState {
             show_action_panel,
             focused_item,
+            ..
         } = self.root_state(grid_widget.__id__);
 
-        let content = if grid_widget.content.ordered_members.is_empty() {
+        let grid_widget_id = grid_widget.__id__;
+        let filter = self.grid_item_filter(grid_widget);
+        let item_amount = ComponentWidgets::grid_section_sizes(grid_widget, &filter)
+            .iter()
+            .map(|data| data.amount_in_section)
+            .sum::<usize>();
+        let item_height = focused_item.item_height();
+        let is_loading = grid_widget.is_loading.unwrap_or(false);
+
+        let content = if grid_widget.content.ordered_members.is_empty() || (filter.is_active() && item_amount == 0) {
             match &grid_widget.content.empty_view {
                 Some(widget) => self.render_empty_view_widget(widget),
                 None => horizontal_space().into(),
@@ -62,6 +76,10 @@ impl<'b> ComponentWidgets<'b> {
             for members in &grid_widget.content.ordered_members {
                 match &members {
                     GridWidgetOrderedMembers::GridItem(widget) => {
+                        if !filter.matches(widget.title.as_deref(), &widget.keywords) {
+                            continue;
+                        }
+
                         first_section = false;
                         pending.push(widget)
                     }
@@ -75,8 +93,26 @@ impl<'b> ComponentWidgets<'b> {
                             pending = vec![];
                         }
 
+                        let section_items: Vec<_> = widget
+                            .content
+                            .ordered_members
+                            .iter()
+                            .map(|members| {
+                                match members {
+                                    GridSectionWidgetOrderedMembers::GridItem(widget) => widget,
+                                }
+                            })
+                            .filter(|widget| filter.matches(widget.title.as_deref(), &widget.keywords))
+                            .collect();
+
+                        // sections without matching items are not shown, same as in grid_section_sizes
+                        if section_items.is_empty() && filter.is_active() {
+                            continue;
+                        }
+
                         items.push(self.render_grid_section_widget(
                             widget,
+                            &section_items,
                             focused_item.index,
                             index_counter,
                             first_section,
@@ -93,12 +129,25 @@ impl<'b> ComponentWidgets<'b> {
                 items.push(content);
             }
 
+            if is_loading {
+                items.push(render_loading_footer());
+            }
+
             let content: Element<_> = column(items).into();
 
             let content: Element<_> = container(content).width(Length::Fill).themed(ContainerStyle::GridInner);
 
             let content: Element<_> = scrollable(content)
                 .id(focused_item.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    let load_more = !is_loading && is_viewport_near_end(&viewport, item_height);
+
+                    ComponentWidgetEvent::ScrollGrid {
+                        grid_widget_id,
+                        position: viewport.absolute_offset().y,
+                        load_more_at: load_more.then_some(item_amount),
+                    }
+                })
                 .width(Length::Fill)
                 .into();
 
@@ -107,41 +156,33 @@ impl<'b> ComponentWidgets<'b> {
             content
         };
 
-        let focused_item_id = ComponentWidgets::grid_focused_item_id(focused_item, grid_widget);
+        let focused_item_id = ComponentWidgets::grid_focused_item_id(focused_item, grid_widget, &filter);
 
         self.render_plugin_root(
             *show_action_panel,
-            grid_widget.__id__,
+            grid_widget_id,
             focused_item_id,
             &grid_widget.content.search_bar,
+            &grid_widget.content.search_bar_accessory,
             &grid_widget.content.actions,
             content,
-            grid_widget.is_loading.unwrap_or(false),
+            is_loading,
             plugin_view_state,
             entrypoint_name,
             action_shortcuts,
+            keybindings,
         )
     }
 
     fn render_grid_section_widget<'a>(
         &self,
         widget: &GridSectionWidget,
+        items: &[&GridItemWidget],
         item_focus_index: Option<usize>,
         index_counter: &Cell<usize>,
         first_section: bool,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let items: Vec<_> = widget
-            .content
-            .ordered_members
-            .iter()
-            .map(|members| {
-                match members {
-                    GridSectionWidgetOrderedMembers::GridItem(widget) => widget,
-                }
-            })
-            .collect();
-
-        let content = self.render_grid(&items, &widget.columns, item_focus_index, index_counter);
+        let content = self.render_grid(items, &widget.columns, item_focus_index, index_counter);
 
         let section_title_style = if first_section {
             RowStyle::GridFirstSectionTitle
@@ -193,7 +234,7 @@ impl<'b> ComponentWidgets<'b> {
 
         index_counter.set(index_counter.get() + 1);
 
-        let action_ids = self.get_action_ids();
+        let action_ids = self.get_plugin_action_ids();
         let primary_action = action_ids.first();
 
         let on_press_msg = match primary_action {
@@ -266,6 +307,7 @@ impl<'b> ComponentWidgets<'b> {
 
         let grid_width = grid_width(columns);
 
+        // unlike list, grid is always laid out fully because row height depends on titles and subtitles of its items
         let rows: Vec<GridRow<_, _, _>> = items
             .iter()
             .map(|widget| self.render_grid_item_widget(widget, item_focus_index, index_counter, grid_width))
@@ -292,6 +334,15 @@ pub fn grid_width(columns: &Option<f64>) -> usize {
     columns.map(|value| value.trunc() as usize).unwrap_or(5)
 }
 
+pub fn render_loading_footer<'a>() -> Element<'a, ComponentWidgetEvent> {
+    let footer: Element<_> = text("Loading...").themed(TextStyle::LoadingFooter);
+
+    container(footer)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .themed(ContainerStyle::LoadingFooter)
+}
+
 pub fn render_section<'a>(
     content: Element<'a, ComponentWidgetEvent>,
     title: Option<&str>,
@@ -0,0 +1,152 @@
+use once_cell::sync::Lazy;
+use syntect::parsing::ParseState;
+use syntect::parsing::ScopeStack;
+use syntect::parsing::SyntaxReference;
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+use crate::ui::theme::text::HighlightKind;
+
+static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+
+pub type HighlightedLine = Vec<(HighlightKind, String)>;
+
+pub fn highlight_code(code: &str, language: Option<&str>) -> Vec<HighlightedLine> {
+    let syntax = language.and_then(|language| SYNTAX_SET.find_syntax_by_token(language));
+
+    match syntax {
+        Some(syntax) => {
+            highlight_lines(code, syntax).unwrap_or_else(|err| {
+                tracing::warn!("unable to highlight code block: {:?}", err);
+
+                plain_lines(code)
+            })
+        }
+        None => plain_lines(code),
+    }
+}
+
+fn highlight_lines(code: &str, syntax: &SyntaxReference) -> anyhow::Result<Vec<HighlightedLine>> {
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+
+    let mut lines = vec![];
+
+    for line in LinesWithEndings::from(code) {
+        let ops = state.parse_line(line, &SYNTAX_SET)?;
+
+        let mut tokens = vec![];
+        let mut position = 0;
+
+        for (index, op) in ops {
+            if index > position {
+                push_token(&mut tokens, stack_kind(&stack), &line[position..index]);
+                position = index;
+            }
+
+            stack.apply(&op)?;
+        }
+
+        push_token(&mut tokens, stack_kind(&stack), &line[position..]);
+
+        lines.push(tokens);
+    }
+
+    Ok(lines)
+}
+
+fn plain_lines(code: &str) -> Vec<HighlightedLine> {
+    code.lines()
+        .map(|line| vec![(HighlightKind::Plain, line.to_string())])
+        .collect()
+}
+
+fn push_token(tokens: &mut HighlightedLine, kind: HighlightKind, value: &str) {
+    let value = value.trim_end_matches(['\n', '\r']);
+
+    if value.is_empty() {
+        return;
+    }
+
+    match tokens.last_mut() {
+        Some((last_kind, last_value)) if *last_kind == kind => last_value.push_str(value),
+        _ => tokens.push((kind, value.to_string())),
+    }
+}
+
+fn stack_kind(stack: &ScopeStack) -> HighlightKind {
+    stack
+        .as_slice()
+        .iter()
+        .rev()
+        .find_map(|scope| scope_kind(&scope.build_string()))
+        .unwrap_or(HighlightKind::Plain)
+}
+
+fn scope_kind(scope: &str) -> Option<HighlightKind> {
+    let matches = |prefix: &str| scope == prefix || scope.starts_with(&format!("{}.", prefix));
+
+    if matches("comment") {
+        Some(HighlightKind::Comment)
+    } else if matches("string") {
+        Some(HighlightKind::String)
+    } else if matches("constant.numeric") {
+        Some(HighlightKind::Number)
+    } else if matches("constant") {
+        Some(HighlightKind::Constant)
+    } else if matches("entity.name.function") || matches("support.function") || matches("variable.function") {
+        Some(HighlightKind::Function)
+    } else if matches("entity.name") || matches("support.type") || matches("support.class") {
+        Some(HighlightKind::Type)
+    } else if matches("keyword") || matches("storage") {
+        Some(HighlightKind::Keyword)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_to_kind() {
+        assert_eq!(
+            scope_kind("comment.line.double-slash.rust"),
+            Some(HighlightKind::Comment)
+        );
+        assert_eq!(
+            scope_kind("constant.numeric.integer.decimal.rust"),
+            Some(HighlightKind::Number)
+        );
+        assert_eq!(scope_kind("constant.language.rust"), Some(HighlightKind::Constant));
+        assert_eq!(scope_kind("entity.name.function.rust"), Some(HighlightKind::Function));
+        assert_eq!(scope_kind("entity.name.struct.rust"), Some(HighlightKind::Type));
+        assert_eq!(scope_kind("storage.type.function.rust"), Some(HighlightKind::Keyword));
+        assert_eq!(scope_kind("stringy"), None);
+        assert_eq!(scope_kind("source.rust"), None);
+    }
+
+    #[test]
+    fn highlight_rust() {
+        let lines = highlight_code("// hello\nlet x = 42;\n", Some("rust"));
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], vec![(HighlightKind::Comment, "// hello".to_string())]);
+        assert!(lines[1].contains(&(HighlightKind::Keyword, "let".to_string())));
+        assert!(lines[1].contains(&(HighlightKind::Number, "42".to_string())));
+    }
+
+    #[test]
+    fn unknown_language_is_plain() {
+        let lines = highlight_code("first\nsecond", Some("not-a-language"));
+
+        assert_eq!(
+            lines,
+            vec![
+                vec![(HighlightKind::Plain, "first".to_string())],
+                vec![(HighlightKind::Plain, "second".to_string())],
+            ]
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use std::cell::Cell;
 use std::collections::HashMap;
+use std::ops::Range;
 
+use gauntlet_common::model::LauncherAction;
 use gauntlet_common::model::ListItemAccessories;
 use gauntlet_common::model::ListItemWidget;
 use gauntlet_common::model::ListSectionWidget;
@@ -17,9 +19,11 @@ use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::text;
 use iced::widget::vertical_rule;
+use iced::widget::vertical_space;
 use iced::Alignment;
 use iced::Length;
 
+use crate::ui::scroll_handle::is_viewport_near_end;
 use crate::ui::state::PluginViewState;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
@@ -31,10 +35,15 @@ use crate::ui::widget::accessories::render_icon_accessory;
 use crate::ui::widget::accessories::render_text_accessory;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::grid::render_loading_footer;
 use crate::ui::widget::grid::render_section;
 use crate::ui::widget::images::render_image;
 use crate::ui::widget::state::RootState;
 
+// lists shorter than this are always laid out fully
+const VIRTUALIZATION_MIN_ITEM_AMOUNT: usize = 100;
+const VIRTUALIZATION_OVERSCAN_ROWS: usize = 20;
+
 impl<'b> ComponentWidgets<'b> {
     pub fn render_list_widget<'a>(
         &self,
@@ -42,13 +51,29 @@ impl<'b> ComponentWidgets<'b> {
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
+        keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = list_widget.__id__;
         let RootState {
             show_action_panel,
+            show_detail,
             focused_item,
+            ..
         } = self.root_state(widget_id);
 
+        let filter = self.list_item_filter(list_widget);
+        let item_amount = ComponentWidgets::list_items(list_widget, &filter).len();
+        let section_title_rows = ComponentWidgets::list_section_title_rows(list_widget, &filter);
+        let item_height = focused_item.item_height();
+        let is_loading = list_widget.is_loading.unwrap_or(false);
+
+        // items far away from viewport are replaced with empty space of the same height
+        let visible_rows = if item_amount > VIRTUALIZATION_MIN_ITEM_AMOUNT {
+            Some(focused_item.visible_rows(item_amount, VIRTUALIZATION_OVERSCAN_ROWS))
+        } else {
+            None
+        };
+
         let mut pending: Vec<&ListItemWidget> = vec![];
         let mut items: Vec<Element<_>> = vec![];
         let index_counter = &Cell::new(0);
@@ -57,17 +82,39 @@ impl<'b> ComponentWidgets<'b> {
         for members in &list_widget.content.ordered_members {
             match &members {
                 ListWidgetOrderedMembers::ListItem(widget) => {
+                    if !filter.matches(Some(&widget.title), &widget.keywords) {
+                        continue;
+                    }
+
                     first_section = false;
                     pending.push(widget)
                 }
                 ListWidgetOrderedMembers::ListSection(widget) => {
-                    if !pending.is_empty() {
-                        let content: Vec<_> = pending
-                            .iter()
-                            .map(|widget| self.render_list_item_widget(widget, focused_item.index, index_counter))
-                            .collect();
+                    let section_items: Vec<_> = widget
+                        .content
+                        .ordered_members
+                        .iter()
+                        .map(|members| {
+                            match members {
+                                ListSectionWidgetOrderedMembers::ListItem(widget) => widget,
+                            }
+                        })
+                        .filter(|widget| filter.matches(Some(&widget.title), &widget.keywords))
+                        .collect();
+
+                    // sections without matching items are not shown
+                    if section_items.is_empty() && filter.is_active() {
+                        continue;
+                    }
 
-                        let content: Element<_> = column(content).into();
+                    if !pending.is_empty() {
+                        let content = self.render_list_items(
+                            &pending,
+                            focused_item.index,
+                            index_counter,
+                            &visible_rows,
+                            item_height,
+                        );
 
                         items.push(content);
 
@@ -76,9 +123,12 @@ impl<'b> ComponentWidgets<'b> {
 
                     items.push(self.render_list_section_widget(
                         widget,
+                        &section_items,
                         focused_item.index,
                         index_counter,
                         first_section,
+                        &visible_rows,
+                        item_height,
                     ));
 
                     first_section = false;
@@ -87,16 +137,16 @@ impl<'b> ComponentWidgets<'b> {
         }
 
         if !pending.is_empty() {
-            let content: Vec<_> = pending
-                .iter()
-                .map(|widget| self.render_list_item_widget(widget, focused_item.index, index_counter))
-                .collect();
-
-            let content: Element<_> = column(content).into();
+            let content =
+                self.render_list_items(&pending, focused_item.index, index_counter, &visible_rows, item_height);
 
             items.push(content);
         }
 
+        if !items.is_empty() && is_loading {
+            items.push(render_loading_footer());
+        }
+
         let content = if items.is_empty() {
             match &list_widget.content.empty_view {
                 Some(widget) => self.render_empty_view_widget(widget),
@@ -109,6 +159,17 @@ impl<'b> ComponentWidgets<'b> {
 
             let content: Element<_> = scrollable(content)
                 .id(focused_item.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    let load_more = !is_loading && is_viewport_near_end(&viewport, item_height);
+
+                    // section titles are passed along, so that scroll position can be converted to rows
+                    ComponentWidgetEvent::ScrollList {
+                        list_widget_id: widget_id,
+                        position: viewport.absolute_offset().y,
+                        section_title_rows: section_title_rows.clone(),
+                        load_more_at: load_more.then_some(item_amount),
+                    }
+                })
                 .width(Length::Fill)
                 .into();
 
@@ -121,7 +182,12 @@ impl<'b> ComponentWidgets<'b> {
 
         let mut elements = vec![content];
 
-        if let Some(detail) = &list_widget.content.detail {
+        // detail of focused item takes precedence over detail of the whole list
+        let detail = ComponentWidgets::list_focused_item(focused_item, list_widget, &filter)
+            .and_then(|item| item.content.detail.as_ref())
+            .or(list_widget.content.detail.as_ref());
+
+        if let Some(detail) = detail.filter(|_| *show_detail) {
             let detail = self.render_detail_widget(detail, true);
 
             let detail: Element<_> = container(detail).width(Length::FillPortion(5)).into();
@@ -135,43 +201,35 @@ impl<'b> ComponentWidgets<'b> {
 
         let content: Element<_> = row(elements).height(Length::Fill).into();
 
-        let focused_item_id = ComponentWidgets::list_focused_item_id(focused_item, list_widget);
+        let focused_item_id = ComponentWidgets::list_focused_item_id(focused_item, list_widget, &filter);
 
         self.render_plugin_root(
             *show_action_panel,
             widget_id,
             focused_item_id,
             &list_widget.content.search_bar,
+            &list_widget.content.search_bar_accessory,
             &list_widget.content.actions,
             content,
-            list_widget.is_loading.unwrap_or(false),
+            is_loading,
             plugin_view_state,
             entrypoint_name,
             action_shortcuts,
+            keybindings,
         )
     }
 
     fn render_list_section_widget<'a>(
         &self,
         widget: &ListSectionWidget,
+        items: &[&ListItemWidget],
         item_focus_index: Option<usize>,
         index_counter: &Cell<usize>,
         first_section: bool,
+        visible_rows: &Option<Range<usize>>,
+        item_height: f32,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let content: Vec<_> = widget
-            .content
-            .ordered_members
-            .iter()
-            .map(|members| {
-                match members {
-                    ListSectionWidgetOrderedMembers::ListItem(widget) => {
-                        self.render_list_item_widget(widget, item_focus_index, index_counter)
-                    }
-                }
-            })
-            .collect();
-
-        let content = column(content).into();
+        let content = self.render_list_items(items, item_focus_index, index_counter, visible_rows, item_height);
 
         let section_title_style = if first_section {
             RowStyle::ListFirstSectionTitle
@@ -189,6 +247,62 @@ impl<'b> ComponentWidgets<'b> {
         )
     }
 
+    fn render_list_items<'a>(
+        &self,
+        widgets: &[&ListItemWidget],
+        item_focus_index: Option<usize>,
+        index_counter: &Cell<usize>,
+        visible_rows: &Option<Range<usize>>,
+        item_height: f32,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let mut content: Vec<Element<_>> = vec![];
+        let mut skipped = 0;
+
+        for widget in widgets {
+            let visible = match visible_rows {
+                None => true,
+                Some(visible_rows) => visible_rows.contains(&index_counter.get()),
+            };
+
+            if !visible {
+                index_counter.set(index_counter.get() + 1);
+
+                skipped += 1;
+
+                continue;
+            }
+
+            if skipped > 0 {
+                content.push(vertical_space().height(skipped as f32 * item_height).into());
+
+                skipped = 0;
+            }
+
+            let item = self.render_list_item_widget(widget, item_focus_index, index_counter);
+
+            // virtualized rows have fixed height, so that empty space in place of skipped rows
+            // and scroll position to row conversion match actual layout
+            let item = match visible_rows {
+                None => item,
+                Some(_) => {
+                    container(item)
+                        .height(item_height)
+                        .align_y(Alignment::Center)
+                        .clip(true)
+                        .into()
+                }
+            };
+
+            content.push(item);
+        }
+
+        if skipped > 0 {
+            content.push(vertical_space().height(skipped as f32 * item_height).into());
+        }
+
+        column(content).into()
+    }
+
     fn render_list_item_widget<'a>(
         &self,
         widget: &ListItemWidget,
@@ -256,7 +370,7 @@ impl<'b> ComponentWidgets<'b> {
 
         index_counter.set(index_counter.get() + 1);
 
-        let action_ids = self.get_action_ids();
+        let action_ids = self.get_plugin_action_ids();
         let primary_action = action_ids.first();
 
         let on_press_msg = match primary_action {
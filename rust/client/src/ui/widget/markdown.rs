@@ -0,0 +1,373 @@
+use pulldown_cmark::CodeBlockKind;
+use pulldown_cmark::Event;
+use pulldown_cmark::HeadingLevel;
+use pulldown_cmark::Options;
+use pulldown_cmark::Parser;
+use pulldown_cmark::Tag;
+use pulldown_cmark::TagEnd;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownBlock {
+    Heading {
+        level: HeadingLevel,
+        spans: Vec<MarkdownSpan>,
+    },
+    Paragraph {
+        spans: Vec<MarkdownSpan>,
+    },
+    CodeBlock {
+        language: Option<String>,
+        text: String,
+    },
+    HorizontalBreak,
+    Quote {
+        blocks: Vec<MarkdownBlock>,
+    },
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<MarkdownBlock>>,
+    },
+    Table {
+        head: Vec<Vec<MarkdownSpan>>,
+        rows: Vec<Vec<Vec<MarkdownSpan>>>,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarkdownSpan {
+    pub text: String,
+    pub strong: bool,
+    pub emphasis: bool,
+    pub strikethrough: bool,
+    pub code: bool,
+    pub link: Option<String>,
+}
+
+enum Frame {
+    Quote(Vec<MarkdownBlock>),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<MarkdownBlock>>,
+    },
+    Item(Vec<MarkdownBlock>),
+    Table {
+        head: Vec<Vec<MarkdownSpan>>,
+        rows: Vec<Vec<Vec<MarkdownSpan>>>,
+        row: Vec<Vec<MarkdownSpan>>,
+    },
+}
+
+#[derive(Default)]
+struct MarkdownParser {
+    blocks: Vec<MarkdownBlock>,
+    frames: Vec<Frame>,
+    spans: Vec<MarkdownSpan>,
+    code_block: Option<(Option<String>, String)>,
+    strong: usize,
+    emphasis: usize,
+    strikethrough: usize,
+    link: Option<String>,
+}
+
+pub fn parse_markdown(source: &str) -> Vec<MarkdownBlock> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+    let mut parser = MarkdownParser::default();
+
+    for event in Parser::new_ext(source, options) {
+        parser.event(event);
+    }
+
+    parser.flush_paragraph();
+
+    parser.blocks
+}
+
+impl MarkdownParser {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                match &mut self.code_block {
+                    Some((_, code_block)) => code_block.push_str(&text),
+                    None => self.push_span(text.to_string(), false),
+                }
+            }
+            Event::Code(text) => self.push_span(text.to_string(), true),
+            Event::Html(text) | Event::InlineHtml(text) => self.push_span(text.to_string(), false),
+            Event::SoftBreak => self.push_span(" ".to_string(), false),
+            Event::HardBreak => self.push_span("\n".to_string(), false),
+            Event::Rule => {
+                self.flush_paragraph();
+                self.push_block(MarkdownBlock::HorizontalBreak)
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = if checked { "☑ " } else { "☐ " };
+
+                self.push_span(marker.to_string(), false)
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph | Tag::Heading { .. } => self.flush_paragraph(),
+            Tag::CodeBlock(kind) => {
+                self.flush_paragraph();
+
+                // info string can contain more than just a language, e.g. "rust ignore"
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().map(|language| language.to_string()),
+                    CodeBlockKind::Indented => None,
+                };
+
+                self.code_block = Some((language, String::new()));
+            }
+            Tag::BlockQuote { .. } => {
+                self.flush_paragraph();
+                self.frames.push(Frame::Quote(vec![]));
+            }
+            Tag::List(start) => {
+                self.flush_paragraph();
+                self.frames.push(Frame::List { start, items: vec![] });
+            }
+            Tag::Item => self.frames.push(Frame::Item(vec![])),
+            Tag::Table(_) => {
+                self.flush_paragraph();
+                self.frames.push(Frame::Table {
+                    head: vec![],
+                    rows: vec![],
+                    row: vec![],
+                });
+            }
+            Tag::Emphasis => self.emphasis += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            // images are not fetched, alt text is shown as a link to the image instead
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => self.link = Some(dest_url.to_string()),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.flush_paragraph(),
+            TagEnd::Heading(level) => {
+                let spans = std::mem::take(&mut self.spans);
+
+                self.push_block(MarkdownBlock::Heading { level, spans })
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, text)) = self.code_block.take() {
+                    let text = text.trim_end_matches('\n').to_string();
+
+                    self.push_block(MarkdownBlock::CodeBlock { language, text })
+                }
+            }
+            TagEnd::BlockQuote { .. } => {
+                self.flush_paragraph();
+
+                if let Some(Frame::Quote(blocks)) = self.frames.pop() {
+                    self.push_block(MarkdownBlock::Quote { blocks })
+                }
+            }
+            TagEnd::List(_) => {
+                if let Some(Frame::List { start, items }) = self.frames.pop() {
+                    self.push_block(MarkdownBlock::List { start, items })
+                }
+            }
+            TagEnd::Item => {
+                // items of tight lists contain text directly without wrapping paragraph
+                self.flush_paragraph();
+
+                if let Some(Frame::Item(blocks)) = self.frames.pop() {
+                    if let Some(Frame::List { items, .. }) = self.frames.last_mut() {
+                        items.push(blocks)
+                    }
+                }
+            }
+            TagEnd::TableCell => {
+                let spans = std::mem::take(&mut self.spans);
+
+                if let Some(Frame::Table { row, .. }) = self.frames.last_mut() {
+                    row.push(spans)
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(Frame::Table { head, row, .. }) = self.frames.last_mut() {
+                    *head = std::mem::take(row)
+                }
+            }
+            TagEnd::TableRow => {
+                if let Some(Frame::Table { rows, row, .. }) = self.frames.last_mut() {
+                    rows.push(std::mem::take(row))
+                }
+            }
+            TagEnd::Table => {
+                if let Some(Frame::Table { head, rows, .. }) = self.frames.pop() {
+                    self.push_block(MarkdownBlock::Table { head, rows })
+                }
+            }
+            TagEnd::Emphasis => self.emphasis = self.emphasis.saturating_sub(1),
+            TagEnd::Strong => self.strong = self.strong.saturating_sub(1),
+            TagEnd::Strikethrough => self.strikethrough = self.strikethrough.saturating_sub(1),
+            TagEnd::Link | TagEnd::Image => self.link = None,
+            _ => {}
+        }
+    }
+
+    fn push_span(&mut self, text: String, code: bool) {
+        self.spans.push(MarkdownSpan {
+            text,
+            strong: self.strong > 0,
+            emphasis: self.emphasis > 0,
+            strikethrough: self.strikethrough > 0,
+            code,
+            link: self.link.clone(),
+        })
+    }
+
+    fn flush_paragraph(&mut self) {
+        if !self.spans.is_empty() {
+            let spans = std::mem::take(&mut self.spans);
+
+            self.push_block(MarkdownBlock::Paragraph { spans })
+        }
+    }
+
+    fn push_block(&mut self, block: MarkdownBlock) {
+        match self.frames.last_mut() {
+            Some(Frame::Quote(blocks)) | Some(Frame::Item(blocks)) => blocks.push(block),
+            Some(Frame::List { .. }) | Some(Frame::Table { .. }) => {}
+            None => self.blocks.push(block),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> MarkdownSpan {
+        MarkdownSpan {
+            text: text.to_string(),
+            ..MarkdownSpan::default()
+        }
+    }
+
+    fn paragraph(spans: Vec<MarkdownSpan>) -> MarkdownBlock {
+        MarkdownBlock::Paragraph { spans }
+    }
+
+    #[test]
+    fn heading_and_paragraph() {
+        let blocks = parse_markdown("# Title\n\nfirst\nsecond");
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Heading {
+                    level: HeadingLevel::H1,
+                    spans: vec![text("Title")],
+                },
+                paragraph(vec![text("first"), text(" "), text("second")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn inline_styles() {
+        let blocks = parse_markdown("**bold** *italic* ~~gone~~ `code` [link](https://example.com)");
+
+        assert_eq!(
+            blocks,
+            vec![paragraph(vec![
+                MarkdownSpan {
+                    strong: true,
+                    ..text("bold")
+                },
+                text(" "),
+                MarkdownSpan {
+                    emphasis: true,
+                    ..text("italic")
+                },
+                text(" "),
+                MarkdownSpan {
+                    strikethrough: true,
+                    ..text("gone")
+                },
+                text(" "),
+                MarkdownSpan {
+                    code: true,
+                    ..text("code")
+                },
+                text(" "),
+                MarkdownSpan {
+                    link: Some("https://example.com".to_string()),
+                    ..text("link")
+                },
+            ])]
+        );
+    }
+
+    #[test]
+    fn code_block() {
+        let blocks = parse_markdown("```rust\nfn main() {}\n```\n\n---");
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::CodeBlock {
+                    language: Some("rust".to_string()),
+                    text: "fn main() {}".to_string(),
+                },
+                MarkdownBlock::HorizontalBreak,
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_lists() {
+        let blocks = parse_markdown("3. one\n4. two\n   - nested\n\n> quote");
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::List {
+                    start: Some(3),
+                    items: vec![
+                        vec![paragraph(vec![text("one")])],
+                        vec![
+                            paragraph(vec![text("two")]),
+                            MarkdownBlock::List {
+                                start: None,
+                                items: vec![vec![paragraph(vec![text("nested")])]],
+                            },
+                        ],
+                    ],
+                },
+                MarkdownBlock::Quote {
+                    blocks: vec![paragraph(vec![text("quote")])],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn table() {
+        let blocks = parse_markdown("| a | b |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |");
+
+        assert_eq!(
+            blocks,
+            vec![MarkdownBlock::Table {
+                head: vec![vec![text("a")], vec![text("b")]],
+                rows: vec![
+                    vec![vec![text("1")], vec![text("2")]],
+                    vec![vec![text("3")], vec![text("4")]],
+                ],
+            }]
+        );
+    }
+}
@@ -6,13 +6,17 @@ pub mod data_mut;
 mod detail;
 mod empty_view;
 pub mod events;
+pub mod file_browser;
 mod form;
 mod grid;
-mod images;
+pub mod highlight;
+pub mod images;
 mod inline;
 mod list;
+mod markdown;
 mod metadata;
 pub mod root;
 mod search_bar;
 pub mod state;
+mod table;
 mod text;
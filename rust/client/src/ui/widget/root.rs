@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 use gauntlet_common::model::ActionPanelWidget;
-use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::DropdownWidget;
+use gauntlet_common::model::LauncherAction;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::RootWidgetMembers;
 use gauntlet_common::model::SearchBarWidget;
@@ -25,6 +26,7 @@ use iced_fonts::Bootstrap;
 use iced_fonts::BOOTSTRAP_FONT;
 
 use crate::ui::custom_widgets::loading_bar::LoadingBar;
+use crate::ui::keymap::launcher_action_shortcut;
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::state::PluginViewState;
 use crate::ui::theme::button::ButtonStyle;
@@ -38,6 +40,7 @@ use crate::ui::widget::action_panel::convert_action_panel;
 use crate::ui::widget::action_panel::render_action_panel;
 use crate::ui::widget::action_panel::render_shortcut;
 use crate::ui::widget::action_panel::ActionPanel;
+use crate::ui::widget::action_panel::ActionPanelItem;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
 use crate::ui::widget::state::RootState;
@@ -48,7 +51,11 @@ impl<'b> ComponentWidgets<'b> {
         plugin_view_state: &PluginViewState,
         entrypoint_name: Option<&String>,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
-    ) -> Element<'a, ComponentWidgetEvent> {
+        keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
+    ) -> Element<'a, ComponentWidgetEvent>
+    where
+        'b: 'a,
+    {
         match &self.root_widget {
             None => horizontal_space().into(),
             Some(root) => {
@@ -69,22 +76,51 @@ impl<'b> ComponentWidgets<'b> {
                                     widget.__id__,
                                     None,
                                     &None,
+                                    &None,
                                     &widget.content.actions,
                                     content,
                                     widget.is_loading.unwrap_or(false),
                                     plugin_view_state,
                                     entrypoint_name,
                                     action_shortcuts,
+                                    keybindings,
                                 )
                             }
                             RootWidgetMembers::Form(widget) => {
-                                self.render_form_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts)
+                                self.render_form_widget(
+                                    widget,
+                                    plugin_view_state,
+                                    entrypoint_name,
+                                    action_shortcuts,
+                                    keybindings,
+                                )
                             }
                             RootWidgetMembers::List(widget) => {
-                                self.render_list_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts)
+                                self.render_list_widget(
+                                    widget,
+                                    plugin_view_state,
+                                    entrypoint_name,
+                                    action_shortcuts,
+                                    keybindings,
+                                )
                             }
                             RootWidgetMembers::Grid(widget) => {
-                                self.render_grid_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts)
+                                self.render_grid_widget(
+                                    widget,
+                                    plugin_view_state,
+                                    entrypoint_name,
+                                    action_shortcuts,
+                                    keybindings,
+                                )
+                            }
+                            RootWidgetMembers::Table(widget) => {
+                                self.render_table_widget(
+                                    widget,
+                                    plugin_view_state,
+                                    entrypoint_name,
+                                    action_shortcuts,
+                                    keybindings,
+                                )
                             }
                             _ => {
                                 panic!("used inline widget in non-inline place")
@@ -126,7 +162,11 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
-    fn render_top_panel<'a>(&self, search_bar: &Option<SearchBarWidget>) -> Element<'a, ComponentWidgetEvent> {
+    fn render_top_panel<'a>(
+        &self,
+        search_bar: &Option<SearchBarWidget>,
+        search_bar_accessory: &Option<DropdownWidget>,
+    ) -> Element<'a, ComponentWidgetEvent> {
         let icon = value(Bootstrap::ArrowLeft).font(BOOTSTRAP_FONT);
 
         let back_button: Element<_> = button(icon)
@@ -138,9 +178,13 @@ impl<'b> ComponentWidgets<'b> {
             .map(|widget| self.render_search_bar_widget(widget))
             .unwrap_or_else(|| Space::with_width(Length::FillPortion(3)).into());
 
-        let top_panel: Element<_> = row(vec![back_button, search_bar_element])
-            .align_y(Alignment::Center)
-            .themed(RowStyle::RootTopPanel);
+        let mut top_panel = vec![back_button, search_bar_element];
+
+        if let Some(widget) = search_bar_accessory {
+            top_panel.push(self.render_dropdown_widget(widget));
+        }
+
+        let top_panel: Element<_> = row(top_panel).align_y(Alignment::Center).themed(RowStyle::RootTopPanel);
 
         let top_panel: Element<_> = container(top_panel)
             .width(Length::Fill)
@@ -155,14 +199,16 @@ impl<'b> ComponentWidgets<'b> {
         root_widget_id: UiWidgetId,
         focused_item_id: Option<String>,
         search_bar: &Option<SearchBarWidget>,
+        search_bar_accessory: &Option<DropdownWidget>,
         action_panel: &Option<ActionPanelWidget>,
         content: Element<'a, ComponentWidgetEvent>,
         is_loading: bool,
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
+        keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let top_panel = self.render_top_panel(search_bar);
+        let top_panel = self.render_top_panel(search_bar, search_bar_accessory);
 
         let top_separator = if is_loading {
             LoadingBar::new().into()
@@ -170,24 +216,42 @@ impl<'b> ComponentWidgets<'b> {
             horizontal_rule(1).into()
         };
 
+        let root_content = self.root_widget.as_ref().and_then(|root| root.content.as_ref());
+
+        let is_form = matches!(root_content, Some(RootWidgetMembers::Form(_)));
+
+        let has_detail = match root_content {
+            Some(RootWidgetMembers::List(widget)) => ComponentWidgets::list_has_detail(widget),
+            _ => false,
+        };
+
         let mut action_panel = convert_action_panel(action_panel, &action_shortcuts);
 
-        let primary_action =
-            action_panel
-                .as_mut()
-                .map(|panel| panel.find_first())
-                .flatten()
-                .map(|(label, widget_id)| {
-                    let shortcut = PhysicalShortcut {
-                        physical_key: PhysicalKey::Enter,
-                        modifier_shift: false,
-                        modifier_control: false,
-                        modifier_alt: false,
-                        modifier_meta: false,
-                    };
-
-                    (label.to_string(), widget_id, shortcut)
-                });
+        // root widget id is used to tell launcher-provided actions apart from plugin actions
+        if has_detail {
+            let toggle_detail = ActionPanelItem::Action {
+                label: "Toggle Detail".to_string(),
+                widget_id: root_widget_id,
+                physical_shortcut: Some(launcher_action_shortcut(keybindings, LauncherAction::ToggleDetail)),
+            };
+
+            action_panel = Some(action_panel.unwrap_or_default().with_launcher_action(toggle_detail));
+        }
+
+        let primary_action = match action_panel.as_mut().map(|panel| panel.find_first()).flatten() {
+            Some((label, widget_id)) => {
+                let shortcut = launcher_action_shortcut(keybindings, LauncherAction::RunPrimaryAction);
+
+                Some((label.to_string(), widget_id, shortcut))
+            }
+            None if is_form => {
+                // form without actions still needs a visible way to submit it
+                let shortcut = launcher_action_shortcut(keybindings, LauncherAction::SubmitForm);
+
+                Some(("Submit".to_string(), root_widget_id, shortcut))
+            }
+            None => None,
+        };
 
         match plugin_view_state {
             PluginViewState::None => {
@@ -201,15 +265,20 @@ impl<'b> ComponentWidgets<'b> {
                     action_panel,
                     None::<&ScrollHandle>,
                     entrypoint_name,
+                    keybindings,
                     || {
                         ComponentWidgetEvent::ToggleActionPanel {
                             widget_id: root_widget_id,
                         }
                     },
                     |widget_id| {
-                        ComponentWidgetEvent::RunPrimaryAction {
-                            widget_id,
-                            id: focused_item_id.clone(),
+                        if is_form && widget_id == root_widget_id {
+                            ComponentWidgetEvent::SubmitFormClick { widget_id }
+                        } else {
+                            ComponentWidgetEvent::RunPrimaryAction {
+                                widget_id,
+                                id: focused_item_id.clone(),
+                            }
                         }
                     },
                     |widget_id| {
@@ -232,15 +301,20 @@ impl<'b> ComponentWidgets<'b> {
                     action_panel,
                     Some(&focused_action_item),
                     entrypoint_name,
+                    keybindings,
                     || {
                         ComponentWidgetEvent::ToggleActionPanel {
                             widget_id: root_widget_id,
                         }
                     },
                     |widget_id| {
-                        ComponentWidgetEvent::RunPrimaryAction {
-                            widget_id,
-                            id: focused_item_id.clone(),
+                        if is_form && widget_id == root_widget_id {
+                            ComponentWidgetEvent::SubmitFormClick { widget_id }
+                        } else {
+                            ComponentWidgetEvent::RunPrimaryAction {
+                                widget_id,
+                                id: focused_item_id.clone(),
+                            }
                         }
                     },
                     |widget_id| {
@@ -266,6 +340,7 @@ pub fn render_root<'a, T: 'a + Clone>(
     action_panel: Option<ActionPanel>,
     action_panel_scroll_handle: Option<&ScrollHandle>,
     entrypoint_name: &str,
+    keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
     on_panel_toggle_click: impl Fn() -> T,
     on_panel_primary_click: impl Fn(UiWidgetId) -> T,
     on_action_click: impl Fn(UiWidgetId) -> T,
@@ -305,13 +380,10 @@ pub fn render_root<'a, T: 'a + Clone>(
             let actions_text: Element<_> =
                 container(actions_text).themed(ContainerStyle::RootBottomPanelActionToggleText);
 
-            let shortcut = render_shortcut(&PhysicalShortcut {
-                physical_key: PhysicalKey::KeyK,
-                modifier_shift: false,
-                modifier_control: false,
-                modifier_alt: true,
-                modifier_meta: false,
-            });
+            let shortcut = render_shortcut(&launcher_action_shortcut(
+                keybindings,
+                LauncherAction::ToggleActionPanel,
+            ));
 
             let mut bottom_panel_content = vec![entrypoint_name];
 
@@ -405,7 +477,8 @@ pub fn render_root<'a, T: 'a + Clone>(
 
     if let (Some(action_panel), Some(action_panel_scroll_handle)) = (action_panel, action_panel_scroll_handle) {
         if !hide_action_panel {
-            let action_panel = render_action_panel(action_panel, on_action_click, action_panel_scroll_handle);
+            let action_panel =
+                render_action_panel(action_panel, on_action_click, action_panel_scroll_handle, keybindings);
 
             let action_panel: Element<_> = container(action_panel)
                 .padding(gauntlet_common_ui::padding(0.0, 8.0, 48.0, 0.0))
@@ -1,12 +1,28 @@
+use std::collections::HashMap;
+
+use gauntlet_common::model::DropdownWidget;
+use gauntlet_common::model::DropdownWidgetOrderedMembers;
+use gauntlet_common::model::GridWidget;
+use gauntlet_common::model::ListWidget;
+use gauntlet_common::model::RootWidgetMembers;
 use gauntlet_common::model::SearchBarWidget;
+use gauntlet_common::model::UiRenderLocation;
+use gauntlet_common::model::UiWidgetId;
+use iced::widget::pick_list;
 use iced::widget::text_input;
+use iced::Task;
 
+use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::form::SelectItem;
+use crate::ui::widget::state::ComponentWidgetState;
+use crate::ui::widget::state::SelectState;
 use crate::ui::widget::state::TextFieldState;
+use crate::ui::AppMsg;
 
 impl<'b> ComponentWidgets<'b> {
     pub fn render_search_bar_widget<'a>(&self, widget: &SearchBarWidget) -> Element<'a, ComponentWidgetEvent> {
@@ -22,4 +38,161 @@ impl<'b> ComponentWidgets<'b> {
             .on_input(move |value| ComponentWidgetEvent::OnChangeSearchBar { widget_id, value })
             .themed(TextInputStyle::PluginSearchBar)
     }
+
+    pub fn render_dropdown_widget<'a>(&self, widget: &DropdownWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let SelectState { state_value } = self.select_state(widget_id);
+
+        let items = dropdown_items(widget);
+
+        let state_value = state_value
+            .as_ref()
+            .and_then(|value| items.iter().find(|item| &item.value == value))
+            .cloned();
+
+        pick_list(items, state_value, move |item| {
+            ComponentWidgetEvent::SelectDropdown {
+                widget_id,
+                value: item.value,
+            }
+        })
+        .themed(PickListStyle::Default)
+    }
+
+    // returns None if current view doesn't have search bar accessory
+    pub fn cycle_search_bar_accessory(&self) -> Option<Task<AppMsg>> {
+        let root_widget = self.root_widget.as_ref()?;
+
+        let widget = match root_widget.content.as_ref()? {
+            RootWidgetMembers::List(widget) => widget.content.search_bar_accessory.as_ref()?,
+            RootWidgetMembers::Grid(widget) => widget.content.search_bar_accessory.as_ref()?,
+            _ => return None,
+        };
+
+        let items = dropdown_items(widget);
+
+        if items.is_empty() {
+            return None;
+        }
+
+        let SelectState { state_value } = self.select_state(widget.__id__);
+
+        let next_index = state_value
+            .as_ref()
+            .and_then(|value| items.iter().position(|item| &item.value == value))
+            .map(|index| (index + 1) % items.len())
+            .unwrap_or(0);
+
+        Some(Task::done(AppMsg::WidgetEvent {
+            plugin_id: self.plugin_id.clone(),
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::SelectDropdown {
+                widget_id: widget.__id__,
+                value: items[next_index].value.clone(),
+            },
+        }))
+    }
+
+    pub fn list_item_filter(&self, widget: &ListWidget) -> ItemFilter {
+        ComponentWidgets::item_filter_on_state(self.state, widget.filtering, &widget.content.search_bar)
+    }
+
+    pub fn grid_item_filter(&self, widget: &GridWidget) -> ItemFilter {
+        ComponentWidgets::item_filter_on_state(self.state, widget.filtering, &widget.content.search_bar)
+    }
+
+    pub fn item_filter_on_state(
+        state: &HashMap<UiWidgetId, ComponentWidgetState>,
+        filtering: Option<bool>,
+        search_bar: &Option<SearchBarWidget>,
+    ) -> ItemFilter {
+        if !filtering.unwrap_or(false) {
+            return ItemFilter::none();
+        }
+
+        let Some(search_bar) = search_bar else {
+            return ItemFilter::none();
+        };
+
+        match state.get(&search_bar.__id__) {
+            Some(ComponentWidgetState::TextField(TextFieldState { state_value, .. })) => ItemFilter::new(state_value),
+            _ => ItemFilter::none(),
+        }
+    }
+}
+
+fn dropdown_items(widget: &DropdownWidget) -> Vec<SelectItem> {
+    widget
+        .content
+        .ordered_members
+        .iter()
+        .map(|members| {
+            match members {
+                DropdownWidgetOrderedMembers::DropdownItem(widget) => {
+                    SelectItem {
+                        value: widget.value.to_owned(),
+                        label: widget.content.text.join(""),
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+// built-in filtering of list and grid items by search bar text, item matches if every word of text
+// is found in its title or in one of its keywords
+#[derive(Debug, Clone)]
+pub struct ItemFilter {
+    words: Vec<String>,
+}
+
+impl ItemFilter {
+    pub fn none() -> ItemFilter {
+        ItemFilter { words: vec![] }
+    }
+
+    pub fn new(text: &str) -> ItemFilter {
+        let words = text.split_whitespace().map(|word| word.to_lowercase()).collect();
+
+        ItemFilter { words }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.words.is_empty()
+    }
+
+    pub fn matches(&self, title: Option<&str>, keywords: &Option<Vec<String>>) -> bool {
+        if !self.is_active() {
+            return true;
+        }
+
+        let candidates: Vec<_> = title
+            .into_iter()
+            .chain(keywords.iter().flatten().map(|keyword| keyword.as_str()))
+            .map(|value| value.to_lowercase())
+            .collect();
+
+        self.words
+            .iter()
+            .all(|word| candidates.iter().any(|candidate| candidate.contains(word)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_by_title_and_keywords() {
+        let keywords = Some(vec!["tracker".to_string(), "bug".to_string()]);
+
+        assert!(ItemFilter::none().matches(Some("Issues"), &None));
+        assert!(ItemFilter::new("   ").matches(Some("Issues"), &None));
+        assert!(ItemFilter::new("iss").matches(Some("Issues"), &None));
+        assert!(ItemFilter::new("ISS").matches(Some("Issues"), &None));
+        assert!(ItemFilter::new("iss bug").matches(Some("Issues"), &keywords));
+        assert!(!ItemFilter::new("iss feature").matches(Some("Issues"), &keywords));
+        assert!(ItemFilter::new("track").matches(None, &keywords));
+        assert!(!ItemFilter::new("track").matches(None, &None));
+    }
 }
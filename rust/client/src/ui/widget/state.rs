@@ -1,17 +1,34 @@
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
 
+use gauntlet_common::model::ContentWidget;
+use gauntlet_common::model::ContentWidgetOrderedMembers;
+use gauntlet_common::model::DetailWidget;
+use gauntlet_common::model::DropdownWidget;
+use gauntlet_common::model::DropdownWidgetOrderedMembers;
 use gauntlet_common::model::FormWidgetOrderedMembers;
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidgetOrderedMembers;
+use gauntlet_common::model::InlineWidgetOrderedMembers;
+use gauntlet_common::model::ListSectionWidgetOrderedMembers;
+use gauntlet_common::model::ListWidgetOrderedMembers;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::RootWidgetMembers;
 use gauntlet_common::model::UiWidgetId;
+use iced::widget::text_editor;
 use iced::widget::text_input;
 use iced_aw::date_picker::Date;
 
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::scroll_handle::ESTIMATED_MAIN_LIST_ITEM_HEIGHT;
+use crate::ui::widget::file_browser::FileBrowserState;
+use crate::ui::widget::file_browser::PathKind;
 use crate::ui::widget::grid::grid_width;
+use crate::ui::widget::highlight::highlight_code;
+use crate::ui::widget::highlight::HighlightedLine;
+use crate::ui::widget::markdown::parse_markdown;
+use crate::ui::widget::markdown::MarkdownBlock;
 
 pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWidgetState> {
     let mut result = HashMap::new();
@@ -22,6 +39,8 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
             match members {
                 RootWidgetMembers::Detail(widget) => {
                     result.insert(widget.__id__, ComponentWidgetState::root(0.0, 0));
+
+                    insert_detail_state(&mut result, widget);
                 }
                 RootWidgetMembers::Form(widget) => {
                     result.insert(widget.__id__, ComponentWidgetState::root(0.0, 0));
@@ -34,6 +53,17 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                             FormWidgetOrderedMembers::PasswordField(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                             }
+                            FormWidgetOrderedMembers::TextArea(widget) => {
+                                let markdown_preview = widget.markdown_preview.unwrap_or(false);
+
+                                result.insert(
+                                    widget.__id__,
+                                    ComponentWidgetState::text_area(&widget.value, markdown_preview),
+                                );
+                            }
+                            FormWidgetOrderedMembers::NumberField(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::number_field(&widget.value));
+                            }
                             FormWidgetOrderedMembers::Checkbox(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::checkbox(&widget.value));
                             }
@@ -43,6 +73,12 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                             FormWidgetOrderedMembers::Select(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::select(&widget.value));
                             }
+                            FormWidgetOrderedMembers::TagPicker(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::tag_picker(&widget.value));
+                            }
+                            FormWidgetOrderedMembers::FilePicker(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::file_picker(&widget.value));
+                            }
                             FormWidgetOrderedMembers::Separator(_) => {}
                         }
                     }
@@ -56,6 +92,35 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                     if let Some(widget) = &widget.content.search_bar {
                         result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                     }
+
+                    if let Some(widget) = &widget.content.search_bar_accessory {
+                        result.insert(widget.__id__, ComponentWidgetState::dropdown(widget));
+                    }
+
+                    if let Some(widget) = &widget.content.detail {
+                        insert_detail_state(&mut result, widget);
+                    }
+
+                    for members in &widget.content.ordered_members {
+                        match members {
+                            ListWidgetOrderedMembers::ListItem(widget) => {
+                                if let Some(widget) = &widget.content.detail {
+                                    insert_detail_state(&mut result, widget);
+                                }
+                            }
+                            ListWidgetOrderedMembers::ListSection(widget) => {
+                                for members in &widget.content.ordered_members {
+                                    match members {
+                                        ListSectionWidgetOrderedMembers::ListItem(widget) => {
+                                            if let Some(widget) = &widget.content.detail {
+                                                insert_detail_state(&mut result, widget);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
                 RootWidgetMembers::Grid(widget) => {
                     // cursed heuristic
@@ -96,11 +161,50 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
 
                     result.insert(widget.__id__, ComponentWidgetState::root(height, rows_per_view));
 
+                    for members in &widget.content.ordered_members {
+                        match members {
+                            GridWidgetOrderedMembers::GridItem(widget) => {
+                                insert_content_state(&mut result, &widget.content.content);
+                            }
+                            GridWidgetOrderedMembers::GridSection(widget) => {
+                                for members in &widget.content.ordered_members {
+                                    match members {
+                                        GridSectionWidgetOrderedMembers::GridItem(widget) => {
+                                            insert_content_state(&mut result, &widget.content.content);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(widget) = &widget.content.search_bar {
+                        result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
+                    }
+
+                    if let Some(widget) = &widget.content.search_bar_accessory {
+                        result.insert(widget.__id__, ComponentWidgetState::dropdown(widget));
+                    }
+                }
+                RootWidgetMembers::Table(widget) => {
+                    // one row of the view is taken by the table header
+                    result.insert(
+                        widget.__id__,
+                        ComponentWidgetState::root(ESTIMATED_MAIN_LIST_ITEM_HEIGHT, 6),
+                    );
+
                     if let Some(widget) = &widget.content.search_bar {
                         result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                     }
                 }
-                RootWidgetMembers::Inline(_) => {}
+                RootWidgetMembers::Inline(widget) => {
+                    for members in &widget.content.ordered_members {
+                        match members {
+                            InlineWidgetOrderedMembers::Content(widget) => insert_content_state(&mut result, widget),
+                            InlineWidgetOrderedMembers::InlineSeparator(_) => {}
+                        }
+                    }
+                }
             }
         }
     }
@@ -108,12 +212,41 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
     result
 }
 
+fn insert_detail_state(result: &mut HashMap<UiWidgetId, ComponentWidgetState>, widget: &DetailWidget) {
+    if let Some(widget) = &widget.content.content {
+        insert_content_state(result, widget);
+    }
+}
+
+fn insert_content_state(result: &mut HashMap<UiWidgetId, ComponentWidgetState>, widget: &ContentWidget) {
+    for members in &widget.content.ordered_members {
+        match members {
+            ContentWidgetOrderedMembers::CodeBlock(widget) => {
+                result.insert(
+                    widget.__id__,
+                    ComponentWidgetState::code_block(&widget.content.text, &widget.language),
+                );
+            }
+            ContentWidgetOrderedMembers::Markdown(widget) => {
+                result.insert(widget.__id__, ComponentWidgetState::markdown(&widget.content.text));
+            }
+            _ => {}
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ComponentWidgetState {
     TextField(TextFieldState),
+    TextArea(TextAreaState),
+    NumberField(NumberFieldState),
     Checkbox(CheckboxState),
     DatePicker(DatePickerState),
     Select(SelectState),
+    TagPicker(TagPickerState),
+    FilePicker(FilePickerState),
+    CodeBlock(CodeBlockState),
+    Markdown(MarkdownState),
     Root(RootState),
 }
 
@@ -123,6 +256,36 @@ pub struct TextFieldState {
     pub state_value: String,
 }
 
+pub struct TextAreaState {
+    pub content: text_editor::Content,
+    // present only if markdown preview is enabled, updated only when text is edited,
+    // so that markdown preview is not parsed on every render
+    pub preview: Option<MarkdownState>,
+}
+
+// editor content implements neither Clone nor Debug, only its text matters here
+impl Clone for TextAreaState {
+    fn clone(&self) -> Self {
+        Self {
+            content: text_editor::Content::with_text(&self.content.text()),
+            preview: self.preview.clone(),
+        }
+    }
+}
+
+impl Debug for TextAreaState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextAreaState")
+            .field("content", &self.content.text())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NumberFieldState {
+    pub state_value: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct CheckboxState {
     pub state_value: bool,
@@ -139,17 +302,93 @@ pub struct SelectState {
     pub state_value: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct TagPickerState {
+    pub state_value: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FilePickerState {
+    pub text_input_id: text_input::Id,
+    pub state_value: String,
+    // updated when value changes, so that file system is not queried on every render
+    pub path_kind: Option<PathKind>,
+    pub browser: Option<FileBrowserState>,
+}
+
+// code is highlighted once per render of the plugin instead of on every frame
+#[derive(Debug, Clone)]
+pub struct CodeBlockState {
+    pub lines: Vec<HighlightedLine>,
+}
+
+// markdown is parsed once per render of the plugin instead of on every frame
+#[derive(Debug, Clone)]
+pub struct MarkdownState {
+    pub blocks: Vec<MarkdownBlock>,
+    // highlighted code blocks, in the same order as they appear in blocks
+    pub code_blocks: Vec<Vec<HighlightedLine>>,
+}
+
+impl MarkdownState {
+    pub fn new(source: &str) -> MarkdownState {
+        fn highlight_code_blocks(blocks: &[MarkdownBlock], result: &mut Vec<Vec<HighlightedLine>>) {
+            for block in blocks {
+                match block {
+                    MarkdownBlock::CodeBlock { language, text } => {
+                        result.push(highlight_code(text, language.as_deref()));
+                    }
+                    MarkdownBlock::Quote { blocks } => highlight_code_blocks(blocks, result),
+                    MarkdownBlock::List { items, .. } => {
+                        for item in items {
+                            highlight_code_blocks(item, result);
+                        }
+                    }
+                    MarkdownBlock::Heading { .. }
+                    | MarkdownBlock::Paragraph { .. }
+                    | MarkdownBlock::HorizontalBreak
+                    | MarkdownBlock::Table { .. } => {}
+                }
+            }
+        }
+
+        let blocks = parse_markdown(source);
+
+        let mut code_blocks = vec![];
+
+        highlight_code_blocks(&blocks, &mut code_blocks);
+
+        MarkdownState { blocks, code_blocks }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RootState {
     pub show_action_panel: bool,
+    pub show_detail: bool,
     pub focused_item: ScrollHandle,
+    // amount of items at the time onLoadMore was last sent, to not send it again until plugin adds more items
+    pub load_more_requested_at: Option<usize>,
+    // required fields are reported as missing only after first submit attempt
+    pub submit_attempted: bool,
+    pub sort: Option<TableSort>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableSort {
+    pub column_index: usize,
+    pub descending: bool,
 }
 
 impl ComponentWidgetState {
     fn root(item_height: f32, rows_per_view: usize) -> ComponentWidgetState {
         ComponentWidgetState::Root(RootState {
             show_action_panel: false,
+            show_detail: true,
             focused_item: ScrollHandle::new(false, item_height, rows_per_view),
+            load_more_requested_at: None,
+            submit_attempted: false,
+            sort: None,
         })
     }
 
@@ -160,6 +399,21 @@ impl ComponentWidgetState {
         })
     }
 
+    fn text_area(value: &Option<String>, markdown_preview: bool) -> ComponentWidgetState {
+        let value = value.as_deref().unwrap_or_default();
+
+        ComponentWidgetState::TextArea(TextAreaState {
+            content: text_editor::Content::with_text(value),
+            preview: markdown_preview.then(|| MarkdownState::new(value)),
+        })
+    }
+
+    fn number_field(value: &Option<f64>) -> ComponentWidgetState {
+        ComponentWidgetState::NumberField(NumberFieldState {
+            state_value: value.to_owned().unwrap_or(0.0),
+        })
+    }
+
     fn checkbox(value: &Option<bool>) -> ComponentWidgetState {
         ComponentWidgetState::Checkbox(CheckboxState {
             state_value: value.to_owned().unwrap_or(false),
@@ -185,6 +439,46 @@ impl ComponentWidgetState {
             state_value: value.to_owned(),
         })
     }
+
+    // unlike select, dropdown always has an item selected
+    fn dropdown(widget: &DropdownWidget) -> ComponentWidgetState {
+        let value = widget.value.to_owned().or_else(|| {
+            widget.content.ordered_members.first().map(|members| {
+                match members {
+                    DropdownWidgetOrderedMembers::DropdownItem(widget) => widget.value.to_owned(),
+                }
+            })
+        });
+
+        ComponentWidgetState::Select(SelectState { state_value: value })
+    }
+
+    fn tag_picker(value: &Option<Vec<String>>) -> ComponentWidgetState {
+        ComponentWidgetState::TagPicker(TagPickerState {
+            state_value: value.to_owned().unwrap_or_default(),
+        })
+    }
+
+    fn file_picker(value: &Option<String>) -> ComponentWidgetState {
+        let state_value = value.to_owned().unwrap_or_default();
+
+        ComponentWidgetState::FilePicker(FilePickerState {
+            text_input_id: text_input::Id::unique(),
+            path_kind: PathKind::of(&state_value),
+            state_value,
+            browser: None,
+        })
+    }
+
+    fn code_block(text: &[String], language: &Option<String>) -> ComponentWidgetState {
+        ComponentWidgetState::CodeBlock(CodeBlockState {
+            lines: highlight_code(&text.join(""), language.as_deref()),
+        })
+    }
+
+    fn markdown(text: &[String]) -> ComponentWidgetState {
+        ComponentWidgetState::Markdown(MarkdownState::new(&text.join("")))
+    }
 }
 
 fn parse_date(value: &str) -> Option<(i32, u32, u32)> {
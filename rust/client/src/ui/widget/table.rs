@@ -0,0 +1,331 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use gauntlet_common::model::LauncherAction;
+use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::TableCellAccessories;
+use gauntlet_common::model::TableCellWidget;
+use gauntlet_common::model::TableColumnWidget;
+use gauntlet_common::model::TableRowWidget;
+use gauntlet_common::model::TableRowWidgetOrderedMembers;
+use gauntlet_common::model::TableWidget;
+use gauntlet_common::model::UiWidgetId;
+use iced::advanced::text::Shaping;
+use iced::widget::button;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::horizontal_space;
+use iced::widget::row;
+use iced::widget::scrollable;
+use iced::widget::text;
+use iced::widget::value;
+use iced::Alignment;
+use iced::Length;
+use iced_fonts::Bootstrap;
+use iced_fonts::BOOTSTRAP_FONT;
+
+use crate::ui::state::PluginViewState;
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::Element;
+use crate::ui::theme::ThemableWidget;
+use crate::ui::widget::accessories::render_icon_accessory;
+use crate::ui::widget::accessories::render_text_accessory;
+use crate::ui::widget::data::ComponentWidgets;
+use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::grid::render_loading_footer;
+use crate::ui::widget::images::render_image;
+use crate::ui::widget::state::RootState;
+use crate::ui::widget::state::TableSort;
+
+impl<'b> ComponentWidgets<'b> {
+    pub fn render_table_widget<'a>(
+        &self,
+        table_widget: &TableWidget,
+        plugin_view_state: &PluginViewState,
+        entrypoint_name: &str,
+        action_shortcuts: &HashMap<String, PhysicalShortcut>,
+        keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = table_widget.__id__;
+        let RootState {
+            show_action_panel,
+            focused_item,
+            sort,
+            ..
+        } = self.root_state(widget_id);
+
+        let is_loading = table_widget.is_loading.unwrap_or(false);
+
+        let columns = ComponentWidgets::table_columns(table_widget);
+        let rows = ComponentWidgets::table_rows(table_widget, sort);
+
+        let content = if rows.is_empty() {
+            match &table_widget.content.empty_view {
+                Some(widget) => self.render_empty_view_widget(widget),
+                None => horizontal_space().into(),
+            }
+        } else {
+            let header = render_table_header(widget_id, &columns, sort);
+
+            let primary_action = self.get_plugin_action_ids().first().copied();
+
+            let mut items: Vec<Element<_>> = rows
+                .iter()
+                .enumerate()
+                .map(|(index, widget)| {
+                    self.render_table_row_widget(widget, &columns, focused_item.index == Some(index), primary_action)
+                })
+                .collect();
+
+            if is_loading {
+                items.push(render_loading_footer());
+            }
+
+            let content: Element<_> = column(items).width(Length::Fill).into();
+
+            let content: Element<_> = container(content)
+                .width(Length::Fill)
+                .themed(ContainerStyle::TableInner);
+
+            let content: Element<_> = scrollable(content)
+                .id(focused_item.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    ComponentWidgetEvent::ScrollTable {
+                        table_widget_id: widget_id,
+                        position: viewport.absolute_offset().y,
+                    }
+                })
+                .width(Length::Fill)
+                .into();
+
+            let content: Element<_> = column([header, content]).into();
+
+            let content: Element<_> = container(content).width(Length::Fill).themed(ContainerStyle::Table);
+
+            content
+        };
+
+        let content: Element<_> = container(content).height(Length::Fill).into();
+
+        let focused_item_id = ComponentWidgets::table_focused_item_id(focused_item, table_widget, sort);
+
+        self.render_plugin_root(
+            *show_action_panel,
+            widget_id,
+            focused_item_id,
+            &table_widget.content.search_bar,
+            &None,
+            &table_widget.content.actions,
+            content,
+            is_loading,
+            plugin_view_state,
+            entrypoint_name,
+            action_shortcuts,
+            keybindings,
+        )
+    }
+
+    fn render_table_row_widget<'a>(
+        &self,
+        widget: &TableRowWidget,
+        columns: &[&TableColumnWidget],
+        focused: bool,
+        primary_action: Option<UiWidgetId>,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let cells: Vec<Element<_>> = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let content = match table_cell(widget, index) {
+                    None => horizontal_space().into(),
+                    Some(cell) => self.render_table_cell_widget(cell),
+                };
+
+                container(content)
+                    .width(column_width(column))
+                    .themed(ContainerStyle::TableCell)
+            })
+            .collect();
+
+        let content: Element<_> = row(cells).align_y(Alignment::Center).into();
+
+        let style = if focused {
+            ButtonStyle::ListItemFocused
+        } else {
+            ButtonStyle::ListItem
+        };
+
+        let on_press_msg = match primary_action {
+            None => ComponentWidgetEvent::Noop,
+            Some(widget_id) => {
+                ComponentWidgetEvent::RunPrimaryAction {
+                    widget_id,
+                    id: Some(widget.id.clone()),
+                }
+            }
+        };
+
+        button(content).on_press(on_press_msg).width(Length::Fill).themed(style)
+    }
+
+    fn render_table_cell_widget<'a>(&self, widget: &TableCellWidget) -> Element<'a, ComponentWidgetEvent> {
+        let mut content = vec![];
+
+        if let Some(icon) = &widget.icon {
+            let icon = render_image(self.data, widget.__id__, icon, None);
+
+            let icon: Element<_> = container(icon).themed(ContainerStyle::TableCellIcon);
+
+            content.push(icon)
+        }
+
+        if let Some(cell_text) = &widget.text {
+            let cell_text: Element<_> = text(cell_text.to_string()).shaping(Shaping::Advanced).into();
+
+            content.push(cell_text)
+        }
+
+        if widget.content.accessories.len() > 0 {
+            let accessories: Vec<Element<_>> = widget
+                .content
+                .accessories
+                .iter()
+                .map(|accessory| {
+                    match accessory {
+                        TableCellAccessories::_0(widget) => render_text_accessory(self.data, widget),
+                        TableCellAccessories::_1(widget) => render_icon_accessory(self.data, widget),
+                    }
+                })
+                .collect();
+
+            let accessories: Element<_> = row(accessories).into();
+
+            content.push(accessories);
+        }
+
+        row(content).align_y(Alignment::Center).into()
+    }
+}
+
+fn render_table_header<'a>(
+    table_widget_id: UiWidgetId,
+    columns: &[&TableColumnWidget],
+    sort: &Option<TableSort>,
+) -> Element<'a, ComponentWidgetEvent> {
+    let cells: Vec<Element<_>> = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let sortable = column.sortable.unwrap_or(false);
+
+            let title: Element<_> = if sortable {
+                text(column.title.to_string()).shaping(Shaping::Advanced).into()
+            } else {
+                text(column.title.to_string())
+                    .shaping(Shaping::Advanced)
+                    .themed(TextStyle::TableHeader)
+            };
+
+            let mut content = vec![title];
+
+            match sort {
+                Some(TableSort {
+                    column_index,
+                    descending,
+                }) if *column_index == index => {
+                    let icon = if *descending {
+                        Bootstrap::ArrowDown
+                    } else {
+                        Bootstrap::ArrowUp
+                    };
+
+                    let icon: Element<_> = value(icon).font(BOOTSTRAP_FONT).size(12).into();
+
+                    let icon: Element<_> = container(icon).themed(ContainerStyle::TableCellIcon);
+
+                    content.push(icon);
+                }
+                _ => {}
+            }
+
+            let content: Element<_> = row(content).align_y(Alignment::Center).into();
+
+            let content: Element<_> = if sortable {
+                button(content)
+                    .on_press(ComponentWidgetEvent::SortTable {
+                        table_widget_id,
+                        column_index: index,
+                        column_id: column.id.to_string(),
+                    })
+                    .width(Length::Fill)
+                    .themed(ButtonStyle::TableHeaderCell)
+            } else {
+                content
+            };
+
+            container(content)
+                .width(column_width(column))
+                .themed(ContainerStyle::TableCell)
+        })
+        .collect();
+
+    let content: Element<_> = row(cells).align_y(Alignment::Center).into();
+
+    container(content)
+        .width(Length::Fill)
+        .themed(ContainerStyle::TableHeader)
+}
+
+fn column_width(column: &TableColumnWidget) -> Length {
+    let portion = column.width.map(|width| width as u16).unwrap_or(1).max(1);
+
+    Length::FillPortion(portion)
+}
+
+pub fn table_cell(row: &TableRowWidget, column_index: usize) -> Option<&TableCellWidget> {
+    row.content
+        .ordered_members
+        .iter()
+        .map(|members| {
+            match members {
+                TableRowWidgetOrderedMembers::TableCell(widget) => widget,
+            }
+        })
+        .nth(column_index)
+}
+
+// numbers are compared by value so that "9" comes before "10", empty cells always go first
+pub fn compare_cell_text(a: Option<&str>, b: Option<&str>) -> Ordering {
+    let a = a.map(|value| value.trim()).filter(|value| !value.is_empty());
+    let b = b.map(|value| value.trim()).filter(|value| !value.is_empty());
+
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => {
+            match (a.parse::<f64>(), b.parse::<f64>()) {
+                (Ok(a), Ok(b)) => a.total_cmp(&b),
+                _ => a.to_lowercase().cmp(&b.to_lowercase()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_cells_numerically_and_case_insensitively() {
+        assert_eq!(compare_cell_text(Some("9"), Some("10")), Ordering::Less);
+        assert_eq!(compare_cell_text(Some("2.5"), Some("-1")), Ordering::Greater);
+        assert_eq!(compare_cell_text(Some("alpha"), Some("Beta")), Ordering::Less);
+        assert_eq!(compare_cell_text(Some("Pod"), Some("pod")), Ordering::Equal);
+        assert_eq!(compare_cell_text(Some("10"), Some("9a")), Ordering::Less);
+        assert_eq!(compare_cell_text(None, Some("a")), Ordering::Less);
+        assert_eq!(compare_cell_text(Some(" "), None), Ordering::Equal);
+    }
+}
@@ -18,9 +18,9 @@ pub enum TextRenderType {
     H6,
 }
 
-impl<'b> ComponentWidgets<'b> {
-    pub fn render_text<'a>(&self, value: &[String], context: TextRenderType) -> Element<'a, ComponentWidgetEvent> {
-        let header = match context {
+impl TextRenderType {
+    pub fn header_size(&self) -> Option<u16> {
+        match self {
             TextRenderType::None => None,
             TextRenderType::H1 => Some(34),
             TextRenderType::H2 => Some(30),
@@ -28,11 +28,15 @@ impl<'b> ComponentWidgets<'b> {
             TextRenderType::H4 => Some(20),
             TextRenderType::H5 => Some(18),
             TextRenderType::H6 => Some(16),
-        };
+        }
+    }
+}
 
+impl<'b> ComponentWidgets<'b> {
+    pub fn render_text<'a>(&self, value: &[String], context: TextRenderType) -> Element<'a, ComponentWidgetEvent> {
         let mut text = text(value.join("")).shaping(Shaping::Advanced);
 
-        if let Some(size) = header {
+        if let Some(size) = context.header_size() {
             text = text.size(size).font(Font {
                 weight: Weight::Bold,
                 ..Font::DEFAULT
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use std::sync::Mutex;
 
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::LauncherAction;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
@@ -21,6 +22,8 @@ use crate::ui::widget::data_mut::ComponentWidgetsMut;
 use crate::ui::widget::events::ComponentWidgetEvent;
 use crate::ui::widget::state::create_state;
 use crate::ui::widget::state::ComponentWidgetState;
+use crate::ui::widget::state::MarkdownState;
+use crate::ui::widget::state::TextAreaState;
 use crate::ui::AppMsg;
 
 pub struct PluginWidgetContainer {
@@ -81,8 +84,31 @@ impl PluginWidgetContainer {
 
         for (key, value) in old_state.into_iter() {
             match self.state.entry(key) {
+                // code block and markdown state is derived from widget text, which may have changed
+                Entry::Occupied(entry)
+                    if matches!(
+                        entry.get(),
+                        ComponentWidgetState::CodeBlock(_) | ComponentWidgetState::Markdown(_)
+                    ) => {}
                 Entry::Occupied(mut entry) => {
-                    entry.insert(value);
+                    match (entry.get_mut(), value) {
+                        // edited text is kept, but whether markdown preview is shown follows current widget props
+                        (ComponentWidgetState::TextArea(new_state), ComponentWidgetState::TextArea(old_state)) => {
+                            let preview = match (&new_state.preview, old_state.preview) {
+                                (None, _) => None,
+                                (Some(_), Some(preview)) => Some(preview),
+                                (Some(_), None) => Some(MarkdownState::new(&old_state.content.text())),
+                            };
+
+                            *new_state = TextAreaState {
+                                content: old_state.content,
+                                preview,
+                            };
+                        }
+                        (new_state, value) => {
+                            *new_state = value;
+                        }
+                    }
                 }
                 Entry::Vacant(_) => {}
             }
@@ -105,19 +131,30 @@ impl PluginWidgetContainer {
     pub fn handle_event(&mut self, plugin_id: PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
         let widget_id = event.widget_id();
 
-        event.handle(plugin_id, self.state.get_mut(&widget_id))
+        let search_text_changed = matches!(event, ComponentWidgetEvent::OnChangeSearchBar { .. });
+
+        let result = event.handle(plugin_id.clone(), self.state.get_mut(&widget_id));
+
+        if search_text_changed {
+            ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data)
+                .reset_filtered_focus();
+        }
+
+        result
     }
 
     pub fn render_root_widget<'a>(
-        &self,
+        &'a self,
         plugin_id: PluginId,
         plugin_view_state: &PluginViewState,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
+        keybindings: &HashMap<LauncherAction, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
         ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.data).render_root_widget(
             plugin_view_state,
             self.entrypoint_name.as_ref(),
             action_shortcuts,
+            keybindings,
         )
     }
 
@@ -128,12 +165,24 @@ impl PluginWidgetContainer {
 
     pub fn append_text(&mut self, text: &str) -> Task<AppMsg> {
         let plugin_id = self.get_plugin_id();
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data).append_text(text)
+        let mut widgets = ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data);
+
+        let task = widgets.append_text(text);
+
+        widgets.reset_filtered_focus();
+
+        task
     }
 
     pub fn backspace_text(&mut self) -> Task<AppMsg> {
         let plugin_id = self.get_plugin_id();
-        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data).backspace_text()
+        let mut widgets = ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data);
+
+        let task = widgets.backspace_text();
+
+        widgets.reset_filtered_focus();
+
+        task
     }
 
     pub fn focus_search_bar(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
@@ -146,10 +195,29 @@ impl PluginWidgetContainer {
         ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data).toggle_action_panel()
     }
 
+    pub fn toggle_detail(&mut self) -> bool {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data).toggle_detail()
+    }
+
+    pub fn cycle_search_bar_accessory(&self) -> Option<Task<AppMsg>> {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.data).cycle_search_bar_accessory()
+    }
+
+    pub fn submit_form(&mut self) -> Option<Task<AppMsg>> {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.data).submit_form()
+    }
+
     pub fn get_action_ids(&self) -> Vec<UiWidgetId> {
         ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.data).get_action_ids()
     }
 
+    pub fn get_plugin_action_ids(&self) -> Vec<UiWidgetId> {
+        ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.data).get_plugin_action_ids()
+    }
+
     pub fn get_focused_item_id(&self) -> Option<String> {
         ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.data).get_focused_item_id()
     }
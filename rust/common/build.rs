@@ -205,6 +205,7 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                             items.iter().flat_map(|prop| all_component_refs(prop)).collect()
                                         }
                                         PropertyType::Array { item } => all_component_refs(item),
+                                        PropertyType::Record { item } => all_component_refs(item),
                                     }
                                 }
 
@@ -802,5 +803,6 @@ fn generate_required_type(property_type: &PropertyType, union_name: Option<Strin
             }
         }
         PropertyType::Array { item } => format!("Vec<{}>", generate_required_type(item, union_name)),
+        PropertyType::Record { .. } => panic!("records are only supported in event arguments"),
     }
 }
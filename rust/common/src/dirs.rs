@@ -56,6 +56,10 @@ impl Dirs {
         self.config_dir().join("theme.toml")
     }
 
+    pub fn calculator_file(&self) -> PathBuf {
+        self.config_dir().join("calculator.nbt")
+    }
+
     pub fn config_dir(&self) -> PathBuf {
         let config_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.config_dir().to_path_buf()
@@ -142,4 +146,8 @@ impl Dirs {
     pub fn window_position(&self) -> PathBuf {
         self.state_dir().join("window_position")
     }
+
+    pub fn window_position_per_monitor(&self) -> PathBuf {
+        self.state_dir().join("window_position_per_monitor")
+    }
 }
@@ -87,7 +87,41 @@ pub enum UiRenderLocation {
     View,
 }
 
-#[derive(Debug, Clone, Encode, Decode)]
+#[derive(Debug, Clone)]
+pub struct UiToast {
+    pub id: u32,
+    pub style: UiToastStyle,
+    pub title: String,
+    pub message: Option<String>,
+    pub primary_action: Option<String>,
+    pub secondary_action: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UiToastStyle {
+    Success,
+    Failure,
+    Animated,
+}
+
+#[derive(Debug, Clone)]
+pub struct UiConfirmAlert {
+    pub id: u32,
+    pub title: String,
+    pub message: Option<String>,
+    pub icon: Option<Icons>,
+    pub primary_action: String,
+    pub primary_action_style: UiConfirmAlertActionStyle,
+    pub dismiss_action: String,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UiConfirmAlertActionStyle {
+    Default,
+    Destructive,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
 pub struct PhysicalShortcut {
     pub physical_key: PhysicalKey,
     pub modifier_shift: bool,
@@ -114,6 +148,27 @@ pub struct SearchResult {
     pub entrypoint_actions: Vec<SearchResultEntrypointAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
     pub entrypoint_alias: Option<String>,
+    // None if result grouping is disabled
+    pub section: Option<SearchResultSection>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SearchResultSection {
+    RecentlyUsed,
+    Applications,
+    Commands,
+    Generated { plugin_name: String },
+}
+
+impl Display for SearchResultSection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchResultSection::RecentlyUsed => write!(f, "Recently Used"),
+            SearchResultSection::Applications => write!(f, "Applications"),
+            SearchResultSection::Commands => write!(f, "Commands"),
+            SearchResultSection::Generated { plugin_name } => write!(f, "Generated by {}", plugin_name),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -159,6 +214,9 @@ pub enum UiThemeMode {
 pub enum WindowPositionMode {
     Static,
     ActiveMonitor,
+    CursorMonitor,
+    FocusedWindowMonitor,
+    RememberPerMonitor,
 }
 
 impl Display for WindowPositionMode {
@@ -166,12 +224,183 @@ impl Display for WindowPositionMode {
         let label = match self {
             WindowPositionMode::Static => "Static",
             WindowPositionMode::ActiveMonitor => "Active Monitor",
+            WindowPositionMode::CursorMonitor => "Center on Monitor with Cursor",
+            WindowPositionMode::FocusedWindowMonitor => "Center on Monitor with Focused Window",
+            WindowPositionMode::RememberPerMonitor => "Remember Position per Monitor",
         };
 
         write!(f, "{}", label)
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NavigationKeymap {
+    Default,
+    // Ctrl+J/Ctrl+K
+    Vim,
+    // Ctrl+N/Ctrl+P
+    Emacs,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Encode, Decode)]
+pub enum LauncherAction {
+    ToggleActionPanel,
+    OpenSettings,
+    RunPrimaryAction,
+    RunSecondaryAction,
+    Back,
+    RecallOlderQuery,
+    RecallNewerQuery,
+    ToggleDetail,
+    SubmitForm,
+    CycleSearchBarAccessory,
+}
+
+impl LauncherAction {
+    pub const ALL: [LauncherAction; 10] = [
+        LauncherAction::ToggleActionPanel,
+        LauncherAction::OpenSettings,
+        LauncherAction::RunPrimaryAction,
+        LauncherAction::RunSecondaryAction,
+        LauncherAction::Back,
+        LauncherAction::RecallOlderQuery,
+        LauncherAction::RecallNewerQuery,
+        LauncherAction::ToggleDetail,
+        LauncherAction::SubmitForm,
+        LauncherAction::CycleSearchBarAccessory,
+    ];
+
+    // name used in config file
+    pub fn id(&self) -> &'static str {
+        match self {
+            LauncherAction::ToggleActionPanel => "toggle_action_panel",
+            LauncherAction::OpenSettings => "open_settings",
+            LauncherAction::RunPrimaryAction => "run_primary_action",
+            LauncherAction::RunSecondaryAction => "run_secondary_action",
+            LauncherAction::Back => "back",
+            LauncherAction::RecallOlderQuery => "recall_older_query",
+            LauncherAction::RecallNewerQuery => "recall_newer_query",
+            LauncherAction::ToggleDetail => "toggle_detail",
+            LauncherAction::SubmitForm => "submit_form",
+            LauncherAction::CycleSearchBarAccessory => "cycle_search_bar_accessory",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<LauncherAction> {
+        LauncherAction::ALL.into_iter().find(|action| action.id() == id)
+    }
+
+    pub fn default_shortcut(&self) -> PhysicalShortcut {
+        let shortcut = |physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta| {
+            PhysicalShortcut {
+                physical_key,
+                modifier_shift,
+                modifier_control,
+                modifier_alt,
+                modifier_meta,
+            }
+        };
+
+        match self {
+            LauncherAction::ToggleActionPanel => shortcut(PhysicalKey::KeyK, false, false, true, false),
+            LauncherAction::OpenSettings => {
+                shortcut(
+                    PhysicalKey::Comma,
+                    false,
+                    cfg!(any(target_os = "linux", target_os = "windows")),
+                    false,
+                    cfg!(target_os = "macos"),
+                )
+            }
+            LauncherAction::RunPrimaryAction => shortcut(PhysicalKey::Enter, false, false, false, false),
+            LauncherAction::RunSecondaryAction => shortcut(PhysicalKey::Enter, true, false, false, false),
+            LauncherAction::Back => shortcut(PhysicalKey::Escape, false, false, false, false),
+            LauncherAction::RecallOlderQuery => shortcut(PhysicalKey::KeyP, false, true, false, false),
+            LauncherAction::RecallNewerQuery => shortcut(PhysicalKey::KeyN, false, true, false, false),
+            LauncherAction::ToggleDetail => shortcut(PhysicalKey::KeyD, false, false, true, false),
+            LauncherAction::SubmitForm => {
+                shortcut(
+                    PhysicalKey::Enter,
+                    false,
+                    cfg!(any(target_os = "linux", target_os = "windows")),
+                    false,
+                    cfg!(target_os = "macos"),
+                )
+            }
+            LauncherAction::CycleSearchBarAccessory => shortcut(PhysicalKey::KeyF, false, false, true, false),
+        }
+    }
+}
+
+impl Display for LauncherAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            LauncherAction::ToggleActionPanel => "Toggle Action Panel",
+            LauncherAction::OpenSettings => "Open Settings",
+            LauncherAction::RunPrimaryAction => "Run Primary Action",
+            LauncherAction::RunSecondaryAction => "Run Secondary Action",
+            LauncherAction::Back => "Go Back",
+            LauncherAction::RecallOlderQuery => "Previous Search Query",
+            LauncherAction::RecallNewerQuery => "Next Search Query",
+            LauncherAction::ToggleDetail => "Toggle Detail",
+            LauncherAction::SubmitForm => "Submit Form",
+            LauncherAction::CycleSearchBarAccessory => "Cycle Search Bar Accessory",
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+pub const QUICK_PICK_COUNT: usize = 9;
+
+/// Shortcut which runs search result at given index in main view, Alt+digit or Cmd+digit on macOS
+pub fn quick_pick_shortcut(index: usize) -> Option<PhysicalShortcut> {
+    let physical_key = match index {
+        0 => PhysicalKey::Digit1,
+        1 => PhysicalKey::Digit2,
+        2 => PhysicalKey::Digit3,
+        3 => PhysicalKey::Digit4,
+        4 => PhysicalKey::Digit5,
+        5 => PhysicalKey::Digit6,
+        6 => PhysicalKey::Digit7,
+        7 => PhysicalKey::Digit8,
+        8 => PhysicalKey::Digit9,
+        _ => return None,
+    };
+
+    Some(PhysicalShortcut {
+        physical_key,
+        modifier_shift: false,
+        modifier_control: false,
+        modifier_alt: cfg!(not(target_os = "macos")),
+        modifier_meta: cfg!(target_os = "macos"),
+    })
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum SettingsKeybindingAction {
+    Launcher(LauncherAction),
+    // index of search result
+    QuickPick(usize),
+}
+
+impl Display for SettingsKeybindingAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsKeybindingAction::Launcher(action) => write!(f, "{}", action),
+            SettingsKeybindingAction::QuickPick(index) => write!(f, "Run Search Result {}", index + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct SettingsKeybinding {
+    pub action: SettingsKeybindingAction,
+    pub shortcut: PhysicalShortcut,
+    // descriptions of plugin actions which use the same shortcut
+    pub conflicts: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct UiThemeColor {
     pub r: f32,
@@ -216,10 +445,17 @@ pub struct UiTheme {
 #[derive(Debug)]
 pub struct UiSetupData {
     pub window_position_file: Option<PathBuf>,
+    pub window_position_per_monitor_file: Option<PathBuf>,
+    pub window_width: f32,
+    pub window_height: f32,
+    pub max_visible_results: Option<usize>,
     pub theme: UiTheme,
     pub global_shortcut: Option<PhysicalShortcut>,
     pub global_entrypoint_shortcuts: HashMap<(PluginId, EntrypointId), PhysicalShortcut>,
     pub close_on_unfocus: bool,
+    pub restore_last_query: bool,
+    pub navigation_keymap: NavigationKeymap,
+    pub keybindings: HashMap<LauncherAction, PhysicalShortcut>,
     pub window_position_mode: WindowPositionMode,
 }
 
@@ -332,6 +568,7 @@ pub trait WidgetVisitor {
     async fn h6_widget(&mut self, _widget: &H6Widget) {}
     async fn horizontal_break_widget(&mut self, _widget: &HorizontalBreakWidget) {}
     async fn code_block_widget(&mut self, _widget: &CodeBlockWidget) {}
+    async fn markdown_widget(&mut self, _widget: &MarkdownWidget) {}
     async fn paragraph_widget(&mut self, _widget: &ParagraphWidget) {}
     async fn content_widget(&mut self, widget: &ContentWidget) {
         for members in &widget.content.ordered_members {
@@ -346,6 +583,7 @@ pub trait WidgetVisitor {
                 ContentWidgetOrderedMembers::H6(widget) => self.h6_widget(widget).await,
                 ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
                 ContentWidgetOrderedMembers::CodeBlock(widget) => self.code_block_widget(widget).await,
+                ContentWidgetOrderedMembers::Markdown(widget) => self.markdown_widget(widget).await,
                 ContentWidgetOrderedMembers::Svg(widget) => self.svg_widget(widget).await,
             }
         }
@@ -365,6 +603,8 @@ pub trait WidgetVisitor {
 
     async fn text_field_widget(&mut self, _widget: &TextFieldWidget) {}
     async fn password_field_widget(&mut self, _widget: &PasswordFieldWidget) {}
+    async fn text_area_widget(&mut self, _widget: &TextAreaWidget) {}
+    async fn number_field_widget(&mut self, _widget: &NumberFieldWidget) {}
     async fn checkbox_widget(&mut self, _widget: &CheckboxWidget) {}
     async fn date_picker_widget(&mut self, _widget: &DatePickerWidget) {}
     async fn select_item_widget(&mut self, _widget: &SelectItemWidget) {}
@@ -375,6 +615,15 @@ pub trait WidgetVisitor {
             }
         }
     }
+    async fn tag_picker_item_widget(&mut self, _widget: &TagPickerItemWidget) {}
+    async fn tag_picker_widget(&mut self, widget: &TagPickerWidget) {
+        for members in &widget.content.ordered_members {
+            match members {
+                TagPickerWidgetOrderedMembers::TagPickerItem(widget) => self.tag_picker_item_widget(widget).await,
+            }
+        }
+    }
+    async fn file_picker_widget(&mut self, _widget: &FilePickerWidget) {}
     async fn separator_widget(&mut self, _widget: &SeparatorWidget) {}
     async fn form_widget(&mut self, widget: &FormWidget) {
         if let Some(widget) = &widget.content.actions {
@@ -384,9 +633,13 @@ pub trait WidgetVisitor {
             match members {
                 FormWidgetOrderedMembers::TextField(widget) => self.text_field_widget(widget).await,
                 FormWidgetOrderedMembers::PasswordField(widget) => self.password_field_widget(widget).await,
+                FormWidgetOrderedMembers::TextArea(widget) => self.text_area_widget(widget).await,
+                FormWidgetOrderedMembers::NumberField(widget) => self.number_field_widget(widget).await,
                 FormWidgetOrderedMembers::Checkbox(widget) => self.checkbox_widget(widget).await,
                 FormWidgetOrderedMembers::DatePicker(widget) => self.date_picker_widget(widget).await,
                 FormWidgetOrderedMembers::Select(widget) => self.select_widget(widget).await,
+                FormWidgetOrderedMembers::TagPicker(widget) => self.tag_picker_widget(widget).await,
+                FormWidgetOrderedMembers::FilePicker(widget) => self.file_picker_widget(widget).await,
                 FormWidgetOrderedMembers::Separator(widget) => self.separator_widget(widget).await,
             }
         }
@@ -434,6 +687,10 @@ pub trait WidgetVisitor {
                 ListItemAccessories::_1(widget) => self.icon_accessory_widget(widget).await,
             }
         }
+
+        if let Some(widget) = &widget.content.detail {
+            self.detail_widget(widget).await
+        }
     }
     async fn list_section_widget(&mut self, widget: &ListSectionWidget) {
         for members in &widget.content.ordered_members {
@@ -493,6 +750,43 @@ pub trait WidgetVisitor {
             }
         }
     }
+    async fn table_column_widget(&mut self, _widget: &TableColumnWidget) {}
+    async fn table_cell_widget(&mut self, widget: &TableCellWidget) {
+        if let Some(image) = &widget.icon {
+            self.image(widget.__id__, image).await
+        }
+
+        for accessories in &widget.content.accessories {
+            match accessories {
+                TableCellAccessories::_0(widget) => self.text_accessory_widget(widget).await,
+                TableCellAccessories::_1(widget) => self.icon_accessory_widget(widget).await,
+            }
+        }
+    }
+    async fn table_row_widget(&mut self, widget: &TableRowWidget) {
+        for members in &widget.content.ordered_members {
+            match members {
+                TableRowWidgetOrderedMembers::TableCell(widget) => self.table_cell_widget(widget).await,
+            }
+        }
+    }
+    async fn table_widget(&mut self, widget: &TableWidget) {
+        if let Some(widget) = &widget.content.actions {
+            self.action_panel_widget(widget).await
+        }
+        if let Some(widget) = &widget.content.search_bar {
+            self.search_bar_widget(widget).await
+        }
+        if let Some(widget) = &widget.content.empty_view {
+            self.empty_view_widget(widget).await
+        }
+        for members in &widget.content.ordered_members {
+            match members {
+                TableWidgetOrderedMembers::TableColumn(widget) => self.table_column_widget(widget).await,
+                TableWidgetOrderedMembers::TableRow(widget) => self.table_row_widget(widget).await,
+            }
+        }
+    }
 
     async fn root_widget(&mut self, root_widget: &RootWidget) {
         if let Some(members) = &root_widget.content {
@@ -502,6 +796,7 @@ pub trait WidgetVisitor {
                 RootWidgetMembers::Inline(widget) => self.inline_widget(widget).await,
                 RootWidgetMembers::List(widget) => self.list_widget(widget).await,
                 RootWidgetMembers::Grid(widget) => self.grid_widget(widget).await,
+                RootWidgetMembers::Table(widget) => self.table_widget(widget).await,
             }
         }
     }
@@ -643,7 +938,7 @@ pub struct PreferenceEnumValue {
 }
 
 // copy of iced (currently fork) PhysicalKey but without modifiers
-#[derive(Debug, Clone, Decode, Encode)]
+#[derive(Debug, Clone, Eq, PartialEq, Decode, Encode)]
 pub enum PhysicalKey {
     Backquote,
     Backslash,
@@ -832,7 +1127,14 @@ pub enum PhysicalKey {
 
 impl PhysicalKey {
     pub fn from_value(key: String) -> PhysicalKey {
-        match key.as_str() {
+        match PhysicalKey::try_from_value(&key) {
+            Some(key) => key,
+            None => panic!("unknown key: {}", key),
+        }
+    }
+
+    pub fn try_from_value(key: &str) -> Option<PhysicalKey> {
+        let key = match key {
             "Backquote" => PhysicalKey::Backquote,
             "Backslash" => PhysicalKey::Backslash,
             "BracketLeft" => PhysicalKey::BracketLeft,
@@ -1016,10 +1318,10 @@ impl PhysicalKey {
             "F33" => PhysicalKey::F33,
             "F34" => PhysicalKey::F34,
             "F35" => PhysicalKey::F35,
-            _ => {
-                panic!("unknown key: {}", key)
-            }
-        }
+            _ => return None,
+        };
+
+        Some(key)
     }
 
     pub fn to_value(&self) -> String {
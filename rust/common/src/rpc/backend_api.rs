@@ -16,6 +16,7 @@ use crate::model::PhysicalShortcut;
 use crate::model::PluginId;
 use crate::model::PluginPreferenceUserData;
 use crate::model::SearchResult;
+use crate::model::SettingsKeybinding;
 use crate::model::SettingsPlugin;
 use crate::model::SettingsTheme;
 use crate::model::UiPropertyValue;
@@ -78,6 +79,15 @@ pub trait BackendForFrontendApi {
 
     async fn send_open_event(&self, plugin_id: PluginId, href: String) -> RequestResult<()>;
 
+    async fn send_toast_action_event(
+        &self,
+        plugin_id: PluginId,
+        toast_id: u32,
+        action_index: usize,
+    ) -> RequestResult<()>;
+
+    async fn send_confirm_alert_event(&self, plugin_id: PluginId, alert_id: u32, confirmed: bool) -> RequestResult<()>;
+
     async fn open_settings_window(&self) -> RequestResult<()>;
 
     async fn open_settings_window_preferences(
@@ -89,6 +99,10 @@ pub trait BackendForFrontendApi {
     async fn inline_view_shortcuts(&self) -> RequestResult<HashMap<PluginId, HashMap<String, PhysicalShortcut>>>;
 
     async fn run_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> RequestResult<()>;
+
+    async fn search_history(&self) -> RequestResult<Vec<String>>;
+
+    async fn save_search_history_entry(&self, query: String) -> RequestResult<()>;
 }
 
 #[boundary_gen(bincode, grpc)]
@@ -159,6 +173,10 @@ pub trait BackendForSettingsApi {
 
     async fn get_window_position_mode(&self) -> RequestResult<WindowPositionMode>;
 
+    async fn clear_search_history(&self) -> RequestResult<()>;
+
+    async fn keybindings(&self) -> RequestResult<Vec<SettingsKeybinding>>;
+
     async fn set_preference_value(
         &self,
         plugin_id: PluginId,
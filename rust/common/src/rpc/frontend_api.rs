@@ -7,8 +7,10 @@ use crate::model::EntrypointId;
 use crate::model::PhysicalShortcut;
 use crate::model::PluginId;
 use crate::model::RootWidget;
+use crate::model::UiConfirmAlert;
 use crate::model::UiRenderLocation;
 use crate::model::UiTheme;
+use crate::model::UiToast;
 use crate::model::UiWidgetId;
 use crate::model::WindowPositionMode;
 
@@ -35,6 +37,8 @@ pub trait FrontendApi {
 
     async fn hide_window(&self) -> RequestResult<()>;
 
+    async fn paste_to_active_window(&self, cursor_offset_from_end: u32) -> RequestResult<()>;
+
     async fn show_preference_required_view(
         &self,
         plugin_id: PluginId,
@@ -52,6 +56,14 @@ pub trait FrontendApi {
 
     async fn show_hud(&self, display: String) -> RequestResult<()>;
 
+    async fn show_toast(&self, plugin_id: PluginId, toast: UiToast) -> RequestResult<()>;
+
+    async fn hide_toast(&self, plugin_id: PluginId, toast_id: u32) -> RequestResult<()>;
+
+    async fn clear_toasts(&self, plugin_id: PluginId) -> RequestResult<()>;
+
+    async fn show_confirm_alert(&self, plugin_id: PluginId, alert: UiConfirmAlert) -> RequestResult<()>;
+
     async fn update_loading_bar(
         &self,
         plugin_id: PluginId,
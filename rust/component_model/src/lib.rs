@@ -85,6 +85,9 @@ pub enum PropertyType {
     Union { items: Vec<PropertyType> },
     #[serde(rename = "array")]
     Array { item: Box<PropertyType> },
+    // object with arbitrary string keys, only supported in event arguments
+    #[serde(rename = "record")]
+    Record { item: Box<PropertyType> },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -118,6 +121,7 @@ impl PropertyType {
                 first_variant.kind()
             }
             PropertyType::Array { item } => item.kind(),
+            PropertyType::Record { item } => item.kind(),
         }
     }
 }
@@ -876,10 +880,23 @@ pub fn create_component_model() -> Vec<Component> {
         "code_block",
         mark_doc!("/code_block/description.md"),
         "CodeBlock",
-        [],
+        [property(
+            "language",
+            mark_doc!("/code_block/props/language.md"),
+            true,
+            PropertyType::String,
+        )],
         children_string(mark_doc!("/code_block/props/children.md")),
     );
 
+    let markdown_component = component(
+        "markdown",
+        mark_doc!("/markdown/description.md"),
+        "Markdown",
+        [],
+        children_string(mark_doc!("/markdown/props/children.md")),
+    );
+
     // let code_component = component(
     //     "code",
     //     "Code",
@@ -919,6 +936,7 @@ pub fn create_component_model() -> Vec<Component> {
                 member("H6", &h6_component, Arity::ZeroOrMore),
                 member("HorizontalBreak", &horizontal_break_component, Arity::ZeroOrMore),
                 member("CodeBlock", &code_block_component, Arity::ZeroOrMore),
+                member("Markdown", &markdown_component, Arity::ZeroOrMore),
                 // member("Code", &code_component),
             ],
             [],
@@ -963,12 +981,31 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
             property(
                 "value",
                 mark_doc!("/text_field/props/value.md"),
                 true,
                 PropertyType::String,
             ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/text_field/props/onChange.md"),
@@ -990,12 +1027,31 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
             property(
                 "value",
                 mark_doc!("/password_field/props/value.md"),
                 true,
                 PropertyType::String,
             ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/password_field/props/onChange.md"),
@@ -1006,12 +1062,121 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
-    // let text_area_component = component(
-    //     "text_area",
-    //     "TextArea",
-    //     [],
-    //     children_none(),
-    // );
+    let text_area_component = component(
+        "text_area",
+        mark_doc!("/text_area/description.md"),
+        "TextArea",
+        [
+            property(
+                "label",
+                mark_doc!("/text_area/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
+            property(
+                "value",
+                mark_doc!("/text_area/props/value.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "markdownPreview",
+                mark_doc!("/text_area/props/markdownPreview.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/text_area/props/onChange.md"),
+                true,
+                [property("value", "".to_string(), true, PropertyType::String)],
+            ),
+        ],
+        children_none(),
+    );
+
+    let number_field_component = component(
+        "number_field",
+        mark_doc!("/number_field/description.md"),
+        "NumberField",
+        [
+            property(
+                "label",
+                mark_doc!("/number_field/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
+            property(
+                "value",
+                mark_doc!("/number_field/props/value.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "min",
+                mark_doc!("/number_field/props/min.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "max",
+                mark_doc!("/number_field/props/max.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "step",
+                mark_doc!("/number_field/props/step.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/number_field/props/onChange.md"),
+                true,
+                [property("value", "".to_string(), true, PropertyType::Number)],
+            ),
+        ],
+        children_none(),
+    );
 
     let checkbox_component = component(
         "checkbox",
@@ -1024,6 +1189,7 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
             property(
                 "title",
                 mark_doc!("/checkbox/props/title.md"),
@@ -1036,6 +1202,24 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::Boolean,
             ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/checkbox/props/onChange.md"),
@@ -1057,12 +1241,31 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
             property(
                 "value",
                 mark_doc!("/date_picker/props/value.md"),
                 true,
                 PropertyType::String,
             ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/date_picker/props/onChange.md"),
@@ -1092,7 +1295,26 @@ pub fn create_component_model() -> Vec<Component> {
         "Select",
         [
             property("label", mark_doc!("/select/props/label.md"), true, PropertyType::String),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
             property("value", mark_doc!("/select/props/value.md"), true, PropertyType::String),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/select/props/onChange.md"),
@@ -1103,12 +1325,125 @@ pub fn create_component_model() -> Vec<Component> {
         children_members([member("Item", &select_item_component, Arity::ZeroOrMore)], []),
     );
 
-    // let multi_select_component = component(
-    //     "multi_select",
-    //     "MultiSelect",
-    //     [],
-    //     children_none(),
-    // );
+    let tag_picker_item_component = component(
+        "tag_picker_item",
+        mark_doc!("/tag_picker_item/description.md"),
+        "TagPickerItem",
+        [property(
+            "value",
+            mark_doc!("/tag_picker_item/props/value.md"),
+            false,
+            PropertyType::String,
+        )],
+        children_string(mark_doc!("/tag_picker_item/props/children.md")),
+    );
+
+    let tag_picker_component = component(
+        "tag_picker",
+        mark_doc!("/tag_picker/description.md"),
+        "TagPicker",
+        [
+            property(
+                "label",
+                mark_doc!("/tag_picker/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
+            property(
+                "value",
+                mark_doc!("/tag_picker/props/value.md"),
+                true,
+                PropertyType::Array {
+                    item: Box::new(PropertyType::String),
+                },
+            ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/tag_picker/props/onChange.md"),
+                true,
+                [property(
+                    "value",
+                    "".to_string(),
+                    false,
+                    PropertyType::Array {
+                        item: Box::new(PropertyType::String),
+                    },
+                )],
+            ),
+        ],
+        children_members([member("Item", &tag_picker_item_component, Arity::ZeroOrMore)], []),
+    );
+
+    let file_picker_component = component(
+        "file_picker",
+        mark_doc!("/file_picker/description.md"),
+        "FilePicker",
+        [
+            property(
+                "label",
+                mark_doc!("/file_picker/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
+            property(
+                "value",
+                mark_doc!("/file_picker/props/value.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "directory",
+                mark_doc!("/file_picker/props/directory.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "required",
+                mark_doc!("/text_field/props/required.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/file_picker/props/onChange.md"),
+                true,
+                [property("value", "".to_string(), true, PropertyType::String)],
+            ),
+        ],
+        children_none(),
+    );
 
     let separator_component = component(
         "separator",
@@ -1135,16 +1470,40 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 component_ref(&action_panel_component, Arity::ZeroOrOne),
             ),
+            event(
+                "onSubmit",
+                mark_doc!("/form/props/onSubmit.md"),
+                true,
+                [property(
+                    "values",
+                    "".to_string(),
+                    false,
+                    PropertyType::Record {
+                        item: Box::new(PropertyType::Union {
+                            items: vec![
+                                PropertyType::String,
+                                PropertyType::Number,
+                                PropertyType::Boolean,
+                                PropertyType::Array {
+                                    item: Box::new(PropertyType::String),
+                                },
+                            ],
+                        }),
+                    },
+                )],
+            ),
         ],
         children_members(
             [
                 member("TextField", &text_field_component, Arity::ZeroOrMore),
                 member("PasswordField", &password_field_component, Arity::ZeroOrMore),
-                // member("TextArea", &text_area_component),
+                member("TextArea", &text_area_component, Arity::ZeroOrMore),
+                member("NumberField", &number_field_component, Arity::ZeroOrMore),
                 member("Checkbox", &checkbox_component, Arity::ZeroOrMore),
                 member("DatePicker", &date_picker_component, Arity::ZeroOrMore),
                 member("Select", &select_component, Arity::ZeroOrMore),
-                // member("MultiSelect", &multi_select_component),
+                member("TagPicker", &tag_picker_component, Arity::ZeroOrMore),
+                member("FilePicker", &file_picker_component, Arity::ZeroOrMore),
                 member("Separator", &separator_component, Arity::ZeroOrMore),
             ],
             [],
@@ -1295,6 +1654,40 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
+    let dropdown_item_component = component(
+        "dropdown_item",
+        mark_doc!("/dropdown_item/description.md"),
+        "DropdownItem",
+        [property(
+            "value",
+            mark_doc!("/dropdown_item/props/value.md"),
+            false,
+            PropertyType::String,
+        )],
+        children_string(mark_doc!("/dropdown_item/props/children.md")),
+    );
+
+    let dropdown_component = component(
+        "dropdown",
+        mark_doc!("/dropdown/description.md"),
+        "Dropdown",
+        [
+            property(
+                "value",
+                mark_doc!("/dropdown/props/value.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/dropdown/props/onChange.md"),
+                true,
+                [property("value", "".to_string(), false, PropertyType::String)],
+            ),
+        ],
+        children_members([member("Item", &dropdown_item_component, Arity::ZeroOrMore)], []),
+    );
+
     let list_item_component = component(
         "list_item",
         mark_doc!("/list_item/description.md"),
@@ -1313,6 +1706,14 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property(
+                "keywords",
+                mark_doc!("/list_item/props/keywords.md"),
+                true,
+                PropertyType::Array {
+                    item: Box::new(PropertyType::String),
+                },
+            ),
             property(
                 "icon",
                 mark_doc!("/list_item/props/icon.md"),
@@ -1335,7 +1736,7 @@ pub fn create_component_model() -> Vec<Component> {
                 },
             ),
         ],
-        children_none(),
+        children_members([], [member("Detail", &detail_component, Arity::ZeroOrOne)]),
     );
 
     let list_section_component = component(
@@ -1376,12 +1777,25 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::Boolean,
             ),
+            property(
+                "searchBarAccessory",
+                mark_doc!("/list/props/searchBarAccessory.md"),
+                true,
+                component_ref(&dropdown_component, Arity::ZeroOrOne),
+            ),
+            property(
+                "filtering",
+                mark_doc!("/list/props/filtering.md"),
+                true,
+                PropertyType::Boolean,
+            ),
             event(
                 "onItemFocusChange",
                 mark_doc!("/list/props/onItemFocusChange.md"),
                 true,
                 [property("itemId", "".to_string(), true, PropertyType::String)],
             ),
+            event("onLoadMore", mark_doc!("/list/props/onLoadMore.md"), true, []),
         ],
         children_members(
             [
@@ -1414,6 +1828,14 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property(
+                "keywords",
+                mark_doc!("/grid_item/props/keywords.md"),
+                true,
+                PropertyType::Array {
+                    item: Box::new(PropertyType::String),
+                },
+            ),
             property(
                 "accessory",
                 mark_doc!("/grid_item/props/accessory.md"),
@@ -1479,12 +1901,25 @@ pub fn create_component_model() -> Vec<Component> {
             ), // TODO default
             // fit
             // inset
+            property(
+                "searchBarAccessory",
+                mark_doc!("/grid/props/searchBarAccessory.md"),
+                true,
+                component_ref(&dropdown_component, Arity::ZeroOrOne),
+            ),
+            property(
+                "filtering",
+                mark_doc!("/grid/props/filtering.md"),
+                true,
+                PropertyType::Boolean,
+            ),
             event(
                 "onItemFocusChange",
                 mark_doc!("/grid/props/onItemFocusChange.md"),
                 true,
                 [property("itemId", "".to_string(), true, PropertyType::String)],
             ),
+            event("onLoadMore", mark_doc!("/grid/props/onLoadMore.md"), true, []),
         ],
         children_members(
             [
@@ -1498,6 +1933,133 @@ pub fn create_component_model() -> Vec<Component> {
         ),
     );
 
+    let table_column_component = component(
+        "table_column",
+        mark_doc!("/table_column/description.md"),
+        "TableColumn",
+        [
+            property(
+                "id",
+                mark_doc!("/table_column/props/id.md"),
+                false,
+                PropertyType::String,
+            ),
+            property(
+                "title",
+                mark_doc!("/table_column/props/title.md"),
+                false,
+                PropertyType::String,
+            ),
+            property(
+                "width",
+                mark_doc!("/table_column/props/width.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "sortable",
+                mark_doc!("/table_column/props/sortable.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+        ],
+        children_none(),
+    );
+
+    let table_cell_component = component(
+        "table_cell",
+        mark_doc!("/table_cell/description.md"),
+        "TableCell",
+        [
+            property(
+                "text",
+                mark_doc!("/table_cell/props/text.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "icon",
+                mark_doc!("/table_cell/props/icon.md"),
+                true,
+                PropertyType::SharedTypeRef {
+                    name: "ImageLike".to_owned(),
+                },
+            ),
+            property(
+                "accessories",
+                mark_doc!("/table_cell/props/accessories.md"),
+                true,
+                PropertyType::Array {
+                    item: Box::new(PropertyType::Union {
+                        items: vec![
+                            component_ref(&accessory_text_component, Arity::ZeroOrMore),
+                            component_ref(&accessory_icon_component, Arity::ZeroOrMore),
+                        ],
+                    }),
+                },
+            ),
+        ],
+        children_none(),
+    );
+
+    let table_row_component = component(
+        "table_row",
+        mark_doc!("/table_row/description.md"),
+        "TableRow",
+        [property(
+            "id",
+            mark_doc!("/table_row/props/id.md"),
+            false,
+            PropertyType::String,
+        )],
+        children_members([member("Cell", &table_cell_component, Arity::ZeroOrMore)], []),
+    );
+
+    let table_component = component(
+        "table",
+        mark_doc!("/table/description.md"),
+        "Table",
+        [
+            property(
+                "actions",
+                mark_doc!("/table/props/actions.md"),
+                true,
+                component_ref(&action_panel_component, Arity::ZeroOrOne),
+            ),
+            property(
+                "isLoading",
+                mark_doc!("/list/props/isLoading.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            event(
+                "onItemFocusChange",
+                mark_doc!("/table/props/onItemFocusChange.md"),
+                true,
+                [property("itemId", "".to_string(), true, PropertyType::String)],
+            ),
+            event(
+                "onSortChange",
+                mark_doc!("/table/props/onSortChange.md"),
+                true,
+                [
+                    property("columnId", "".to_string(), false, PropertyType::String),
+                    property("descending", "".to_string(), false, PropertyType::Boolean),
+                ],
+            ),
+        ],
+        children_members(
+            [
+                member("Column", &table_column_component, Arity::ZeroOrMore),
+                member("Row", &table_row_component, Arity::ZeroOrMore),
+            ],
+            [
+                member("SearchBar", &search_bar_component, Arity::ZeroOrOne),
+                member("EmptyView", &empty_view_component, Arity::ZeroOrOne),
+            ],
+        ),
+    );
+
     let text_part = text_part();
 
     let root = root(&[
@@ -1506,6 +2068,7 @@ pub fn create_component_model() -> Vec<Component> {
         &inline_component,
         &list_component,
         &grid_component,
+        &table_component,
     ]);
 
     // Detail
@@ -1515,6 +2078,7 @@ pub fn create_component_model() -> Vec<Component> {
     // Detail.Content.H1-6
     // Detail.Content.HorizontalBreak
     // Detail.Content.CodeBlock
+    // Detail.Content.Markdown
     // Detail.Metadata
     // Detail.Metadata.TagList
     // Detail.Metadata.TagList.Item
@@ -1567,6 +2131,13 @@ pub fn create_component_model() -> Vec<Component> {
     // Grid.Item
     // Grid.Section
 
+    // Table
+    // Table.Column
+    // Table.Row
+    // Table.Row.Cell
+    // Table.SearchBar
+    // Table.EmptyView
+
     vec![
         text_part,
         action_component,
@@ -1590,18 +2161,22 @@ pub fn create_component_model() -> Vec<Component> {
         h6_component,
         horizontal_break_component,
         code_block_component,
+        markdown_component,
         // code_component,
         paragraph_component,
         content_component,
         detail_component,
         text_field_component,
         password_field_component,
-        // text_area_component,
+        text_area_component,
+        number_field_component,
         checkbox_component,
         date_picker_component,
         select_item_component,
         select_component,
-        // multi_select_component,
+        tag_picker_item_component,
+        tag_picker_component,
+        file_picker_component,
         separator_component,
         form_component,
         inline_separator_component,
@@ -1610,12 +2185,18 @@ pub fn create_component_model() -> Vec<Component> {
         accessory_icon_component,
         accessory_text_component,
         search_bar_component,
+        dropdown_item_component,
+        dropdown_component,
         list_item_component,
         list_section_component,
         list_component,
         grid_item_component,
         grid_section_component,
         grid_component,
+        table_column_component,
+        table_cell_component,
+        table_row_component,
+        table_component,
         root,
     ]
 }
@@ -52,6 +52,9 @@ use crate::theme::GauntletSettingsTheme;
 use crate::views::general::ManagementAppGeneralMsgIn;
 use crate::views::general::ManagementAppGeneralMsgOut;
 use crate::views::general::ManagementAppGeneralState;
+use crate::views::keybindings::ManagementAppKeybindingsMsgIn;
+use crate::views::keybindings::ManagementAppKeybindingsMsgOut;
+use crate::views::keybindings::ManagementAppKeybindingsState;
 use crate::views::plugins::ManagementAppPluginMsgIn;
 use crate::views::plugins::ManagementAppPluginMsgOut;
 use crate::views::plugins::ManagementAppPluginsState;
@@ -79,6 +82,7 @@ struct ManagementAppModel {
     download_info_shown: bool,
     current_settings_view: SettingsView,
     general_state: ManagementAppGeneralState,
+    keybindings_state: ManagementAppKeybindingsState,
     plugins_state: ManagementAppPluginsState,
 }
 
@@ -86,6 +90,7 @@ struct ManagementAppModel {
 pub enum ManagementAppMsg {
     FontLoaded(Result<(), font::Error>),
     General(ManagementAppGeneralMsgIn),
+    Keybindings(ManagementAppKeybindingsMsgIn),
     Plugin(ManagementAppPluginMsgIn),
     SwitchView(SettingsView),
     DownloadStatus { plugins: HashMap<PluginId, DownloadStatus> },
@@ -99,6 +104,7 @@ pub enum ManagementAppMsg {
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum SettingsView {
     General,
+    Keybindings,
     Plugins,
 }
 
@@ -130,6 +136,7 @@ fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
             download_info_shown: false,
             current_settings_view: SettingsView::Plugins,
             general_state: ManagementAppGeneralState::new(backend_api.clone()),
+            keybindings_state: ManagementAppKeybindingsState::new(backend_api.clone()),
             plugins_state: ManagementAppPluginsState::new(backend_api.clone()),
         },
         Task::batch([
@@ -216,14 +223,30 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
                 }
             })
         }
+        ManagementAppMsg::Keybindings(message) => {
+            state.keybindings_state.update(message).map(|msg| {
+                match msg {
+                    ManagementAppKeybindingsMsgOut::Inner(msg) => ManagementAppMsg::Keybindings(msg),
+                    ManagementAppKeybindingsMsgOut::Outer(msg) => msg,
+                }
+            })
+        }
         ManagementAppMsg::FontLoaded(result) => {
             result.expect("unable to load font");
             Task::none()
         }
         ManagementAppMsg::SwitchView(view) => {
-            state.current_settings_view = view;
-
-            Task::none()
+            state.current_settings_view = view.clone();
+
+            match view {
+                // refetch to show up-to-date conflicts with plugin action shortcuts
+                SettingsView::Keybindings => {
+                    Task::done(ManagementAppMsg::Keybindings(
+                        ManagementAppKeybindingsMsgIn::FetchKeybindings,
+                    ))
+                }
+                _ => Task::none(),
+            }
         }
         ManagementAppMsg::HandleBackendError(err) => {
             state.error_view = Some(match err {
@@ -411,6 +434,12 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
 
     let content = match state.current_settings_view {
         SettingsView::General => state.general_state.view().map(|msg| ManagementAppMsg::General(msg)),
+        SettingsView::Keybindings => {
+            state
+                .keybindings_state
+                .view()
+                .map(|msg| ManagementAppMsg::Keybindings(msg))
+        }
         SettingsView::Plugins => state.plugins_state.view().map(|msg| ManagementAppMsg::Plugin(msg)),
     };
 
@@ -449,6 +478,41 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
 
     let general_button: Element<_> = container(general_button).padding(8.0).into();
 
+    let icon_keybindings: Element<_> = value(Bootstrap::KeyboardFill)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_keybindings: Element<_> = text("Keybindings")
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let keybindings_button: Element<_> = column(vec![icon_keybindings, text_keybindings])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let keybindings_button: Element<_> = button(keybindings_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Keybindings))
+        .height(Length::Fill)
+        .width(80)
+        .class(
+            if state.current_settings_view == SettingsView::Keybindings {
+                ButtonStyle::ViewSwitcherSelected
+            } else {
+                ButtonStyle::ViewSwitcher
+            },
+        )
+        .into();
+
+    let keybindings_button: Element<_> = container(keybindings_button).padding(8.0).into();
+
     let icon_plugins: Element<_> = value(Bootstrap::PuzzleFill)
         .font(BOOTSTRAP_FONT)
         .height(Length::Fill)
@@ -484,7 +548,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
 
     let plugins_button: Element<_> = container(plugins_button).padding(8.0).into();
 
-    let top_bar_buttons: Element<_> = row(vec![general_button, plugins_button]).into();
+    let top_bar_buttons: Element<_> = row(vec![general_button, keybindings_button, plugins_button]).into();
 
     let top_bar_buttons: Element<_> = container(top_bar_buttons)
         .width(Length::Fill)
@@ -6,6 +6,7 @@ use gauntlet_common::rpc::backend_api::BackendForSettingsApiProxy;
 use gauntlet_utils::channel::RequestResult;
 use iced::alignment;
 use iced::alignment::Horizontal;
+use iced::widget::button;
 use iced::widget::column;
 use iced::widget::container;
 use iced::widget::pick_list;
@@ -21,6 +22,7 @@ use iced::Task;
 use crate::components::shortcut_selector::render_shortcut_error;
 use crate::components::shortcut_selector::shortcut_selector;
 use crate::components::shortcut_selector::ShortcutData;
+use crate::theme::button::ButtonStyle;
 use crate::theme::container::ContainerStyle;
 use crate::theme::Element;
 use crate::ui::ManagementAppMsg;
@@ -37,6 +39,7 @@ pub enum ManagementAppGeneralMsgIn {
     ShortcutCaptured(Option<PhysicalShortcut>),
     ThemeChanged(SettingsTheme),
     WindowPositionModeChanged(WindowPositionMode),
+    ClearSearchHistory,
     HandleShortcutResponse {
         shortcut: Option<PhysicalShortcut>,
         shortcut_error: Option<String>,
@@ -149,6 +152,20 @@ impl ManagementAppGeneralState {
                     },
                 )
             }
+            ManagementAppGeneralMsgIn::ClearSearchHistory => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        backend_api.clear_search_history().await?;
+
+                        Ok(())
+                    },
+                    |result| {
+                        handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Outer(ManagementAppMsg::Noop))
+                    },
+                )
+            }
             ManagementAppGeneralMsgIn::HandleShortcutResponse {
                 shortcut,
                 shortcut_error,
@@ -186,11 +203,13 @@ impl ManagementAppGeneralState {
 
         let mut content = vec![global_shortcut_field, theme_field];
 
-        #[cfg(target_os = "macos")]
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
         {
             content.push(self.window_position_mode_field())
         }
 
+        content.push(self.search_history_field());
+
         let content: Element<_> = column(content).into();
 
         let content: Element<_> = container(content).width(Length::Fill).into();
@@ -242,9 +261,20 @@ impl ManagementAppGeneralState {
         theme_field
     }
 
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     fn window_position_mode_field(&self) -> Element<ManagementAppGeneralMsgIn> {
+        #[cfg(target_os = "macos")]
         let items = [WindowPositionMode::Static, WindowPositionMode::ActiveMonitor];
 
+        // only applies to x11, on wayland compositor decides where the window is placed
+        #[cfg(target_os = "linux")]
+        let items = [
+            WindowPositionMode::Static,
+            WindowPositionMode::CursorMonitor,
+            WindowPositionMode::FocusedWindowMonitor,
+            WindowPositionMode::RememberPerMonitor,
+        ];
+
         let field: Element<_> = pick_list(items, Some(self.window_position_mode.clone()), move |item| {
             ManagementAppGeneralMsgIn::WindowPositionModeChanged(item)
         })
@@ -257,6 +287,28 @@ impl ManagementAppGeneralState {
         field
     }
 
+    fn search_history_field(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let clear_text: Element<_> = text("Clear").into();
+
+        let clear_text_container: Element<_> = container(clear_text)
+            .width(Length::Fill)
+            .align_y(Alignment::Center)
+            .align_x(Alignment::Center)
+            .into();
+
+        let field: Element<_> = button(clear_text_container)
+            .width(Length::Fill)
+            .class(ButtonStyle::Destructive)
+            .on_press(ManagementAppGeneralMsgIn::ClearSearchHistory)
+            .into();
+
+        let field: Element<_> = container(field).width(Length::Fill).into();
+
+        let field = self.view_field("Search History", field, None);
+
+        field
+    }
+
     fn view_field<'a>(
         &'a self,
         label: &'a str,
@@ -0,0 +1,158 @@
+use gauntlet_common::model::SettingsKeybinding;
+use gauntlet_common::model::SettingsKeybindingAction;
+use gauntlet_common::rpc::backend_api::BackendForSettingsApi;
+use gauntlet_common::rpc::backend_api::BackendForSettingsApiProxy;
+use gauntlet_utils::channel::RequestResult;
+use iced::alignment::Horizontal;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::row;
+use iced::widget::scrollable;
+use iced::widget::text;
+use iced::widget::text::Shaping;
+use iced::Alignment;
+use iced::Length;
+use iced::Task;
+
+use crate::components::shortcut_selector::render_shortcut;
+use crate::components::shortcut_selector::ShortcutData;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use crate::ui::ManagementAppMsg;
+
+pub struct ManagementAppKeybindingsState {
+    backend_api: Option<BackendForSettingsApiProxy>,
+    keybindings: Vec<SettingsKeybinding>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppKeybindingsMsgIn {
+    FetchKeybindings,
+    KeybindingsFetched(Vec<SettingsKeybinding>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppKeybindingsMsgOut {
+    Inner(ManagementAppKeybindingsMsgIn),
+    Outer(ManagementAppMsg),
+}
+
+impl ManagementAppKeybindingsState {
+    pub fn new(backend_api: Option<BackendForSettingsApiProxy>) -> Self {
+        Self {
+            backend_api,
+            keybindings: vec![],
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppKeybindingsMsgIn) -> Task<ManagementAppKeybindingsMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => return Task::none(),
+        };
+
+        match message {
+            ManagementAppKeybindingsMsgIn::FetchKeybindings => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        let keybindings = backend_api.keybindings().await?;
+
+                        Ok(keybindings)
+                    },
+                    |result| {
+                        handle_backend_error(result, |keybindings| {
+                            ManagementAppKeybindingsMsgOut::Inner(ManagementAppKeybindingsMsgIn::KeybindingsFetched(
+                                keybindings,
+                            ))
+                        })
+                    },
+                )
+            }
+            ManagementAppKeybindingsMsgIn::KeybindingsFetched(keybindings) => {
+                self.keybindings = keybindings;
+
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppKeybindingsMsgIn> {
+        let description: Element<_> = text("Keybindings can be changed in \"keybindings\" section of config file")
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .align_x(Horizontal::Center)
+            .width(Length::Fill)
+            .into();
+
+        let description: Element<_> = container(description).padding(12).into();
+
+        let mut content = vec![description];
+
+        for keybinding in &self.keybindings {
+            content.push(self.keybinding_field(keybinding));
+        }
+
+        let content: Element<_> = column(content).into();
+
+        let content: Element<_> = scrollable(content).width(Length::Fill).into();
+
+        let content: Element<_> = container(content).width(Length::Fill).into();
+
+        content
+    }
+
+    fn keybinding_field<'a>(
+        &'a self,
+        keybinding: &'a SettingsKeybinding,
+    ) -> Element<'a, ManagementAppKeybindingsMsgIn> {
+        let label: Element<_> = text(keybinding.action.to_string())
+            .shaping(Shaping::Advanced)
+            .align_x(Horizontal::Right)
+            .width(Length::Fill)
+            .into();
+
+        let label: Element<_> = container(label).width(Length::FillPortion(3)).padding(4).into();
+
+        let error = if keybinding.conflicts.is_empty() {
+            None
+        } else {
+            // plugin action shortcuts are shadowed by launcher keybindings
+            let message = match keybinding.action {
+                SettingsKeybindingAction::Launcher(_) => "Plugin actions with the same shortcut will not work",
+                SettingsKeybindingAction::QuickPick(_) => {
+                    "Plugin actions with the same shortcut will not work in main view"
+                }
+            };
+
+            Some(format!("{}: {}", message, keybinding.conflicts.join(", ")))
+        };
+
+        let shortcut = render_shortcut(
+            &ShortcutData {
+                shortcut: Some(keybinding.shortcut.clone()),
+                error,
+            },
+            true,
+        );
+
+        let shortcut: Element<_> = container(shortcut).width(Length::FillPortion(4)).padding(4).into();
+
+        let content = vec![label, shortcut];
+
+        let row: Element<_> = row(content).align_y(Alignment::Center).padding(12).into();
+
+        row
+    }
+}
+
+fn handle_backend_error<T>(
+    result: RequestResult<T>,
+    convert: impl FnOnce(T) -> ManagementAppKeybindingsMsgOut,
+) -> ManagementAppKeybindingsMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => ManagementAppKeybindingsMsgOut::Outer(ManagementAppMsg::HandleBackendError(err)),
+    }
+}
@@ -1,2 +1,3 @@
 pub mod general;
+pub mod keybindings;
 pub mod plugins;
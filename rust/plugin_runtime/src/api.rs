@@ -6,8 +6,12 @@ use gauntlet_utils::channel::RequestResult;
 use gauntlet_utils_macros::boundary_gen;
 
 use crate::model::JsClipboardData;
+use crate::model::JsClipboardHistoryEntry;
+use crate::model::JsConfirmAlert;
 use crate::model::JsGeneratedSearchItem;
 use crate::model::JsPreferenceUserData;
+use crate::model::JsSnippet;
+use crate::model::JsToast;
 use crate::JsUiRenderLocation;
 
 #[allow(async_fn_in_trait)]
@@ -32,9 +36,20 @@ pub trait BackendForPluginRuntimeApi {
     async fn clipboard_write(&self, data: JsClipboardData) -> RequestResult<()>;
     async fn clipboard_write_text(&self, data: String) -> RequestResult<()>;
     async fn clipboard_clear(&self) -> RequestResult<()>;
+    async fn clipboard_history_list(&self, query: String) -> RequestResult<Vec<JsClipboardHistoryEntry>>;
+    async fn clipboard_history_copy(&self, id: i64) -> RequestResult<()>;
+    async fn clipboard_history_set_pinned(&self, id: i64, pinned: bool) -> RequestResult<()>;
+    async fn clipboard_history_remove(&self, id: i64) -> RequestResult<()>;
+    async fn snippets_list(&self, query: String) -> RequestResult<Vec<JsSnippet>>;
+    async fn snippet_save(&self, id: Option<i64>, name: String, text: String) -> RequestResult<i64>;
+    async fn snippet_remove(&self, id: i64) -> RequestResult<()>;
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> RequestResult<()>;
     async fn ui_show_hud(&self, display: String) -> RequestResult<()>;
+    async fn ui_show_toast(&self, toast: JsToast) -> RequestResult<()>;
+    async fn ui_hide_toast(&self, toast_id: u32) -> RequestResult<()>;
+    async fn ui_show_confirm_alert(&self, alert: JsConfirmAlert) -> RequestResult<()>;
     async fn ui_hide_window(&self) -> RequestResult<()>;
+    async fn ui_paste_to_active_window(&self, text: String, cursor_offset_from_end: u32) -> RequestResult<()>;
     async fn ui_get_action_id_for_shortcut(
         &self,
         entrypoint_id: EntrypointId,
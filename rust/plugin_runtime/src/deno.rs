@@ -62,9 +62,27 @@ use crate::plugin_data::PluginData;
 use crate::plugins::applications::current_os;
 use crate::plugins::applications::wayland;
 use crate::plugins::applications::ApplicationContext;
+use crate::plugins::clipboard_history::clipboard_history_copy;
+use crate::plugins::clipboard_history::clipboard_history_list;
+use crate::plugins::clipboard_history::clipboard_history_remove;
+use crate::plugins::clipboard_history::clipboard_history_set_pinned;
+use crate::plugins::emoji::emoji_mark_used;
+use crate::plugins::emoji::emoji_search;
+use crate::plugins::emoji::EmojiContext;
+use crate::plugins::file_search::file_search;
+use crate::plugins::file_search::file_search_open;
+use crate::plugins::file_search::file_search_refresh;
+use crate::plugins::file_search::file_search_reveal;
+use crate::plugins::file_search::FileSearchContext;
+use crate::plugins::numbat::numbat_clear_history;
+use crate::plugins::numbat::numbat_history;
+use crate::plugins::numbat::numbat_save_history;
 use crate::plugins::numbat::run_numbat;
 use crate::plugins::numbat::NumbatContext;
 use crate::plugins::settings::open_settings;
+use crate::plugins::snippets::snippet_remove;
+use crate::plugins::snippets::snippet_save;
+use crate::plugins::snippets::snippets_list;
 use crate::preferences::entrypoint_preferences_required;
 use crate::preferences::get_entrypoint_preferences;
 use crate::preferences::get_plugin_preferences;
@@ -72,14 +90,18 @@ use crate::preferences::plugin_preferences_required;
 use crate::search::reload_search_index;
 use crate::ui::clear_inline_view;
 use crate::ui::fetch_action_id_for_shortcut;
+use crate::ui::hide_toast;
 use crate::ui::hide_window;
 use crate::ui::op_component_model;
 use crate::ui::op_entrypoint_names;
 use crate::ui::op_inline_view_entrypoint_id;
 use crate::ui::op_react_replace_view;
+use crate::ui::paste_to_active_window;
+use crate::ui::show_confirm_alert;
 use crate::ui::show_hud;
 use crate::ui::show_plugin_error_view;
 use crate::ui::show_preferences_required_view;
+use crate::ui::show_toast;
 use crate::ui::update_loading_bar;
 use crate::JsPluginCode;
 
@@ -311,7 +333,11 @@ deno_core::extension!(
         op_component_model,
         fetch_action_id_for_shortcut,
         show_hud,
+        show_toast,
+        hide_toast,
+        show_confirm_alert,
         hide_window,
+        paste_to_active_window,
         update_loading_bar,
 
         // preferences
@@ -392,6 +418,9 @@ deno_core::extension!(
     ops = [
         // plugins numbat
         run_numbat,
+        numbat_save_history,
+        numbat_history,
+        numbat_clear_history,
 
         // plugins applications
         current_os,
@@ -399,6 +428,27 @@ deno_core::extension!(
 
         // plugins settings
         open_settings,
+
+        // plugins clipboard history
+        clipboard_history_list,
+        clipboard_history_copy,
+        clipboard_history_set_pinned,
+        clipboard_history_remove,
+
+        // plugins emoji
+        emoji_search,
+        emoji_mark_used,
+
+        // plugins snippets
+        snippets_list,
+        snippet_save,
+        snippet_remove,
+
+        // plugins file search
+        file_search_refresh,
+        file_search,
+        file_search_open,
+        file_search_reveal,
     ],
     esm_entry_point = "ext:gauntlet/internal-all/bootstrap.js",
     esm = [
@@ -408,10 +458,14 @@ deno_core::extension!(
     options = {
         numbat_context: NumbatContext,
         application_context: ApplicationContext,
+        file_search_context: FileSearchContext,
+        emoji_context: EmojiContext,
     },
     state = |state, options| {
         state.put(options.numbat_context);
         state.put(options.application_context);
+        state.put(options.file_search_context);
+        state.put(options.emoji_context);
     },
 );
 
@@ -513,6 +567,10 @@ pub async fn start_js_runtime(
         dev::gauntlet_esm::init_ops_and_esm()
     };
 
+    let plugin_data_dir = PathBuf::from(&init.plugin_data_dir);
+    let plugin_cache_dir = PathBuf::from(&init.plugin_cache_dir);
+    let calculator_file = PathBuf::from(&init.calculator_file);
+
     let mut extensions = vec![
         gauntlet::init_ops(
             EventReceiver::new(event_stream),
@@ -534,8 +592,10 @@ pub async fn start_js_runtime(
 
     if init.plugin_id.to_string() == "bundled://gauntlet" {
         extensions.push(gauntlet_internal_all::init_ops_and_esm(
-            NumbatContext::new(),
+            NumbatContext::new(plugin_data_dir.clone(), plugin_cache_dir, calculator_file),
             ApplicationContext::new()?,
+            FileSearchContext::new(),
+            EmojiContext::new(plugin_data_dir),
         ));
 
         #[cfg(target_os = "macos")]
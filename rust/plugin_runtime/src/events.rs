@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::rc::Rc;
 
@@ -57,6 +58,17 @@ pub enum JsEvent {
         #[serde(rename = "modifierMeta")]
         modifier_meta: bool,
     },
+    ToastActionEvent {
+        #[serde(rename = "toastId")]
+        toast_id: u32,
+        #[serde(rename = "actionIndex")]
+        action_index: usize,
+    },
+    ConfirmAlertEvent {
+        #[serde(rename = "alertId")]
+        alert_id: u32,
+        confirmed: bool,
+    },
     OpenInlineView {
         #[serde(rename = "text")]
         text: String,
@@ -77,6 +89,8 @@ pub enum JsUiPropertyValue {
     String { value: String },
     Number { value: f64 },
     Bool { value: bool },
+    Array { value: Vec<JsUiPropertyValue> },
+    Object { value: HashMap<String, JsUiPropertyValue> },
     Undefined,
 }
 
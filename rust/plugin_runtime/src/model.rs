@@ -43,6 +43,7 @@ pub struct JsInit {
     pub entrypoint_names: HashMap<EntrypointId, String>,
     pub dev_plugin: bool,
     pub home_dir: String,
+    pub calculator_file: String,
     pub local_storage_dir: String,
     pub plugin_cache_dir: String,
     pub plugin_data_dir: String,
@@ -92,6 +93,8 @@ pub struct JsGeneratedSearchItem {
     pub entrypoint_icon: Option<Vec<u8>>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
+    pub entrypoint_application: bool,
 }
 
 impl fmt::Debug for JsGeneratedSearchItem {
@@ -104,6 +107,8 @@ impl fmt::Debug for JsGeneratedSearchItem {
             .field("entrypoint_uuid", &self.entrypoint_uuid)
             .field("entrypoint_actions", &self.entrypoint_actions)
             .field("entrypoint_accessories", &self.entrypoint_accessories)
+            .field("entrypoint_keywords", &self.entrypoint_keywords)
+            .field("entrypoint_application", &self.entrypoint_application)
             .finish()
     }
 }
@@ -117,6 +122,8 @@ pub struct DenoOutGeneratedSearchItem {
     pub entrypoint_icon: Option<ToJsBuffer>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
+    pub entrypoint_application: bool,
 }
 
 #[derive(Deserialize)]
@@ -128,6 +135,8 @@ pub struct DenoInGeneratedSearchItem {
     pub entrypoint_icon: Option<JsBuffer>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    pub entrypoint_keywords: Vec<String>,
+    pub entrypoint_application: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, Encode, Decode)]
@@ -184,3 +193,57 @@ pub struct DenoInClipboardData {
     pub text_data: Option<String>,
     pub png_data: Option<JsBuffer>,
 }
+
+#[derive(Debug, Serialize, Encode, Decode)]
+pub struct JsClipboardHistoryEntry {
+    pub id: i64,
+    pub text_data: Option<String>,
+    // image itself is not sent, only its size
+    pub image_width: Option<u32>,
+    pub image_height: Option<u32>,
+    pub pinned: bool,
+    pub created_at: f64,
+}
+
+#[derive(Debug, Serialize, Encode, Decode)]
+pub struct JsSnippet {
+    pub id: i64,
+    pub name: String,
+    pub text: String,
+    pub updated_at: f64,
+}
+
+#[derive(Debug, Deserialize, Encode, Decode)]
+pub struct JsToast {
+    pub id: u32,
+    pub style: JsToastStyle,
+    pub title: String,
+    pub message: Option<String>,
+    // only titles of actions are sent, handlers stay in js and are called by toast id and action index
+    pub primary_action: Option<String>,
+    pub secondary_action: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Encode, Decode)]
+pub enum JsToastStyle {
+    Success,
+    Failure,
+    Animated,
+}
+
+#[derive(Debug, Deserialize, Encode, Decode)]
+pub struct JsConfirmAlert {
+    pub id: u32,
+    pub title: String,
+    pub message: Option<String>,
+    pub icon: Option<Icons>,
+    pub primary_action: String,
+    pub primary_action_style: JsConfirmAlertActionStyle,
+    pub dismiss_action: String,
+}
+
+#[derive(Debug, Deserialize, Encode, Decode)]
+pub enum JsConfirmAlertActionStyle {
+    Default,
+    Destructive,
+}
@@ -0,0 +1,515 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use freedesktop_entry_parser::parse_entry;
+use gauntlet_common::detached_process::CommandExt;
+use zbus::zvariant::ObjectPath;
+use zbus::zvariant::Value;
+use zbus::Connection;
+
+// checked in order when terminal is not configured, paired with argument that precedes the command
+const KNOWN_TERMINALS: &[(&str, &[&str])] = &[
+    ("x-terminal-emulator", &["-e"]),
+    ("gnome-terminal", &["--"]),
+    ("konsole", &["-e"]),
+    ("xfce4-terminal", &["-x"]),
+    ("kitty", &[]),
+    ("alacritty", &["-e"]),
+    ("foot", &[]),
+    ("wezterm", &["start", "--"]),
+    ("xterm", &["-e"]),
+];
+
+#[zbus::proxy(interface = "org.freedesktop.Application")]
+trait FreedesktopApplication {
+    fn activate(&self, platform_data: HashMap<&str, Value<'_>>) -> zbus::Result<()>;
+
+    fn activate_action(
+        &self,
+        action_name: &str,
+        parameter: Vec<Value<'_>>,
+        platform_data: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<()>;
+}
+
+/// Data from .desktop file needed to launch application
+pub struct LaunchEntry {
+    pub id: String,
+    pub name: String,
+    pub icon: Option<String>,
+    pub startup_wm_class: Option<String>,
+    pub startup_notify: bool,
+    desktop_file_path: String,
+    exec: Option<String>,
+    action_execs: HashMap<String, String>,
+    dbus_activatable: bool,
+    terminal: bool,
+    working_dir: Option<String>,
+}
+
+impl LaunchEntry {
+    pub fn read(desktop_file_id: &str, desktop_file_path: &Path) -> anyhow::Result<Self> {
+        let entry = parse_entry(desktop_file_path)
+            .with_context(|| format!("Unable to parse .desktop file at path {:?}", desktop_file_path))?;
+
+        let main_section = entry.section("Desktop Entry");
+
+        let is_true = |key: &str| main_section.attr(key).map(|val| val == "true").unwrap_or(false);
+
+        let action_execs = main_section
+            .attr("Actions")
+            .unwrap_or_default()
+            .split(';')
+            .filter(|id| !id.is_empty())
+            .filter_map(|id| {
+                let exec = entry.section(format!("Desktop Action {}", id)).attr("Exec")?;
+
+                Some((id.to_string(), exec.to_string()))
+            })
+            .collect();
+
+        Ok(Self {
+            id: desktop_file_id.to_string(),
+            name: main_section.attr("Name").unwrap_or(desktop_file_id).to_string(),
+            icon: main_section.attr("Icon").map(|s| s.to_string()),
+            startup_wm_class: main_section.attr("StartupWMClass").map(|s| s.to_string()),
+            startup_notify: is_true("StartupNotify"),
+            desktop_file_path: desktop_file_path
+                .to_str()
+                .expect("non-utf8 paths are not supported")
+                .to_string(),
+            exec: main_section.attr("Exec").map(|s| s.to_string()),
+            action_execs,
+            dbus_activatable: is_true("DBusActivatable"),
+            terminal: is_true("Terminal"),
+            working_dir: main_section.attr("Path").map(|s| s.to_string()),
+        })
+    }
+}
+
+/// `startup_id` is passed to launched application both as X11 startup notification id and as wayland activation token
+pub async fn open_application(
+    entry: &LaunchEntry,
+    action_id: Option<&str>,
+    terminal_command: Option<&str>,
+    startup_id: Option<String>,
+) -> anyhow::Result<()> {
+    if entry.dbus_activatable {
+        match activate_dbus(entry, action_id, startup_id.as_deref()).await {
+            Ok(()) => return Ok(()),
+            Err(err) => {
+                // applications are required to still have Exec key as a fallback
+                tracing::warn!(
+                    "Unable to activate {:?} using D-Bus, using Exec instead: {:?}",
+                    entry.id,
+                    err
+                );
+            }
+        }
+    }
+
+    let exec = match action_id {
+        None => entry.exec.as_deref(),
+        Some(action_id) => entry.action_execs.get(action_id).map(|exec| exec.as_str()),
+    };
+
+    let exec = exec.ok_or_else(|| anyhow!("Exec key not found in {:?}", entry.desktop_file_path))?;
+
+    let fields = ExecFields {
+        name: &entry.name,
+        icon: entry.icon.as_deref(),
+        desktop_file_path: &entry.desktop_file_path,
+    };
+
+    for mut args in expand_exec(exec, &fields, &[])? {
+        if entry.terminal {
+            let mut terminal_args = terminal_args(terminal_command)?;
+            terminal_args.append(&mut args);
+            args = terminal_args;
+        }
+
+        let (program, args) = args
+            .split_first()
+            .ok_or_else(|| anyhow!("Exec key is empty in {:?}", entry.desktop_file_path))?;
+
+        let mut command = Command::new(program);
+        command.args(args);
+
+        if let Some(working_dir) = &entry.working_dir {
+            command.current_dir(working_dir);
+        }
+
+        if let Some(startup_id) = &startup_id {
+            command.env("DESKTOP_STARTUP_ID", startup_id);
+            command.env("XDG_ACTIVATION_TOKEN", startup_id);
+        }
+
+        command
+            .spawn_detached()
+            .with_context(|| format!("Unable to launch {:?}", program))?;
+    }
+
+    Ok(())
+}
+
+// https://specifications.freedesktop.org/desktop-entry-spec/latest/dbus.html
+async fn activate_dbus(entry: &LaunchEntry, action_id: Option<&str>, startup_id: Option<&str>) -> anyhow::Result<()> {
+    let connection = Connection::session().await?;
+
+    let object_path = format!("/{}", entry.id.replace('.', "/").replace('-', "_"));
+
+    let application = FreedesktopApplicationProxy::builder(&connection)
+        .destination(entry.id.as_str())?
+        .path(ObjectPath::try_from(object_path)?)?
+        .build()
+        .await?;
+
+    let mut platform_data = HashMap::new();
+
+    if let Some(startup_id) = startup_id {
+        platform_data.insert("desktop-startup-id", Value::from(startup_id));
+        platform_data.insert("activation-token", Value::from(startup_id));
+    }
+
+    match action_id {
+        None => application.activate(platform_data).await?,
+        Some(action_id) => application.activate_action(action_id, vec![], platform_data).await?,
+    }
+
+    Ok(())
+}
+
+/// Values used to expand field codes which do not depend on launched files
+pub struct ExecFields<'a> {
+    pub name: &'a str,
+    pub icon: Option<&'a str>,
+    pub desktop_file_path: &'a str,
+}
+
+/// Splits Exec value into arguments and expands field codes.
+///
+/// `targets` are file paths or URIs, if Exec only accepts single one (%f or %u)
+/// but multiple are given, one command is returned per target
+pub fn expand_exec(exec: &str, fields: &ExecFields, targets: &[String]) -> anyhow::Result<Vec<Vec<String>>> {
+    let args = split_exec(exec)?;
+
+    let single_target = args.iter().any(|arg| arg.contains("%f") || arg.contains("%u"));
+
+    if single_target && targets.len() > 1 {
+        targets
+            .iter()
+            .map(|target| expand_args(&args, fields, std::slice::from_ref(target)))
+            .collect()
+    } else {
+        Ok(vec![expand_args(&args, fields, targets)?])
+    }
+}
+
+fn expand_args(args: &[String], fields: &ExecFields, targets: &[String]) -> anyhow::Result<Vec<String>> {
+    let mut result = vec![];
+
+    for arg in args {
+        // codes expanding to multiple arguments are only allowed as a standalone argument
+        match arg.as_str() {
+            "%F" => {
+                result.extend(targets.iter().map(|target| target_to_path(target)));
+                continue;
+            }
+            "%U" => {
+                result.extend(targets.iter().cloned());
+                continue;
+            }
+            "%i" => {
+                if let Some(icon) = fields.icon {
+                    result.push("--icon".to_string());
+                    result.push(icon.to_string());
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        let mut expanded = String::new();
+        let mut only_field_codes = !arg.is_empty();
+        let mut chars = arg.chars();
+
+        while let Some(char) = chars.next() {
+            if char != '%' {
+                expanded.push(char);
+                only_field_codes = false;
+                continue;
+            }
+
+            match chars.next() {
+                Some('%') => {
+                    expanded.push('%');
+                    only_field_codes = false;
+                }
+                Some('f') => {
+                    if let Some(target) = targets.first() {
+                        expanded.push_str(&target_to_path(target));
+                    }
+                }
+                Some('u') => {
+                    if let Some(target) = targets.first() {
+                        expanded.push_str(target);
+                    }
+                }
+                Some('c') => expanded.push_str(fields.name),
+                Some('k') => expanded.push_str(fields.desktop_file_path),
+                // deprecated
+                Some('d' | 'D' | 'n' | 'N' | 'v' | 'm') => {}
+                Some(code @ ('F' | 'U' | 'i')) => {
+                    return Err(anyhow!("Field code %{} has to be a separate argument: {:?}", code, arg));
+                }
+                Some(code) => return Err(anyhow!("Unknown field code %{} in argument: {:?}", code, arg)),
+                None => return Err(anyhow!("Unfinished field code in argument: {:?}", arg)),
+            }
+        }
+
+        // argument that only consisted of field codes which expanded to nothing is removed
+        if !(only_field_codes && expanded.is_empty()) {
+            result.push(expanded);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Splits Exec value into arguments according to quoting rules of desktop entry spec
+fn split_exec(exec: &str) -> anyhow::Result<Vec<String>> {
+    let mut args = vec![];
+    let mut current: Option<String> = None;
+    let mut chars = exec.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            ' ' | '\t' => {
+                if let Some(arg) = current.take() {
+                    args.push(arg);
+                }
+            }
+            '"' => {
+                let arg = current.get_or_insert_with(String::new);
+
+                loop {
+                    match chars.next() {
+                        None => return Err(anyhow!("Unterminated quote in Exec: {:?}", exec)),
+                        Some('"') => break,
+                        Some('\\') => {
+                            match chars.next() {
+                                Some(escaped @ ('"' | '`' | '$' | '\\')) => arg.push(escaped),
+                                Some(other) => {
+                                    arg.push('\\');
+                                    arg.push(other);
+                                }
+                                None => return Err(anyhow!("Unterminated quote in Exec: {:?}", exec)),
+                            }
+                        }
+                        Some(other) => arg.push(other),
+                    }
+                }
+            }
+            other => current.get_or_insert_with(String::new).push(other),
+        }
+    }
+
+    if let Some(arg) = current.take() {
+        args.push(arg);
+    }
+
+    Ok(args)
+}
+
+fn target_to_path(target: &str) -> String {
+    match target.strip_prefix("file://") {
+        Some(path) => percent_decode(path),
+        None => target.to_string(),
+    }
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let decoded = match (bytes[index], bytes.get(index + 1..index + 3)) {
+            (b'%', Some(hex)) => {
+                std::str::from_utf8(hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            }
+            _ => None,
+        };
+
+        match decoded {
+            Some(byte) => {
+                result.push(byte);
+                index += 3;
+            }
+            None => {
+                result.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&result).to_string()
+}
+
+fn terminal_args(terminal_command: Option<&str>) -> anyhow::Result<Vec<String>> {
+    if let Some(terminal_command) = terminal_command.filter(|command| !command.trim().is_empty()) {
+        return split_exec(terminal_command);
+    }
+
+    if let Ok(terminal) = std::env::var("TERMINAL") {
+        if find_executable(&terminal).is_some() {
+            return Ok(vec![terminal, "-e".to_string()]);
+        }
+    }
+
+    KNOWN_TERMINALS
+        .iter()
+        .find(|(program, _)| find_executable(program).is_some())
+        .map(|(program, args)| {
+            std::iter::once(*program)
+                .chain(args.iter().copied())
+                .map(|arg| arg.to_string())
+                .collect()
+        })
+        .ok_or_else(|| anyhow!("Unable to find terminal emulator to launch application"))
+}
+
+/// Absolute paths are checked directly, otherwise program is looked up in PATH
+pub fn find_executable(program: &str) -> Option<PathBuf> {
+    let path = Path::new(program);
+
+    if path.is_absolute() {
+        return path.is_file().then(|| path.to_path_buf());
+    }
+
+    let paths = std::env::var_os("PATH")?;
+
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(program))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELDS: ExecFields = ExecFields {
+        name: "Text Editor",
+        icon: Some("text-editor"),
+        desktop_file_path: "/usr/share/applications/editor.desktop",
+    };
+
+    fn expand(exec: &str, targets: &[&str]) -> Vec<Vec<String>> {
+        let targets = targets.iter().map(|target| target.to_string()).collect::<Vec<_>>();
+
+        expand_exec(exec, &FIELDS, &targets).unwrap()
+    }
+
+    #[test]
+    fn test_no_targets_removes_file_codes() {
+        assert_eq!(expand("editor %f", &[]), vec![vec!["editor"]]);
+        assert_eq!(expand("editor %F", &[]), vec![vec!["editor"]]);
+        assert_eq!(expand("editor %u", &[]), vec![vec!["editor"]]);
+        assert_eq!(
+            expand("editor %U --new-window", &[]),
+            vec![vec!["editor", "--new-window"]]
+        );
+    }
+
+    #[test]
+    fn test_file_list() {
+        assert_eq!(
+            expand("editor %F", &["/tmp/a", "file:///tmp/b%20c"]),
+            vec![vec!["editor", "/tmp/a", "/tmp/b c"]]
+        );
+    }
+
+    #[test]
+    fn test_url_list() {
+        assert_eq!(
+            expand("browser %U", &["https://example.com", "file:///tmp/a"]),
+            vec![vec!["browser", "https://example.com", "file:///tmp/a"]]
+        );
+    }
+
+    #[test]
+    fn test_single_target_launches_once_per_target() {
+        assert_eq!(
+            expand("editor --file=%f", &["/tmp/a", "/tmp/b"]),
+            vec![vec!["editor", "--file=/tmp/a"], vec!["editor", "--file=/tmp/b"]]
+        );
+        assert_eq!(
+            expand("browser %u", &["https://a.example", "https://b.example"]),
+            vec![
+                vec!["browser", "https://a.example"],
+                vec!["browser", "https://b.example"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_icon() {
+        assert_eq!(expand("editor %i", &[]), vec![vec!["editor", "--icon", "text-editor"]]);
+
+        let fields = ExecFields { icon: None, ..FIELDS };
+
+        assert_eq!(expand_exec("editor %i", &fields, &[]).unwrap(), vec![vec!["editor"]]);
+    }
+
+    #[test]
+    fn test_name_and_location() {
+        assert_eq!(
+            expand("editor --class %c --desktop=%k", &[]),
+            vec![vec![
+                "editor",
+                "--class",
+                "Text Editor",
+                "--desktop=/usr/share/applications/editor.desktop"
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_percent_and_deprecated_codes() {
+        assert_eq!(expand("printf 100%% %d", &[]), vec![vec!["printf", "100%"]]);
+    }
+
+    #[test]
+    fn test_quoting() {
+        assert_eq!(
+            expand(r#""/opt/my app/run" --title "say \"hi\"" "%f""#, &["/tmp/a"]),
+            vec![vec!["/opt/my app/run", "--title", "say \"hi\"", "/tmp/a"]]
+        );
+        assert_eq!(expand(r#"run "" arg"#, &[]), vec![vec!["run", "", "arg"]]);
+    }
+
+    #[test]
+    fn test_configured_terminal() {
+        assert_eq!(
+            terminal_args(Some("kitty --hold -e")).unwrap(),
+            vec!["kitty", "--hold", "-e"]
+        );
+        assert_eq!(
+            terminal_args(Some(r#""/opt/my term/run" -x"#)).unwrap(),
+            vec!["/opt/my term/run", "-x"]
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(expand_exec("editor --files=%F", &FIELDS, &[]).is_err());
+        assert!(expand_exec("editor %x", &FIELDS, &[]).is_err());
+        assert!(expand_exec("editor \"unterminated", &FIELDS, &[]).is_err());
+    }
+}
@@ -5,15 +5,17 @@ use std::fs::Metadata;
 use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::Duration;
 
+use anyhow::anyhow;
 use deno_core::op2;
 use deno_core::OpState;
 use deno_core::ToJsBuffer;
 use freedesktop_entry_parser::parse_entry;
 use freedesktop_icons::lookup;
-use gauntlet_common::detached_process::CommandExt;
 use image::imageops::FilterType;
 use image::ImageFormat;
+use serde::Deserialize;
 use tokio::sync::mpsc::Sender;
 use tokio::task::spawn_blocking;
 use walkdir::WalkDir;
@@ -21,12 +23,18 @@ use walkdir::WalkDir;
 use crate::plugin_data::PluginData;
 use crate::plugins::applications::linux;
 use crate::plugins::applications::resize_icon;
+use crate::plugins::applications::ApplicationContext;
 use crate::plugins::applications::DesktopApplication;
+use crate::plugins::applications::DesktopApplicationAction;
+use crate::plugins::applications::DesktopEnvironment;
 use crate::plugins::applications::DesktopPathAction;
 
+mod launcher;
 mod wayland;
 mod x11;
 
+const ACTIVATION_TOKEN_TIMEOUT: Duration = Duration::from_secs(1);
+
 deno_core::extension!(
     gauntlet_internal_linux,
     ops = [
@@ -35,9 +43,15 @@ deno_core::extension!(
         linux_application_dirs,
         linux_open_application,
         x11::linux_x11_focus_window,
+        x11::linux_x11_window_action,
+        x11::linux_x11_move_window_to_workspace,
+        x11::linux_x11_workspaces,
         x11::application_x11_pending_event,
         wayland::linux_wayland_focus_window,
+        wayland::linux_wayland_window_action,
         wayland::application_wayland_pending_event,
+        // plugins system actions
+        crate::plugins::system_actions::linux_system_action,
     ],
     esm_entry_point = "ext:gauntlet/internal-linux/bootstrap.js",
     esm = [
@@ -71,6 +85,16 @@ impl LinuxDesktopEnvironment {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum WindowAction {
+    #[serde(rename = "close")]
+    Close,
+    #[serde(rename = "minimize")]
+    Minimize,
+    #[serde(rename = "toggle_maximize")]
+    ToggleMaximize,
+}
+
 #[op2(async)]
 #[serde]
 async fn linux_app_from_path(
@@ -105,13 +129,53 @@ fn linux_application_dirs(state: Rc<RefCell<OpState>>) -> Vec<String> {
         .collect()
 }
 
-#[op2(fast)]
-fn linux_open_application(#[string] desktop_file_id: String) -> anyhow::Result<()> {
-    std::process::Command::new("gtk-launch")
-        .args([desktop_file_id])
-        .spawn_detached()?;
+#[op2(async)]
+async fn linux_open_application(
+    state: Rc<RefCell<OpState>>,
+    #[string] desktop_file_id: String,
+    #[string] desktop_file_path: String,
+    #[serde] action_id: Option<String>,
+    #[serde] terminal_command: Option<String>,
+) -> anyhow::Result<()> {
+    let entry = launcher::LaunchEntry::read(&desktop_file_id, Path::new(&desktop_file_path))?;
+
+    let startup_id = if entry.startup_notify {
+        startup_id(&state, &entry)
+            .await
+            .inspect_err(|err| tracing::warn!("Unable to get startup id for {:?}: {:?}", desktop_file_id, err))
+            .ok()
+    } else {
+        None
+    };
+
+    launcher::open_application(&entry, action_id.as_deref(), terminal_command.as_deref(), startup_id).await
+}
+
+async fn startup_id(state: &Rc<RefCell<OpState>>, entry: &launcher::LaunchEntry) -> anyhow::Result<String> {
+    let token_receiver = {
+        let state = state.borrow();
+
+        let context = state.borrow::<ApplicationContext>();
+
+        match &context.desktop {
+            DesktopEnvironment::Linux(LinuxDesktopEnvironment::Wayland(env)) => {
+                Some(env.request_activation_token(entry.id.clone())?)
+            }
+            _ => None,
+        }
+    };
+
+    match token_receiver {
+        Some(token_receiver) => {
+            let token = tokio::time::timeout(ACTIVATION_TOKEN_TIMEOUT, token_receiver)
+                .await
+                .map_err(|_| anyhow!("Timed out waiting for activation token"))?
+                .map_err(|_| anyhow!("Compositor doesn't support xdg activation protocol"))?;
 
-    Ok(())
+            Ok(token)
+        }
+        None => x11::startup_notification_begin(entry),
+    }
 }
 
 fn linux_application_dirs_inner(home_dir: PathBuf) -> Vec<PathBuf> {
@@ -207,6 +271,22 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
         .expect("non-utf8 paths are not supported")
         .to_string();
 
+    let actions = {
+        let main_section = entry.section("Desktop Entry");
+
+        split_list(main_section.attr("Actions"))
+            .into_iter()
+            .filter_map(|id| {
+                let name = entry
+                    .section(format!("Desktop Action {}", id))
+                    .attr("Name")?
+                    .to_string();
+
+                Some(DesktopApplicationAction { id, name })
+            })
+            .collect()
+    };
+
     let entry = entry.section("Desktop Entry");
 
     let name = entry.attr("Name")?;
@@ -214,12 +294,31 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
     let no_display = entry.attr("NoDisplay").map(|val| val == "true").unwrap_or(false);
     let hidden = entry.attr("Hidden").map(|val| val == "true").unwrap_or(false);
     let startup_wm_class = entry.attr("StartupWMClass").map(|s| s.to_string());
-    // TODO NotShowIn, OnlyShowIn https://wiki.archlinux.org/title/desktop_entries
+    let generic_name = entry.attr("GenericName").map(|s| s.to_string());
+    let keywords = split_list(entry.attr("Keywords"));
+    let only_show_in = split_list(entry.attr("OnlyShowIn"));
+    let not_show_in = split_list(entry.attr("NotShowIn"));
+    let try_exec = entry.attr("TryExec");
 
     if no_display || hidden {
         return None;
     }
 
+    if !shown_in_current_desktop(&only_show_in, &not_show_in) {
+        return None;
+    }
+
+    if let Some(try_exec) = try_exec {
+        if launcher::find_executable(try_exec).is_none() {
+            tracing::debug!(
+                "TryExec executable of {:?} not found: {:?}",
+                desktop_file_path,
+                try_exec
+            );
+            return None;
+        }
+    }
+
     let icon = icon
         .map(|icon| {
             let icon_path = PathBuf::from(&icon);
@@ -276,5 +375,121 @@ fn create_app_entry(desktop_file_path: &Path) -> Option<DesktopApplication> {
         desktop_file_path: desktop_file_path_str,
         icon,
         startup_wm_class,
+        generic_name,
+        keywords,
+        actions,
     })
 }
+
+// list values in desktop entries are separated by semicolon, with optional trailing semicolon
+fn split_list(value: Option<&str>) -> Vec<String> {
+    value
+        .map(|value| {
+            value
+                .split(';')
+                .map(|item| item.trim())
+                .filter(|item| !item.is_empty())
+                .map(|item| item.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn shown_in_current_desktop(only_show_in: &[String], not_show_in: &[String]) -> bool {
+    // XDG_CURRENT_DESKTOP can contain multiple colon separated names, e.g. "ubuntu:GNOME"
+    let current_desktops = std::env::var("XDG_CURRENT_DESKTOP")
+        .map(|value| value.split(':').map(|name| name.to_string()).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let is_current = |desktops: &[String]| desktops.iter().any(|desktop| current_desktops.contains(desktop));
+
+    if !only_show_in.is_empty() && !is_current(only_show_in) {
+        return false;
+    }
+
+    !is_current(not_show_in)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_desktop_file(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+
+        std::fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(
+            split_list(Some("text;notes; editor ;;")),
+            vec!["text", "notes", "editor"]
+        );
+        assert_eq!(split_list(Some("")), Vec::<String>::new());
+        assert_eq!(split_list(None), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_actions_and_keywords() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let path = write_desktop_file(
+            dir.path(),
+            "editor.desktop",
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Editor\n\
+             GenericName=Text Editor\n\
+             Exec=editor %F\n\
+             Keywords=text;notes;\n\
+             Actions=new-window;missing;\n\
+             \n\
+             [Desktop Action new-window]\n\
+             Name=New Window\n\
+             Exec=editor --new-window\n",
+        );
+
+        let app = create_app_entry(&path).unwrap();
+
+        assert_eq!(app.name, "Editor");
+        assert_eq!(app.generic_name.as_deref(), Some("Text Editor"));
+        assert_eq!(app.keywords, vec!["text", "notes"]);
+
+        // actions without their own section are skipped
+        let actions: Vec<_> = app
+            .actions
+            .iter()
+            .map(|action| (action.id.as_str(), action.name.as_str()))
+            .collect();
+
+        assert_eq!(actions, vec![("new-window", "New Window")]);
+    }
+
+    #[test]
+    fn test_hidden_entries() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let no_display = write_desktop_file(
+            dir.path(),
+            "no-display.desktop",
+            "[Desktop Entry]\nType=Application\nName=Hidden\nExec=hidden\nNoDisplay=true\n",
+        );
+        let hidden = write_desktop_file(
+            dir.path(),
+            "hidden.desktop",
+            "[Desktop Entry]\nType=Application\nName=Hidden\nExec=hidden\nHidden=true\n",
+        );
+        let missing_try_exec = write_desktop_file(
+            dir.path(),
+            "try-exec.desktop",
+            "[Desktop Entry]\nType=Application\nName=Missing\nExec=missing\nTryExec=/nonexistent/gauntlet-test\n",
+        );
+
+        assert!(create_app_entry(&no_display).is_none());
+        assert!(create_app_entry(&hidden).is_none());
+        assert!(create_app_entry(&missing_try_exec).is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -21,14 +22,17 @@ use wayland_client::Proxy;
 use wayland_client::QueueHandle;
 
 use crate::plugins::applications::linux::wayland::send_event;
+use crate::plugins::applications::linux::wayland::toplevel_state_values;
 use crate::plugins::applications::linux::wayland::JsWaylandApplicationEvent;
 use crate::plugins::applications::linux::wayland::WaylandState;
 use crate::plugins::applications::linux::wayland::WaylandStateInner;
+use crate::plugins::applications::linux::WindowAction;
 
 pub struct CosmicWaylandState {
     uuid_to_obj_id: HashMap<String, ObjectId>,
     obj_id_to_uuid: HashMap<ObjectId, String>,
     toplevels: HashMap<ObjectId, zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1>,
+    maximized: HashSet<ObjectId>,
     management: zcosmic_toplevel_manager_v1::ZcosmicToplevelManagerV1,
 }
 
@@ -42,6 +46,7 @@ impl CosmicWaylandState {
             uuid_to_obj_id: HashMap::new(),
             obj_id_to_uuid: HashMap::new(),
             toplevels: HashMap::new(),
+            maximized: HashSet::new(),
         })
     }
 
@@ -63,6 +68,32 @@ impl CosmicWaylandState {
 
         Ok(())
     }
+
+    pub fn window_action(&self, window_uuid: String, action: WindowAction) -> anyhow::Result<()> {
+        let obj_id = self
+            .uuid_to_obj_id
+            .get(&window_uuid)
+            .ok_or(anyhow!("Unable to find object id for window uuid: {}", window_uuid))?;
+
+        let toplevel = self
+            .toplevels
+            .get(&obj_id)
+            .ok_or(anyhow!("Unable to find object id for window uuid: {}", window_uuid))?;
+
+        match action {
+            WindowAction::Close => self.management.close(&toplevel),
+            WindowAction::Minimize => self.management.minimize(&toplevel),
+            WindowAction::ToggleMaximize => {
+                if self.maximized.contains(obj_id) {
+                    self.management.unset_maximize(&toplevel)
+                } else {
+                    self.management.maximize(&toplevel)
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Dispatch<zcosmic_toplevel_manager_v1::ZcosmicToplevelManagerV1, ()> for WaylandState {
@@ -172,10 +203,26 @@ impl Dispatch<zcosmic_toplevel_handle_v1::ZcosmicToplevelHandleV1, ()> for Wayla
                     _ => {}
                 }
             }
+            zcosmic_toplevel_handle_v1::Event::State { state: toplevel_state } => {
+                match &mut state.inner {
+                    WaylandStateInner::Cosmic(inner) => {
+                        let maximized = toplevel_state_values(&toplevel_state)
+                            .any(|value| value == zcosmic_toplevel_handle_v1::State::Maximized as u32);
+
+                        if maximized {
+                            inner.maximized.insert(proxy.id());
+                        } else {
+                            inner.maximized.remove(&proxy.id());
+                        }
+                    }
+                    _ => {}
+                }
+            }
             zcosmic_toplevel_handle_v1::Event::Closed => {
                 match &mut state.inner {
                     WaylandStateInner::Cosmic(inner) => {
                         inner.toplevels.remove(&proxy.id());
+                        inner.maximized.remove(&proxy.id());
                         match inner.obj_id_to_uuid.remove(&proxy.id()) {
                             Some(window_id) => {
                                 inner.uuid_to_obj_id.remove(&window_id);
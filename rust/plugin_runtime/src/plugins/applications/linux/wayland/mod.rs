@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::Mutex;
 use std::thread;
 
 use anyhow::anyhow;
@@ -7,6 +8,9 @@ use deno_core::op2;
 use deno_core::OpState;
 use serde::Deserialize;
 use serde::Serialize;
+use smithay_client_toolkit::activation::ActivationHandler;
+use smithay_client_toolkit::activation::ActivationState;
+use smithay_client_toolkit::activation::RequestDataExt;
 use smithay_client_toolkit::reexports::calloop;
 use smithay_client_toolkit::reexports::calloop::channel::Channel;
 use smithay_client_toolkit::reexports::calloop::channel::Event;
@@ -20,16 +24,19 @@ use smithay_client_toolkit::seat::SeatState;
 use tokio::runtime::Handle;
 use tokio::sync::mpsc::Receiver;
 use tokio::sync::mpsc::Sender;
+use tokio::sync::oneshot;
 use wayland_client::globals::registry_queue_init;
 use wayland_client::globals::GlobalList;
 use wayland_client::globals::GlobalListContents;
 use wayland_client::protocol::wl_registry;
 use wayland_client::protocol::wl_seat::WlSeat;
+use wayland_client::protocol::wl_surface::WlSurface;
 use wayland_client::Connection;
 use wayland_client::Dispatch;
 use wayland_client::QueueHandle;
 
 use crate::plugins::applications::linux;
+use crate::plugins::applications::linux::WindowAction;
 use crate::plugins::applications::ApplicationContext;
 use crate::plugins::applications::DesktopEnvironment;
 
@@ -37,17 +44,51 @@ mod cosmic;
 mod wlr;
 
 pub struct WaylandDesktopEnvironment {
-    activate_sender: calloop::channel::Sender<String>,
+    request_sender: calloop::channel::Sender<WaylandWindowRequest>,
     event_receiver: Rc<RefCell<Receiver<JsWaylandApplicationEvent>>>,
 }
 
+enum WaylandWindowRequest {
+    Focus {
+        window_uuid: String,
+    },
+    Action {
+        window_uuid: String,
+        action: WindowAction,
+    },
+    ActivationToken {
+        app_id: String,
+        sender: oneshot::Sender<String>,
+    },
+}
+
+// passed along with activation token request to get it back together with the token
+struct ActivationTokenRequest {
+    app_id: String,
+    sender: Mutex<Option<oneshot::Sender<String>>>,
+}
+
+impl RequestDataExt for ActivationTokenRequest {
+    fn app_id(&self) -> Option<&str> {
+        Some(&self.app_id)
+    }
+
+    fn seat_and_serial(&self) -> Option<(&WlSeat, u32)> {
+        None
+    }
+
+    fn surface(&self) -> Option<&WlSurface> {
+        None
+    }
+}
+
 impl WaylandDesktopEnvironment {
     pub fn new() -> anyhow::Result<WaylandDesktopEnvironment> {
         let (event_sender, event_receiver) = tokio::sync::mpsc::channel(100);
-        let (activate_sender, activate_receiver) = calloop::channel::channel();
+        let (request_sender, request_receiver) = calloop::channel::channel();
 
         let environment = WaylandDesktopEnvironment {
-            activate_sender,
+            request_sender,
             event_receiver: Rc::new(RefCell::new(event_receiver)),
         };
 
@@ -56,7 +97,7 @@ impl WaylandDesktopEnvironment {
         thread::Builder::new()
             .name("gauntlet-wayland-events".to_string())
             .spawn(|| {
-                if let Err(e) = run_wayland_client(handle, event_sender, activate_receiver) {
+                if let Err(e) = run_wayland_client(handle, event_sender, request_receiver) {
                     tracing::error!("Error while running wayland client: {:?}", e);
                 }
             })
@@ -66,10 +107,28 @@ impl WaylandDesktopEnvironment {
     }
 
     pub fn focus_window(&self, window_uuid: String) -> anyhow::Result<()> {
-        self.activate_sender.send(window_uuid)?;
+        self.request_sender.send(WaylandWindowRequest::Focus { window_uuid })?;
+
+        Ok(())
+    }
+
+    pub fn window_action(&self, window_uuid: String, action: WindowAction) -> anyhow::Result<()> {
+        self.request_sender
+            .send(WaylandWindowRequest::Action { window_uuid, action })?;
 
         Ok(())
     }
+
+    /// Token is requested without surface and serial, so compositor may not allow
+    /// launched application to take focus with it, depending on its focus stealing prevention policy
+    pub fn request_activation_token(&self, app_id: String) -> anyhow::Result<oneshot::Receiver<String>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(WaylandWindowRequest::ActivationToken { app_id, sender })?;
+
+        Ok(receiver)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -83,6 +142,8 @@ pub enum JsWaylandApplicationEvent {
 
 pub struct WaylandState {
     seat_state: SeatState,
+    activation_state: Option<ActivationState>,
+    queue_handle: QueueHandle<WaylandState>,
     tokio_handle: Handle,
     sender: Sender<JsWaylandApplicationEvent>,
     inner: WaylandStateInner,
@@ -103,8 +164,14 @@ impl WaylandState {
             })
             .unwrap_or(WaylandStateInner::None);
 
+        let activation_state = ActivationState::bind(globals, queue_handle)
+            .inspect_err(|err| tracing::debug!("xdg activation protocol is not supported: {:?}", err))
+            .ok();
+
         Ok(WaylandState {
             seat_state,
+            activation_state,
+            queue_handle: queue_handle.clone(),
             tokio_handle,
             sender,
             inner,
@@ -130,7 +197,7 @@ fn send_event(tokio_handle: &Handle, sender: &Sender<JsWaylandApplicationEvent>,
 fn run_wayland_client(
     tokio_handle: Handle,
     event_sender: Sender<JsWaylandApplicationEvent>,
-    activate_receiver: Channel<String>,
+    request_receiver: Channel<WaylandWindowRequest>,
 ) -> anyhow::Result<()> {
     let conn = Connection::connect_to_env()?;
     let (globals, event_queue) = registry_queue_init::<WaylandState>(&conn)?;
@@ -141,10 +208,10 @@ fn run_wayland_client(
     let seat_state = SeatState::new(&globals, &queue_handle);
     let loop_handle = event_loop.handle();
 
-    if let Err(err) = loop_handle.insert_source(activate_receiver, activation_handler) {
-        tracing::error!("Unable to insert activation source into event loop: {:?}", err);
+    if let Err(err) = loop_handle.insert_source(request_receiver, request_handler) {
+        tracing::error!("Unable to insert request source into event loop: {:?}", err);
 
-        Err(anyhow!("Unable to insert activation source into event loop"))?
+        Err(anyhow!("Unable to insert request source into event loop"))?
     };
 
     if let Err(err) = wayland_source.insert(loop_handle) {
@@ -183,29 +250,84 @@ pub fn linux_wayland_focus_window(state: Rc<RefCell<OpState>>, #[string] window_
     Ok(())
 }
 
-fn activation_handler(event: Event<String>, _metadata: &mut (), state: &mut WaylandState) {
-    let window_uuid = match event {
-        Event::Msg(window_uuid) => window_uuid,
-        Event::Closed => panic!("activation source was closed"),
+#[op2]
+pub fn linux_wayland_window_action(
+    state: Rc<RefCell<OpState>>,
+    #[string] window_uuid: String,
+    #[serde] action: WindowAction,
+) -> anyhow::Result<()> {
+    {
+        let state = state.borrow();
+
+        let context = state.borrow::<ApplicationContext>();
+
+        match &context.desktop {
+            DesktopEnvironment::Linux(linux::LinuxDesktopEnvironment::Wayland(env)) => {
+                env.window_action(window_uuid, action)?;
+            }
+            _ => Err(anyhow!("Calling linux_wayland_window_action on non-wayland platform"))?,
+        };
+    };
+
+    Ok(())
+}
+
+fn request_handler(event: Event<WaylandWindowRequest>, _metadata: &mut (), state: &mut WaylandState) {
+    let request = match event {
+        Event::Msg(request) => request,
+        Event::Closed => panic!("request source was closed"),
     };
 
-    match &state.inner {
-        WaylandStateInner::Wlr(wlr) => {
-            if let Err(err) = wlr.focus_window(window_uuid, &state.seat_state) {
-                tracing::error!("Unable to focus wayland window: {:?}", err);
-            };
+    match request {
+        WaylandWindowRequest::Focus { window_uuid } => {
+            match &state.inner {
+                WaylandStateInner::Wlr(wlr) => {
+                    if let Err(err) = wlr.focus_window(window_uuid, &state.seat_state) {
+                        tracing::error!("Unable to focus wayland window: {:?}", err);
+                    };
+                }
+                WaylandStateInner::Cosmic(cosmic) => {
+                    if let Err(err) = cosmic.focus_window(window_uuid, &state.seat_state) {
+                        tracing::error!("Unable to focus wayland window: {:?}", err);
+                    };
+                }
+                WaylandStateInner::None => {
+                    tracing::error!("Calling focus window when there is no supported wayland protocols available");
+                }
+            }
         }
-        WaylandStateInner::Cosmic(cosmic) => {
-            if let Err(err) = cosmic.focus_window(window_uuid, &state.seat_state) {
-                tracing::error!("Unable to focus wayland window: {:?}", err);
+        WaylandWindowRequest::Action { window_uuid, action } => {
+            let result = match &state.inner {
+                WaylandStateInner::Wlr(wlr) => wlr.window_action(window_uuid, action),
+                WaylandStateInner::Cosmic(cosmic) => cosmic.window_action(window_uuid, action),
+                WaylandStateInner::None => Err(anyhow!("there is no supported wayland protocols available")),
             };
+
+            if let Err(err) = result {
+                tracing::error!("Unable to run {:?} action on wayland window: {:?}", action, err);
+            }
         }
-        WaylandStateInner::None => {
-            tracing::error!("Calling focus window when there is no supported wayland protocols available");
+        WaylandWindowRequest::ActivationToken { app_id, sender } => {
+            // if protocol is not supported sender is dropped, which is reported as error to the caller
+            if let Some(activation_state) = &state.activation_state {
+                let request = ActivationTokenRequest {
+                    app_id,
+                    sender: Mutex::new(Some(sender)),
+                };
+
+                activation_state.request_token_with_data(&state.queue_handle, request);
+            }
         }
     }
 }
 
+// toplevel state is sent as array of native endian u32 values
+fn toplevel_state_values(state: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    state
+        .chunks_exact(4)
+        .map(|value| u32::from_ne_bytes(value.try_into().expect("chunk should have 4 bytes")))
+}
+
 #[op2(async)]
 #[serde]
 pub async fn application_wayland_pending_event(
@@ -270,4 +392,17 @@ impl SeatHandler for WaylandState {
     fn remove_seat(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _seat: WlSeat) {}
 }
 
+impl ActivationHandler for WaylandState {
+    type RequestData = ActivationTokenRequest;
+
+    fn new_token(&mut self, token: String, data: &Self::RequestData) {
+        let sender = data.sender.lock().expect("lock is poisoned").take();
+
+        if let Some(sender) = sender {
+            let _ = sender.send(token);
+        }
+    }
+}
+
 smithay_client_toolkit::delegate_seat!(WaylandState);
+smithay_client_toolkit::delegate_activation!(WaylandState, ActivationTokenRequest);
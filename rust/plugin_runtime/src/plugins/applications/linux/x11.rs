@@ -25,19 +25,29 @@ use x11rb::protocol::xproto::ChangeWindowAttributesAux;
 use x11rb::protocol::xproto::ClientMessageEvent;
 use x11rb::protocol::xproto::ConfigureWindowAux;
 use x11rb::protocol::xproto::ConnectionExt;
+use x11rb::protocol::xproto::CreateWindowAux;
 use x11rb::protocol::xproto::EventMask;
 use x11rb::protocol::xproto::InputFocus;
 use x11rb::protocol::xproto::MapState;
 use x11rb::protocol::xproto::StackMode;
 use x11rb::protocol::xproto::Window;
+use x11rb::protocol::xproto::WindowClass;
 use x11rb::protocol::Event;
 use x11rb::rust_connection::RustConnection;
 
 use crate::plugins::applications::linux;
+use crate::plugins::applications::linux::launcher::LaunchEntry;
 use crate::plugins::applications::linux::x11;
+use crate::plugins::applications::linux::WindowAction;
 use crate::plugins::applications::ApplicationContext;
 use crate::plugins::applications::DesktopEnvironment;
 
+// requests are sent as if they come from pager, which window managers are expected to always honor
+const SOURCE_INDICATION_PAGER: u32 = 2;
+const ALL_WORKSPACES: u32 = 0xFFFFFFFF;
+const ICONIC_STATE: u32 = 3;
+const NET_WM_STATE_TOGGLE: u32 = 2;
+
 pub struct X11DesktopEnvironment {
     receiver: Rc<RefCell<Receiver<JsX11ApplicationEvent>>>,
 }
@@ -118,6 +128,18 @@ enum JsX11ApplicationEvent {
         id: String,
         desktop_file_name: String,
     },
+    WorkspacePropertyNotify {
+        id: String,
+        // none if window is shown on all workspaces
+        workspace: Option<u32>,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsX11Workspace {
+    index: u32,
+    name: String,
+    current: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -219,6 +241,244 @@ fn focus_window(window_id: String) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[op2]
+pub fn linux_x11_window_action(#[string] x11_window_id: String, #[serde] action: WindowAction) -> anyhow::Result<()> {
+    let window = Window::from_str(&x11_window_id)?;
+
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    window_action(&conn, root, &atoms, window, action)?;
+
+    conn.flush()?;
+
+    Ok(())
+}
+
+#[op2(fast)]
+pub fn linux_x11_move_window_to_workspace(#[string] x11_window_id: String, workspace: u32) -> anyhow::Result<()> {
+    let window = Window::from_str(&x11_window_id)?;
+
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    move_window_to_workspace(&conn, root, &atoms, window, workspace)?;
+
+    conn.flush()?;
+
+    Ok(())
+}
+
+#[op2]
+#[serde]
+pub fn linux_x11_workspaces() -> anyhow::Result<Vec<JsX11Workspace>> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    read_workspaces(&conn, root, &atoms)
+}
+
+fn window_action(
+    conn: &impl Connection,
+    root: Window,
+    atoms: &atoms::Atoms,
+    window: Window,
+    action: WindowAction,
+) -> anyhow::Result<()> {
+    match action {
+        WindowAction::Close => {
+            let data = [x11rb::CURRENT_TIME, SOURCE_INDICATION_PAGER, 0, 0, 0];
+
+            send_root_client_message(conn, root, window, atoms._NET_CLOSE_WINDOW, data)
+        }
+        WindowAction::Minimize => {
+            // _NET_WM_STATE_HIDDEN is managed by window manager, so iconify request from ICCCM is used
+            let data = [ICONIC_STATE, 0, 0, 0, 0];
+
+            send_root_client_message(conn, root, window, atoms.WM_CHANGE_STATE, data)
+        }
+        WindowAction::ToggleMaximize => {
+            let data = [
+                NET_WM_STATE_TOGGLE,
+                atoms._NET_WM_STATE_MAXIMIZED_VERT,
+                atoms._NET_WM_STATE_MAXIMIZED_HORZ,
+                SOURCE_INDICATION_PAGER,
+                0,
+            ];
+
+            send_root_client_message(conn, root, window, atoms._NET_WM_STATE, data)
+        }
+    }
+}
+
+fn move_window_to_workspace(
+    conn: &impl Connection,
+    root: Window,
+    atoms: &atoms::Atoms,
+    window: Window,
+    workspace: u32,
+) -> anyhow::Result<()> {
+    let data = [workspace, SOURCE_INDICATION_PAGER, 0, 0, 0];
+
+    send_root_client_message(conn, root, window, atoms._NET_WM_DESKTOP, data)
+}
+
+fn send_root_client_message(
+    conn: &impl Connection,
+    root: Window,
+    window: Window,
+    message_type: Atom,
+    data: [u32; 5],
+) -> anyhow::Result<()> {
+    let event = ClientMessageEvent::new(32, window, message_type, data);
+
+    conn.send_event(
+        false,
+        root,
+        EventMask::SUBSTRUCTURE_NOTIFY | EventMask::SUBSTRUCTURE_REDIRECT,
+        event,
+    )?;
+
+    Ok(())
+}
+
+fn read_workspaces(conn: &impl Connection, root: Window, atoms: &atoms::Atoms) -> anyhow::Result<Vec<JsX11Workspace>> {
+    let read_cardinal = |atom: Atom| -> anyhow::Result<Option<u32>> {
+        let value = conn
+            .get_property(false, root, atom, AtomEnum::CARDINAL, 0, 1)?
+            .reply()?
+            .value32()
+            .and_then(|mut iter| iter.next());
+
+        Ok(value)
+    };
+
+    let count =
+        read_cardinal(atoms._NET_NUMBER_OF_DESKTOPS)?.ok_or(anyhow!("Window manager doesn't support workspaces"))?;
+
+    let current = read_cardinal(atoms._NET_CURRENT_DESKTOP)?;
+
+    let names = conn
+        .get_property(false, root, atoms._NET_DESKTOP_NAMES, atoms.UTF8_STRING, 0, 4096)?
+        .reply()?
+        .value8()
+        .map(|bytes| {
+            // names are null-terminated strings placed one after another
+            bytes
+                .collect::<Vec<_>>()
+                .split(|byte| *byte == 0)
+                .map(|name| String::from_utf8_lossy(name).to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let workspaces = (0..count)
+        .map(|index| {
+            let name = names
+                .get(index as usize)
+                .filter(|name| !name.is_empty())
+                .cloned()
+                .unwrap_or_else(|| format!("Workspace {}", index + 1));
+
+            JsX11Workspace {
+                index,
+                name,
+                current: current == Some(index),
+            }
+        })
+        .collect();
+
+    Ok(workspaces)
+}
+
+/// Announces application launch using startup notification protocol and returns id which
+/// should be passed to launched application in DESKTOP_STARTUP_ID environment variable
+/// https://specifications.freedesktop.org/startup-notification-spec/latest/
+pub fn startup_notification_begin(entry: &LaunchEntry) -> anyhow::Result<String> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let root = conn.setup().roots[screen_num].root;
+    let atoms = atoms::Atoms::new(&conn)?.reply()?;
+
+    let startup_id = format!("gauntlet-{}-{}", std::process::id(), uuid::Uuid::new_v4());
+
+    let mut message = format!(
+        "new: ID={} NAME={} SCREEN={} APPLICATION_ID={}",
+        startup_notification_value(&startup_id),
+        startup_notification_value(&entry.name),
+        screen_num,
+        startup_notification_value(&entry.id),
+    );
+
+    if let Some(icon) = &entry.icon {
+        message.push_str(&format!(" ICON={}", startup_notification_value(icon)));
+    }
+
+    if let Some(wm_class) = &entry.startup_wm_class {
+        message.push_str(&format!(" WMCLASS={}", startup_notification_value(wm_class)));
+    }
+
+    // messages are sent from a window owned by sender
+    let window = conn.generate_id()?;
+
+    conn.create_window(
+        x11rb::COPY_DEPTH_FROM_PARENT,
+        window,
+        root,
+        -100,
+        -100,
+        1,
+        1,
+        0,
+        WindowClass::INPUT_ONLY,
+        x11rb::COPY_FROM_PARENT,
+        &CreateWindowAux::new().override_redirect(1),
+    )?;
+
+    send_startup_notification_message(&conn, root, &atoms, window, &message)?;
+
+    conn.destroy_window(window)?;
+    conn.flush()?;
+
+    Ok(startup_id)
+}
+
+fn send_startup_notification_message(
+    conn: &impl Connection,
+    root: Window,
+    atoms: &atoms::Atoms,
+    window: Window,
+    message: &str,
+) -> anyhow::Result<()> {
+    // message is null-terminated and split into 20 byte chunks,
+    // first chunk uses different message type to mark start of the message
+    let mut bytes = message.as_bytes().to_vec();
+    bytes.push(0);
+
+    for (index, chunk) in bytes.chunks(20).enumerate() {
+        let message_type = if index == 0 {
+            atoms._NET_STARTUP_INFO_BEGIN
+        } else {
+            atoms._NET_STARTUP_INFO
+        };
+
+        let mut data = [0u8; 20];
+        data[..chunk.len()].copy_from_slice(chunk);
+
+        let event = ClientMessageEvent::new(8, window, message_type, data);
+
+        conn.send_event(false, root, EventMask::PROPERTY_CHANGE, event)?;
+    }
+
+    Ok(())
+}
+
+fn startup_notification_value(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 fn send_event(tokio_handle: &Handle, sender: &Sender<JsX11ApplicationEvent>, app_event: JsX11ApplicationEvent) {
     let sender = sender.clone();
     tokio_handle.spawn(async move {
@@ -336,6 +596,9 @@ pub fn listen_on_x11_events(tokio_handle: Handle, sender: Sender<JsX11Applicatio
                     atom if atom == atoms._KDE_NET_WM_DESKTOP_FILE || atom == atoms._GTK_APPLICATION_ID => {
                         let _ = update_desktop_file_name(event.window, &conn, &tokio_handle, &sender, atoms);
                     }
+                    atom if atom == atoms._NET_WM_DESKTOP => {
+                        let _ = update_workspace(event.window, &conn, &tokio_handle, &sender, atoms);
+                    }
                     _ => {}
                 }
             }
@@ -395,6 +658,7 @@ fn update_properties(
     let _ = update_transient_for(window_id, conn, tokio_handle, sender);
     let _ = update_net_window_type(window_id, conn, tokio_handle, sender, atoms);
     let _ = update_desktop_file_name(window_id, &conn, &tokio_handle, &sender, atoms);
+    let _ = update_workspace(window_id, conn, tokio_handle, sender, atoms);
 }
 
 fn update_title(
@@ -621,6 +885,33 @@ fn update_desktop_file_name(
     Ok(())
 }
 
+fn update_workspace(
+    window_id: Window,
+    conn: &RustConnection,
+    tokio_handle: &Handle,
+    sender: &Sender<JsX11ApplicationEvent>,
+    atoms: atoms::Atoms,
+) -> anyhow::Result<()> {
+    let reply = conn
+        .get_property(false, window_id, atoms._NET_WM_DESKTOP, AtomEnum::CARDINAL, 0, 1)?
+        .reply()?;
+
+    let Some(workspace) = reply.value32().and_then(|mut iter| iter.next()) else {
+        return Ok(());
+    };
+
+    send_event(
+        &tokio_handle,
+        &sender,
+        JsX11ApplicationEvent::WorkspacePropertyNotify {
+            id: format!("{}", window_id),
+            workspace: (workspace != ALL_WORKSPACES).then_some(workspace),
+        },
+    );
+
+    Ok(())
+}
+
 fn read_window_property_string(
     window_id: Window,
     conn: &RustConnection,
@@ -673,11 +964,204 @@ mod atoms {
             _NET_WM_WINDOW_TYPE_TOOLTIP,
             _NET_WM_WINDOW_TYPE_UTILITY,
             _NET_WM_STATE_MODAL,
+            _NET_WM_DESKTOP,
             _NET_ACTIVE_WINDOW,
 
+            // requests to window manager
+            WM_CHANGE_STATE,
+            _NET_CLOSE_WINDOW,
+            _NET_WM_STATE,
+            _NET_WM_STATE_MAXIMIZED_VERT,
+            _NET_WM_STATE_MAXIMIZED_HORZ,
+
+            // startup notification
+            _NET_STARTUP_INFO_BEGIN,
+            _NET_STARTUP_INFO,
+
+            // workspaces
+            _NET_NUMBER_OF_DESKTOPS,
+            _NET_CURRENT_DESKTOP,
+            _NET_DESKTOP_NAMES,
+
             // non-standard
             _KDE_NET_WM_DESKTOP_FILE,
             _GTK_APPLICATION_ID,
         }
     }
 }
+
+// these tests need X server without window manager, e.g. `xvfb-run cargo test -- --ignored`
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use x11rb::protocol::xproto::PropMode;
+    use x11rb::wrapper::ConnectionExt as WrapperConnectionExt;
+
+    use super::*;
+
+    // only one client can receive requests sent to root window
+    static X_SERVER: Mutex<()> = Mutex::new(());
+
+    const WINDOW: Window = 0x1234;
+
+    struct FakeWindowManager {
+        conn: RustConnection,
+        root: Window,
+        atoms: atoms::Atoms,
+    }
+
+    impl FakeWindowManager {
+        fn new() -> Self {
+            let (conn, screen_num) = RustConnection::connect(None).unwrap();
+            let root = conn.setup().roots[screen_num].root;
+            let atoms = atoms::Atoms::new(&conn).unwrap().reply().unwrap();
+
+            let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::SUBSTRUCTURE_REDIRECT);
+
+            conn.change_window_attributes(root, &aux).unwrap().check().unwrap();
+
+            Self { conn, root, atoms }
+        }
+
+        fn receive_request(&self) -> ClientMessageEvent {
+            loop {
+                if let Event::ClientMessage(event) = self.conn.wait_for_event().unwrap() {
+                    return event;
+                }
+            }
+        }
+    }
+
+    fn send_request(request: impl FnOnce(&RustConnection, Window, &atoms::Atoms) -> anyhow::Result<()>) {
+        let (conn, screen_num) = RustConnection::connect(None).unwrap();
+        let root = conn.setup().roots[screen_num].root;
+        let atoms = atoms::Atoms::new(&conn).unwrap().reply().unwrap();
+
+        request(&conn, root, &atoms).unwrap();
+
+        conn.flush().unwrap();
+    }
+
+    #[test]
+    #[ignore = "requires X server"]
+    fn test_close_window() {
+        let _guard = X_SERVER.lock().unwrap();
+        let wm = FakeWindowManager::new();
+
+        send_request(|conn, root, atoms| window_action(conn, root, atoms, WINDOW, WindowAction::Close));
+
+        let event = wm.receive_request();
+
+        assert_eq!(event.window, WINDOW);
+        assert_eq!(event.type_, wm.atoms._NET_CLOSE_WINDOW);
+        assert_eq!(event.data.as_data32()[1], SOURCE_INDICATION_PAGER);
+    }
+
+    #[test]
+    #[ignore = "requires X server"]
+    fn test_minimize_window() {
+        let _guard = X_SERVER.lock().unwrap();
+        let wm = FakeWindowManager::new();
+
+        send_request(|conn, root, atoms| window_action(conn, root, atoms, WINDOW, WindowAction::Minimize));
+
+        let event = wm.receive_request();
+
+        assert_eq!(event.window, WINDOW);
+        assert_eq!(event.type_, wm.atoms.WM_CHANGE_STATE);
+        assert_eq!(event.data.as_data32()[0], ICONIC_STATE);
+    }
+
+    #[test]
+    #[ignore = "requires X server"]
+    fn test_toggle_maximize_window() {
+        let _guard = X_SERVER.lock().unwrap();
+        let wm = FakeWindowManager::new();
+
+        send_request(|conn, root, atoms| window_action(conn, root, atoms, WINDOW, WindowAction::ToggleMaximize));
+
+        let event = wm.receive_request();
+
+        assert_eq!(event.window, WINDOW);
+        assert_eq!(event.type_, wm.atoms._NET_WM_STATE);
+        assert_eq!(
+            event.data.as_data32(),
+            [
+                NET_WM_STATE_TOGGLE,
+                wm.atoms._NET_WM_STATE_MAXIMIZED_VERT,
+                wm.atoms._NET_WM_STATE_MAXIMIZED_HORZ,
+                SOURCE_INDICATION_PAGER,
+                0
+            ]
+        );
+    }
+
+    #[test]
+    #[ignore = "requires X server"]
+    fn test_move_window_to_workspace() {
+        let _guard = X_SERVER.lock().unwrap();
+        let wm = FakeWindowManager::new();
+
+        send_request(|conn, root, atoms| move_window_to_workspace(conn, root, atoms, WINDOW, 2));
+
+        let event = wm.receive_request();
+
+        assert_eq!(event.window, WINDOW);
+        assert_eq!(event.type_, wm.atoms._NET_WM_DESKTOP);
+        assert_eq!(event.data.as_data32()[0], 2);
+    }
+
+    #[test]
+    #[ignore = "requires X server"]
+    fn test_read_workspaces() {
+        let _guard = X_SERVER.lock().unwrap();
+        let wm = FakeWindowManager::new();
+
+        wm.conn
+            .change_property32(
+                PropMode::REPLACE,
+                wm.root,
+                wm.atoms._NET_NUMBER_OF_DESKTOPS,
+                AtomEnum::CARDINAL,
+                &[3],
+            )
+            .unwrap()
+            .check()
+            .unwrap();
+        wm.conn
+            .change_property32(
+                PropMode::REPLACE,
+                wm.root,
+                wm.atoms._NET_CURRENT_DESKTOP,
+                AtomEnum::CARDINAL,
+                &[1],
+            )
+            .unwrap()
+            .check()
+            .unwrap();
+        wm.conn
+            .change_property8(
+                PropMode::REPLACE,
+                wm.root,
+                wm.atoms._NET_DESKTOP_NAMES,
+                wm.atoms.UTF8_STRING,
+                b"Main\0Web\0",
+            )
+            .unwrap()
+            .check()
+            .unwrap();
+
+        let workspaces = read_workspaces(&wm.conn, wm.root, &wm.atoms).unwrap();
+
+        let workspaces = workspaces
+            .iter()
+            .map(|workspace| (workspace.index, workspace.name.as_str(), workspace.current))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            workspaces,
+            vec![(0, "Main", false), (1, "Web", true), (2, "Workspace 3", false)]
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use deno_core::op2;
+use deno_core::OpState;
+
+use crate::api::BackendForPluginRuntimeApi;
+use crate::api::BackendForPluginRuntimeApiProxy;
+use crate::JsClipboardHistoryEntry;
+
+#[op2(async)]
+#[serde]
+pub async fn clipboard_history_list(
+    state: Rc<RefCell<OpState>>,
+    #[string] query: String,
+) -> anyhow::Result<Vec<JsClipboardHistoryEntry>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.clipboard_history_list(query).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn clipboard_history_copy(state: Rc<RefCell<OpState>>, #[number] id: i64) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.clipboard_history_copy(id).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn clipboard_history_set_pinned(
+    state: Rc<RefCell<OpState>>,
+    #[number] id: i64,
+    pinned: bool,
+) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.clipboard_history_set_pinned(id, pinned).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn clipboard_history_remove(state: Rc<RefCell<OpState>>, #[number] id: i64) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.clipboard_history_remove(id).await.map_err(Into::into)
+}
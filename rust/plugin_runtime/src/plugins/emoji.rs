@@ -0,0 +1,346 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+use bincode::Decode;
+use bincode::Encode;
+use deno_core::op2;
+use deno_core::OpState;
+use emojis::Emoji;
+use emojis::Group;
+use emojis::SkinTone;
+use serde::Deserialize;
+use serde::Serialize;
+
+const USAGE_FILE_NAME: &str = "emoji_usage.bin";
+// same as entrypoint frecency
+const USAGE_HALF_LIFE: f64 = 60.0 * 60.0 * 24.0 * 3.0;
+const FREQUENTLY_USED_LIMIT: usize = 24;
+const CHARACTERS_LIMIT: usize = 200;
+
+// lowercased names of all named characters, built in background because it requires going through every code point
+static CHARACTER_NAMES: OnceLock<Vec<(char, String)>> = OnceLock::new();
+
+pub struct EmojiContext {
+    usage: Rc<RefCell<HashMap<String, CharacterUsage>>>,
+    usage_file: PathBuf,
+}
+
+impl EmojiContext {
+    pub fn new(plugin_data_dir: PathBuf) -> Self {
+        let usage_file = plugin_data_dir.join(USAGE_FILE_NAME);
+
+        std::thread::spawn(|| {
+            let _ = CHARACTER_NAMES.set(character_names());
+        });
+
+        Self {
+            usage: Rc::new(RefCell::new(load_usage(&usage_file))),
+            usage_file,
+        }
+    }
+}
+
+#[derive(Clone, Encode, Decode)]
+struct CharacterUsage {
+    score: f64,
+    last_used: f64,
+}
+
+impl CharacterUsage {
+    fn frecency(&self, now: f64) -> f64 {
+        self.score / 2.0f64.powf((now - self.last_used) / USAGE_HALF_LIFE)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum EmojiSkinTone {
+    #[serde(rename = "default")]
+    Default,
+    #[serde(rename = "light")]
+    Light,
+    #[serde(rename = "medium_light")]
+    MediumLight,
+    #[serde(rename = "medium")]
+    Medium,
+    #[serde(rename = "medium_dark")]
+    MediumDark,
+    #[serde(rename = "dark")]
+    Dark,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CharacterSection {
+    title: String,
+    items: Vec<CharacterItem>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CharacterItem {
+    // character without skin tone applied, used to track usage
+    id: String,
+    value: String,
+    name: String,
+    shortcodes: Vec<String>,
+}
+
+/// Empty query returns frequently used characters followed by all emoji grouped by category
+#[op2]
+#[serde]
+pub fn emoji_search(
+    state: Rc<RefCell<OpState>>,
+    #[string] query: String,
+    #[serde] skin_tone: EmojiSkinTone,
+) -> Vec<CharacterSection> {
+    let state = state.borrow();
+
+    let usage = state.borrow::<EmojiContext>().usage.borrow();
+
+    let now = current_time_secs();
+
+    let frecency = |id: &str| usage.get(id).map(|usage| usage.frecency(now)).unwrap_or_default();
+
+    let query = query.to_lowercase();
+
+    let query_words = query.split_whitespace().collect::<Vec<_>>();
+
+    if query_words.is_empty() {
+        let mut frequently_used = usage.keys().collect::<Vec<_>>();
+
+        frequently_used.sort_by(|a, b| frecency(b).total_cmp(&frecency(a)));
+
+        let frequently_used = frequently_used
+            .into_iter()
+            .take(FREQUENTLY_USED_LIMIT)
+            .filter_map(|id| character_item(id, skin_tone))
+            .collect::<Vec<_>>();
+
+        let mut result = vec![];
+
+        if !frequently_used.is_empty() {
+            result.push(CharacterSection {
+                title: "Frequently Used".to_string(),
+                items: frequently_used,
+            });
+        }
+
+        // skin tone and hair style components are not useful on their own
+        for group in Group::iter().filter(|group| !matches!(group, Group::Component)) {
+            result.push(CharacterSection {
+                title: group_title(group).to_string(),
+                items: group.emojis().map(|emoji| emoji_item(emoji, skin_tone)).collect(),
+            });
+        }
+
+        return result;
+    }
+
+    let mut emoji = emojis::iter()
+        .filter_map(|emoji| {
+            let score = emoji_match_score(emoji, &query, &query_words)?;
+
+            Some((score, frecency(emoji.as_str()), emoji))
+        })
+        .collect::<Vec<_>>();
+
+    emoji.sort_by(|(score_a, frecency_a, _), (score_b, frecency_b, _)| {
+        score_b.cmp(score_a).then(frecency_b.total_cmp(frecency_a))
+    });
+
+    let emoji = emoji
+        .into_iter()
+        .map(|(_, _, emoji)| emoji_item(emoji, skin_tone))
+        .collect::<Vec<_>>();
+
+    // until names are ready only emoji are searched, instead of blocking the search
+    let character_names = CHARACTER_NAMES.get().map(|names| names.as_slice()).unwrap_or_default();
+
+    let mut characters = character_names
+        .iter()
+        .filter(|(_, name)| query_words.iter().all(|word| name.contains(word)))
+        .filter(|(character, _)| emojis::get(&character.to_string()).is_none())
+        .take(CHARACTERS_LIMIT)
+        .map(|(character, _)| *character)
+        .collect::<Vec<_>>();
+
+    characters.sort_by(|a, b| frecency(&b.to_string()).total_cmp(&frecency(&a.to_string())));
+
+    let characters = characters
+        .into_iter()
+        .filter_map(|character| character_item(&character.to_string(), skin_tone))
+        .collect::<Vec<_>>();
+
+    let mut result = vec![];
+
+    if !emoji.is_empty() {
+        result.push(CharacterSection {
+            title: "Emoji".to_string(),
+            items: emoji,
+        });
+    }
+
+    if !characters.is_empty() {
+        result.push(CharacterSection {
+            title: "Unicode Characters".to_string(),
+            items: characters,
+        });
+    }
+
+    result
+}
+
+#[op2(fast)]
+pub fn emoji_mark_used(state: Rc<RefCell<OpState>>, #[string] id: String) -> anyhow::Result<()> {
+    let state = state.borrow();
+
+    let context = state.borrow::<EmojiContext>();
+
+    let mut usage = context.usage.borrow_mut();
+
+    let now = current_time_secs();
+
+    let entry = usage.entry(id).or_insert(CharacterUsage {
+        score: 0.0,
+        last_used: now,
+    });
+
+    *entry = CharacterUsage {
+        score: entry.frecency(now) + 1.0,
+        last_used: now,
+    };
+
+    save_usage(&context.usage_file, &usage)
+}
+
+fn emoji_item(emoji: &'static Emoji, skin_tone: EmojiSkinTone) -> CharacterItem {
+    let value = match skin_tone_to_emojis(skin_tone) {
+        Some(skin_tone) => emoji.with_skin_tone(skin_tone).unwrap_or(emoji),
+        None => emoji,
+    };
+
+    CharacterItem {
+        id: emoji.as_str().to_string(),
+        value: value.as_str().to_string(),
+        name: emoji.name().to_string(),
+        shortcodes: emoji.shortcodes().map(|shortcode| shortcode.to_string()).collect(),
+    }
+}
+
+fn character_item(id: &str, skin_tone: EmojiSkinTone) -> Option<CharacterItem> {
+    if let Some(emoji) = emojis::get(id) {
+        return Some(emoji_item(emoji, skin_tone));
+    }
+
+    let mut chars = id.chars();
+
+    let (Some(character), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+
+    let name = unicode_names2::name(character)?.to_string();
+
+    Some(CharacterItem {
+        id: id.to_string(),
+        value: id.to_string(),
+        name: name.to_lowercase(),
+        shortcodes: vec![],
+    })
+}
+
+// name and shortcodes are matched, emoji with name starting with the query are ranked higher
+fn emoji_match_score(emoji: &Emoji, query: &str, query_words: &[&str]) -> Option<u8> {
+    let name = emoji.name().to_lowercase();
+
+    let shortcodes = emoji.shortcodes().collect::<Vec<_>>();
+
+    let matches = |word: &str| name.contains(word) || shortcodes.iter().any(|shortcode| shortcode.contains(word));
+
+    if !query_words.iter().all(|word| matches(word)) {
+        return None;
+    }
+
+    if name == query || shortcodes.contains(&query) {
+        Some(2)
+    } else if name.starts_with(query) {
+        Some(1)
+    } else {
+        Some(0)
+    }
+}
+
+fn character_names() -> Vec<(char, String)> {
+    (0..=char::MAX as u32)
+        .filter_map(char::from_u32)
+        .filter(|character| !character.is_control())
+        .filter_map(|character| {
+            let name = unicode_names2::name(character)?.to_string();
+
+            // names of these are generated from code point, so they are not useful for search
+            if name.starts_with("CJK UNIFIED IDEOGRAPH") || name.starts_with("HANGUL SYLLABLE") {
+                return None;
+            }
+
+            Some((character, name.to_lowercase()))
+        })
+        .collect()
+}
+
+fn group_title(group: Group) -> &'static str {
+    match group {
+        Group::SmileysAndEmotion => "Smileys & Emotion",
+        Group::PeopleAndBody => "People & Body",
+        Group::Component => "Component",
+        Group::AnimalsAndNature => "Animals & Nature",
+        Group::FoodAndDrink => "Food & Drink",
+        Group::TravelAndPlaces => "Travel & Places",
+        Group::Activities => "Activities",
+        Group::Objects => "Objects",
+        Group::Symbols => "Symbols",
+        Group::Flags => "Flags",
+    }
+}
+
+fn skin_tone_to_emojis(skin_tone: EmojiSkinTone) -> Option<SkinTone> {
+    match skin_tone {
+        EmojiSkinTone::Default => None,
+        EmojiSkinTone::Light => Some(SkinTone::Light),
+        EmojiSkinTone::MediumLight => Some(SkinTone::MediumLight),
+        EmojiSkinTone::Medium => Some(SkinTone::Medium),
+        EmojiSkinTone::MediumDark => Some(SkinTone::MediumDark),
+        EmojiSkinTone::Dark => Some(SkinTone::Dark),
+    }
+}
+
+fn current_time_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("failed to get system time")
+        .as_secs_f64()
+}
+
+fn load_usage(usage_file: &Path) -> HashMap<String, CharacterUsage> {
+    let Ok(data) = std::fs::read(usage_file) else {
+        return HashMap::new();
+    };
+
+    match bincode::decode_from_slice(&data, bincode::config::standard()) {
+        Ok((usage, _)) => usage,
+        Err(err) => {
+            tracing::warn!("Unable to read emoji usage: {:?}", err);
+
+            HashMap::new()
+        }
+    }
+}
+
+fn save_usage(usage_file: &Path, usage: &HashMap<String, CharacterUsage>) -> anyhow::Result<()> {
+    let data = bincode::encode_to_vec(usage, bincode::config::standard())?;
+
+    std::fs::write(usage_file, data)?;
+
+    Ok(())
+}
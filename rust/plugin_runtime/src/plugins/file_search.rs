@@ -0,0 +1,420 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use anyhow::Context;
+use bincode::Decode;
+use bincode::Encode;
+use deno_core::op2;
+use deno_core::OpState;
+use gauntlet_common::detached_process::CommandExt;
+use globset::Glob;
+use globset::GlobSet;
+use globset::GlobSetBuilder;
+use serde::Serialize;
+use tokio::task::spawn_blocking;
+use walkdir::DirEntry;
+use walkdir::WalkDir;
+
+use crate::plugin_data::PluginData;
+
+const INDEX_FILE_NAME: &str = "file_search_index.bin";
+
+pub struct FileSearchContext {
+    // None until loaded from plugin cache dir or refreshed
+    index: Rc<RefCell<Option<FileIndex>>>,
+}
+
+impl FileSearchContext {
+    pub fn new() -> Self {
+        Self {
+            index: Rc::new(RefCell::new(None)),
+        }
+    }
+}
+
+#[derive(Default, Encode, Decode)]
+struct FileIndex {
+    // directory path -> directory data
+    directories: HashMap<String, IndexedDirectory>,
+}
+
+#[derive(Encode, Decode)]
+struct IndexedDirectory {
+    modified: u64,
+    // direct children which are not directories
+    files: Vec<IndexedFile>,
+}
+
+#[derive(Clone, Encode, Decode)]
+struct IndexedFile {
+    name: String,
+    modified: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FileSearchItem {
+    path: String,
+    name: String,
+    directory: bool,
+    modified: u64,
+}
+
+#[op2(async)]
+pub async fn file_search_refresh(
+    state: Rc<RefCell<OpState>>,
+    #[serde] roots: Vec<String>,
+    #[serde] ignore: Vec<String>,
+) -> anyhow::Result<()> {
+    let (index, index_file, home_dir) = {
+        let state = state.borrow();
+
+        let plugin_data = state.borrow::<PluginData>();
+
+        let index_file = PathBuf::from(plugin_data.plugin_cache_dir()).join(INDEX_FILE_NAME);
+        let home_dir = plugin_data.home_dir();
+
+        let index = state.borrow::<FileSearchContext>().index.clone();
+
+        (index, index_file, home_dir)
+    };
+
+    let old_index = index.borrow_mut().take();
+
+    let new_index = spawn_blocking(move || {
+        let old_index = old_index.unwrap_or_else(|| load_index(&index_file));
+
+        let roots = roots
+            .iter()
+            .map(|root| expand_home_dir(root, &home_dir))
+            .collect::<Vec<_>>();
+
+        let ignore = build_ignore_set(&ignore)?;
+
+        let new_index = refresh_index(old_index, &roots, &ignore);
+
+        if let Err(err) = save_index(&index_file, &new_index) {
+            tracing::warn!("Unable to save file search index: {:?}", err);
+        }
+
+        Ok::<_, anyhow::Error>(new_index)
+    })
+    .await??;
+
+    *index.borrow_mut() = Some(new_index);
+
+    Ok(())
+}
+
+/// Empty query returns most recently modified files
+#[op2]
+#[serde]
+pub fn file_search(state: Rc<RefCell<OpState>>, #[string] query: String, #[smi] limit: u32) -> Vec<FileSearchItem> {
+    let state = state.borrow();
+
+    let index_file = PathBuf::from(state.borrow::<PluginData>().plugin_cache_dir()).join(INDEX_FILE_NAME);
+
+    let mut index = state.borrow::<FileSearchContext>().index.borrow_mut();
+
+    // search can be done before first refresh finishes, use persisted index in that case
+    let index = index.get_or_insert_with(|| load_index(&index_file));
+
+    search_index(index, &query, limit as usize)
+}
+
+#[op2(fast)]
+pub fn file_search_open(#[string] path: String) -> anyhow::Result<()> {
+    open::that_detached(path)?;
+
+    Ok(())
+}
+
+#[op2(fast)]
+pub fn file_search_reveal(#[string] path: String) -> anyhow::Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        let uri = format!("file://{}", path);
+
+        let result = std::process::Command::new("dbus-send")
+            .args([
+                "--session",
+                "--dest=org.freedesktop.FileManager1",
+                "--type=method_call",
+                "/org/freedesktop/FileManager1",
+                "org.freedesktop.FileManager1.ShowItems",
+                &format!("array:string:{}", uri),
+                "string:",
+            ])
+            .spawn_detached();
+
+        // dbus-send is not always installed, fallback to opening parent directory
+        if let Err(err) = result {
+            tracing::debug!("Unable to reveal file using FileManager1 interface: {:?}", err);
+
+            let parent = Path::new(&path).parent().unwrap_or(Path::new("/"));
+
+            open::that_detached(parent)?;
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    std::process::Command::new("open")
+        .args(["-R", &path])
+        .spawn_detached()?;
+
+    #[cfg(target_os = "windows")]
+    std::process::Command::new("explorer")
+        .arg(format!("/select,{}", path))
+        .spawn_detached()?;
+
+    Ok(())
+}
+
+fn expand_home_dir(root: &str, home_dir: &Path) -> PathBuf {
+    match root.strip_prefix("~") {
+        Some(rest) => home_dir.join(rest.trim_start_matches(['/', '\\'])),
+        None => PathBuf::from(root),
+    }
+}
+
+fn build_ignore_set(ignore: &[String]) -> anyhow::Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in ignore {
+        let glob = Glob::new(pattern).with_context(|| format!("Invalid ignore pattern: {}", pattern))?;
+
+        builder.add(glob);
+    }
+
+    Ok(builder.build()?)
+}
+
+fn modified_secs(entry: &DirEntry) -> Option<u64> {
+    let modified = entry.metadata().ok()?.modified().ok()?;
+
+    modified
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .ok()
+        .map(|duration| duration.as_secs())
+}
+
+fn refresh_index(mut old_index: FileIndex, roots: &[PathBuf], ignore: &GlobSet) -> FileIndex {
+    let mut directories: HashMap<String, IndexedDirectory> = HashMap::new();
+
+    // files of directories which were not modified since last refresh are reused
+    // instead of reading metadata of each of them again
+    let mut unchanged_directories = HashSet::new();
+
+    for root in roots {
+        let walker = WalkDir::new(root)
+            .follow_links(false)
+            .into_iter()
+            .filter_entry(|entry| entry.depth() == 0 || !ignore.is_match(entry.path()));
+
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    tracing::debug!("Unable to read entry while indexing files: {:?}", err);
+                    continue;
+                }
+            };
+
+            let Some(path) = entry.path().to_str() else {
+                continue;
+            };
+
+            if entry.file_type().is_dir() {
+                let modified = modified_secs(&entry).unwrap_or_default();
+
+                let directory = match old_index.directories.remove(path) {
+                    Some(directory) if directory.modified == modified => {
+                        unchanged_directories.insert(path.to_string());
+
+                        directory
+                    }
+                    _ => {
+                        IndexedDirectory {
+                            modified,
+                            files: vec![],
+                        }
+                    }
+                };
+
+                directories.insert(path.to_string(), directory);
+            } else {
+                let Some(parent) = entry.path().parent().and_then(|parent| parent.to_str()) else {
+                    continue;
+                };
+
+                if unchanged_directories.contains(parent) {
+                    continue;
+                }
+
+                let Some(directory) = directories.get_mut(parent) else {
+                    continue;
+                };
+
+                directory.files.push(IndexedFile {
+                    name: entry.file_name().to_string_lossy().to_string(),
+                    modified: modified_secs(&entry).unwrap_or_default(),
+                });
+            }
+        }
+    }
+
+    FileIndex { directories }
+}
+
+fn search_index(index: &FileIndex, query: &str, limit: usize) -> Vec<FileSearchItem> {
+    let query = query.to_lowercase();
+
+    let query_words = query.split_whitespace().collect::<Vec<_>>();
+
+    let mut result = vec![];
+
+    for (directory_path, directory) in &index.directories {
+        let directory_path = Path::new(directory_path);
+
+        let directory_name = directory_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if let Some(score) = match_score(&directory_name, &query, &query_words) {
+            result.push((
+                score,
+                FileSearchItem {
+                    path: directory_path.to_string_lossy().to_string(),
+                    name: directory_name,
+                    directory: true,
+                    modified: directory.modified,
+                },
+            ));
+        }
+
+        for file in &directory.files {
+            if let Some(score) = match_score(&file.name, &query, &query_words) {
+                result.push((
+                    score,
+                    FileSearchItem {
+                        path: directory_path.join(&file.name).to_string_lossy().to_string(),
+                        name: file.name.clone(),
+                        directory: false,
+                        modified: file.modified,
+                    },
+                ));
+            }
+        }
+    }
+
+    result.sort_by(|(score_a, item_a), (score_b, item_b)| {
+        score_b.cmp(score_a).then(item_b.modified.cmp(&item_a.modified))
+    });
+
+    result.into_iter().take(limit).map(|(_, item)| item).collect()
+}
+
+// every word of query needs to be present in the name, names starting with the query are ranked higher
+fn match_score(name: &str, query: &str, query_words: &[&str]) -> Option<u8> {
+    let name = name.to_lowercase();
+
+    if !query_words.iter().all(|word| name.contains(word)) {
+        return None;
+    }
+
+    if name == query {
+        Some(2)
+    } else if name.starts_with(query) {
+        Some(1)
+    } else {
+        Some(0)
+    }
+}
+
+fn load_index(index_file: &Path) -> FileIndex {
+    let Ok(data) = std::fs::read(index_file) else {
+        return FileIndex::default();
+    };
+
+    match bincode::decode_from_slice(&data, bincode::config::standard()) {
+        Ok((index, _)) => index,
+        Err(err) => {
+            tracing::warn!("Unable to read file search index, it will be rebuilt: {:?}", err);
+
+            FileIndex::default()
+        }
+    }
+}
+
+fn save_index(index_file: &Path, index: &FileIndex) -> anyhow::Result<()> {
+    let data = bincode::encode_to_vec(index, bincode::config::standard())?;
+
+    std::fs::write(index_file, data)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignore_patterns() {
+        let ignore = build_ignore_set(&["**/node_modules".to_string(), "**/.*".to_string()]).unwrap();
+
+        assert!(ignore.is_match("/home/user/project/node_modules"));
+        assert!(ignore.is_match("/home/user/.cache"));
+        assert!(!ignore.is_match("/home/user/project/src"));
+
+        assert!(build_ignore_set(&["a[".to_string()]).is_err());
+    }
+
+    #[test]
+    fn index_directory_tree() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        std::fs::create_dir_all(root.join("docs/reports")).unwrap();
+        std::fs::create_dir_all(root.join("node_modules/package")).unwrap();
+        std::fs::write(root.join("docs/notes.txt"), "").unwrap();
+        std::fs::write(root.join("docs/reports/Report 2024.pdf"), "").unwrap();
+        std::fs::write(root.join("node_modules/package/index.js"), "").unwrap();
+
+        let ignore = build_ignore_set(&["**/node_modules".to_string()]).unwrap();
+
+        let index = refresh_index(FileIndex::default(), &[root.clone()], &ignore);
+
+        let mut directories: Vec<_> = index.directories.keys().cloned().collect();
+        directories.sort();
+
+        let expected: Vec<_> = [root.clone(), root.join("docs"), root.join("docs/reports")]
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect();
+
+        assert_eq!(directories, expected);
+
+        let names = |query: &str| {
+            search_index(&index, query, 10)
+                .into_iter()
+                .map(|item| item.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names("report 2024"), vec!["Report 2024.pdf"]);
+        assert_eq!(names("notes"), vec!["notes.txt"]);
+        assert!(names("index").is_empty());
+
+        // unchanged directories keep files from previous index
+        let index = refresh_index(index, &[root.clone()], &ignore);
+
+        let docs = &index.directories[&root.join("docs").to_string_lossy().to_string()];
+
+        assert_eq!(docs.files.len(), 1);
+    }
+}
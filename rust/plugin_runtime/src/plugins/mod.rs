@@ -1,3 +1,9 @@
 pub mod applications;
+pub mod clipboard_history;
+pub mod emoji;
+pub mod file_search;
 pub mod numbat;
 pub mod settings;
+pub mod snippets;
+#[cfg(target_os = "linux")]
+pub mod system_actions;
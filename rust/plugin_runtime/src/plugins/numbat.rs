@@ -1,9 +1,14 @@
 use std::cell::RefCell;
+use std::path::Path;
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::SystemTime;
 
-use anyhow::anyhow;
+use bincode::Decode;
+use bincode::Encode;
 use deno_core::op2;
 use deno_core::OpState;
+use numbat::diagnostic::ErrorDiagnostic;
 use numbat::markup::Formatter;
 use numbat::markup::PlainTextFormatter;
 use numbat::module_importer::BuiltinModuleImporter;
@@ -11,36 +16,197 @@ use numbat::pretty_print::PrettyPrint;
 use numbat::resolver::CodeSource;
 use numbat::Context;
 use numbat::InterpreterResult;
+use numbat::Statement;
 use serde::Serialize;
 
+const HISTORY_FILE_NAME: &str = "calculator_history.bin";
+const HISTORY_LIMIT: usize = 200;
+const EXCHANGE_RATES_FILE_NAME: &str = "exchange_rates.xml";
+// the same feed numbat uses
+const EXCHANGE_RATES_URL: &str = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml";
+// holds value of `ans` from persistent context inside of preview context
+const PREVIEW_ANS_VARIABLE: &str = "__gauntlet_ans";
+
 #[derive(Clone)]
-pub struct NumbatContext(Rc<RefCell<Context>>);
+pub struct NumbatContext {
+    context: Rc<RefCell<Context>>,
+    // copy of persistent context used for previews, prepared once and reused until it diverges
+    preview: Rc<RefCell<Option<PreviewContext>>>,
+    history: Rc<RefCell<Vec<CalculatorHistoryEntry>>>,
+    history_file: PathBuf,
+}
 
 impl NumbatContext {
-    pub fn new() -> NumbatContext {
+    pub fn new(plugin_data_dir: PathBuf, plugin_cache_dir: PathBuf, calculator_file: PathBuf) -> NumbatContext {
         let mut context = Context::new(BuiltinModuleImporter::default());
 
         context.load_currency_module_on_demand(true);
 
-        if cfg!(feature = "release") {
-            Context::prefetch_exchange_rates();
-        }
+        load_exchange_rates(plugin_cache_dir.join(EXCHANGE_RATES_FILE_NAME));
 
         let _ = context.interpret("use prelude", CodeSource::Internal);
 
-        NumbatContext(Rc::new(RefCell::new(context)))
+        load_user_definitions(&mut context, &calculator_file);
+
+        let history_file = plugin_data_dir.join(HISTORY_FILE_NAME);
+
+        let history = load_history(&history_file);
+
+        restore_history_state(&mut context, &history);
+
+        NumbatContext {
+            context: Rc::new(RefCell::new(context)),
+            preview: Rc::new(RefCell::new(None)),
+            history: Rc::new(RefCell::new(history)),
+            history_file,
+        }
     }
 }
 
-#[derive(Debug, Serialize)]
-struct NumbatResult {
+struct PreviewContext {
+    context: Context,
+    // false if persistent context doesn't have `ans` yet
+    has_ans: bool,
+}
+
+impl PreviewContext {
+    fn new(context: &Context) -> PreviewContext {
+        let mut context = context.clone();
+
+        let has_ans = context
+            .interpret(&format!("let {} = ans", PREVIEW_ANS_VARIABLE), CodeSource::Internal)
+            .is_ok();
+
+        PreviewContext { context, has_ans }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Encode, Decode)]
+pub struct CalculatorHistoryEntry {
+    input: String,
     left: String,
     right: String,
+    created_at: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum NumbatResult {
+    #[serde(rename = "value")]
+    Value { left: String, right: String },
+    // start and end are char offsets in input
+    #[serde(rename = "error")]
+    Error {
+        message: String,
+        start: Option<usize>,
+        end: Option<usize>,
+    },
+}
+
+/// Evaluates input without changing calculator state, used for preview on every key press
+#[op2]
+#[serde]
+pub fn run_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> NumbatResult {
+    let context = {
+        let state = state.borrow();
+
+        let context = state.borrow::<NumbatContext>().clone();
+
+        context
+    };
+
+    let mut preview = context.preview.borrow_mut();
+
+    let PreviewContext {
+        context: preview_context,
+        has_ans,
+    } = preview.get_or_insert_with(|| PreviewContext::new(&context.context.borrow()));
+
+    let (result, definitions) = evaluate(preview_context, &input);
+
+    if definitions {
+        // definitions can't be undone, prepare clean copy for the next preview
+        *preview = None;
+    } else if let NumbatResult::Value { .. } = result {
+        // every value overwrites `ans`, restore the one from persistent context
+        if *has_ans {
+            let _ = preview_context.interpret(PREVIEW_ANS_VARIABLE, CodeSource::Internal);
+        } else {
+            *preview = None;
+        }
+    }
+
+    result
+}
+
+/// Evaluates input in persistent calculator state, making its result available as `ans`
+/// and its variables available in following calculations, and adds it to history
+#[op2]
+#[serde]
+pub fn numbat_save_history(state: Rc<RefCell<OpState>>, #[string] input: String) -> anyhow::Result<NumbatResult> {
+    let context = {
+        let state = state.borrow();
+
+        let context = state.borrow::<NumbatContext>().clone();
+
+        context
+    };
+
+    let (result, _) = evaluate(&mut context.context.borrow_mut(), &input);
+
+    // persistent context has changed, preview context is prepared again on next preview
+    *context.preview.borrow_mut() = None;
+
+    if let NumbatResult::Value { left, right } = &result {
+        let mut history = context.history.borrow_mut();
+
+        history.push(CalculatorHistoryEntry {
+            input: input.clone(),
+            left: left.clone(),
+            right: right.clone(),
+            created_at: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .expect("failed to get system time")
+                .as_secs_f64(),
+        });
+
+        if history.len() > HISTORY_LIMIT {
+            let excess = history.len() - HISTORY_LIMIT;
+            history.drain(..excess);
+        }
+
+        save_history(&context.history_file, &history)?;
+    }
+
+    Ok(result)
 }
 
+/// Most recent entries first
 #[op2]
 #[serde]
-pub fn run_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> anyhow::Result<NumbatResult> {
+pub fn numbat_history(state: Rc<RefCell<OpState>>, #[string] query: String) -> Vec<CalculatorHistoryEntry> {
+    let context = {
+        let state = state.borrow();
+
+        let context = state.borrow::<NumbatContext>().clone();
+
+        context
+    };
+
+    let query = query.to_lowercase();
+
+    let history = context.history.borrow();
+
+    history
+        .iter()
+        .rev()
+        .filter(|entry| entry.input.to_lowercase().contains(&query) || entry.right.to_lowercase().contains(&query))
+        .cloned()
+        .collect()
+}
+
+#[op2(fast)]
+pub fn numbat_clear_history(state: Rc<RefCell<OpState>>) -> anyhow::Result<()> {
     let context = {
         let state = state.borrow();
 
@@ -49,9 +215,42 @@ pub fn run_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> anyho
         context
     };
 
-    let mut context = context.0.borrow_mut();
+    let mut history = context.history.borrow_mut();
 
-    let (statements, result) = context.interpret(&input, CodeSource::Text)?;
+    history.clear();
+
+    save_history(&context.history_file, &history)
+}
+
+// also returns whether input contained anything else than expressions, e.g. variable or unit definitions
+fn evaluate(context: &mut Context, input: &str) -> (NumbatResult, bool) {
+    let (statements, result) = match context.interpret(input, CodeSource::Text) {
+        Ok(result) => result,
+        Err(err) => {
+            let range = err
+                .diagnostics()
+                .into_iter()
+                .flat_map(|diagnostic| diagnostic.labels)
+                .map(|label| label.range)
+                .next();
+
+            // numbat reports byte offsets
+            let to_char_offset = |byte_offset: usize| input.get(..byte_offset).map(|text| text.chars().count());
+
+            let result = NumbatResult::Error {
+                message: err.to_string(),
+                start: range.as_ref().and_then(|range| to_char_offset(range.start)),
+                end: range.as_ref().and_then(|range| to_char_offset(range.end)),
+            };
+
+            // numbat reverts its state if input fails to evaluate
+            return (result, false);
+        }
+    };
+
+    let definitions = statements
+        .iter()
+        .any(|statement| !matches!(statement, Statement::Expression(_)));
 
     let formatter = PlainTextFormatter;
 
@@ -62,13 +261,100 @@ pub fn run_numbat(state: Rc<RefCell<OpState>>, #[string] input: String) -> anyho
         .join(" ")
         .replace('➞', "to");
 
-    let value = match result {
-        InterpreterResult::Value(value) => format!("{}", value.pretty_print()),
-        InterpreterResult::Continue => Err(anyhow!("numbat returned Continue"))?,
+    let result = match result {
+        InterpreterResult::Value(value) => {
+            NumbatResult::Value {
+                left: expression,
+                right: format!("{}", value.pretty_print()),
+            }
+        }
+        InterpreterResult::Continue => {
+            // statements without value, e.g. variable definition
+            NumbatResult::Error {
+                message: "Expression doesn't produce a value".to_string(),
+                start: None,
+                end: None,
+            }
+        }
+    };
+
+    (result, definitions)
+}
+
+fn load_user_definitions(context: &mut Context, calculator_file: &Path) {
+    let Ok(code) = std::fs::read_to_string(calculator_file) else {
+        return;
+    };
+
+    if let Err(err) = context.interpret(&code, CodeSource::File(calculator_file.to_path_buf())) {
+        tracing::warn!(
+            "Unable to load calculator definitions from {:?}: {}",
+            calculator_file,
+            err
+        );
+    }
+}
+
+// restores variables defined in previous sessions and `ans`
+fn restore_history_state(context: &mut Context, history: &[CalculatorHistoryEntry]) {
+    let last_index = history.len().checked_sub(1);
+
+    for (index, entry) in history.iter().enumerate() {
+        if entry.input.trim_start().starts_with("let ") || Some(index) == last_index {
+            let _ = context.interpret(&entry.input, CodeSource::Text);
+        }
+    }
+}
+
+fn load_exchange_rates(exchange_rates_file: PathBuf) {
+    // cached rates make currency conversion available right away and without network
+    if let Ok(xml) = std::fs::read_to_string(&exchange_rates_file) {
+        Context::set_exchange_rates(&xml);
+    }
+
+    if cfg!(feature = "release") {
+        std::thread::spawn(move || {
+            match fetch_exchange_rates() {
+                Ok(xml) => {
+                    Context::set_exchange_rates(&xml);
+
+                    if let Err(err) = std::fs::write(&exchange_rates_file, xml) {
+                        tracing::warn!("Unable to save exchange rates cache: {:?}", err);
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("Unable to fetch exchange rates: {:?}", err);
+                }
+            }
+        });
+    }
+}
+
+fn fetch_exchange_rates() -> anyhow::Result<String> {
+    let xml = ureq::get(EXCHANGE_RATES_URL).call()?.into_string()?;
+
+    Ok(xml)
+}
+
+fn load_history(history_file: &Path) -> Vec<CalculatorHistoryEntry> {
+    let Ok(data) = std::fs::read(history_file) else {
+        return vec![];
     };
 
-    Ok(NumbatResult {
-        left: expression,
-        right: value,
-    })
+    match bincode::decode_from_slice(&data, bincode::config::standard()) {
+        Ok((history, _)) => history,
+        Err(err) => {
+            tracing::warn!("Unable to read calculator history: {:?}", err);
+
+            vec![]
+        }
+    }
+}
+
+fn save_history(history_file: &Path, history: &[CalculatorHistoryEntry]) -> anyhow::Result<()> {
+    let data = bincode::encode_to_vec(history, bincode::config::standard())?;
+
+    std::fs::write(history_file, data)?;
+
+    Ok(())
 }
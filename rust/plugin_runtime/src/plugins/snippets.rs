@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use deno_core::op2;
+use deno_core::OpState;
+
+use crate::api::BackendForPluginRuntimeApi;
+use crate::api::BackendForPluginRuntimeApiProxy;
+use crate::JsSnippet;
+
+#[op2(async)]
+#[serde]
+pub async fn snippets_list(state: Rc<RefCell<OpState>>, #[string] query: String) -> anyhow::Result<Vec<JsSnippet>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.snippets_list(query).await.map_err(Into::into)
+}
+
+/// Creates new snippet if id is not specified, returns id of the snippet
+#[op2(async)]
+#[number]
+pub async fn snippet_save(
+    state: Rc<RefCell<OpState>>,
+    #[serde] id: Option<i64>,
+    #[string] name: String,
+    #[string] text: String,
+) -> anyhow::Result<i64> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.snippet_save(id, name, text).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn snippet_remove(state: Rc<RefCell<OpState>>, #[number] id: i64) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.snippet_remove(id).await.map_err(Into::into)
+}
@@ -0,0 +1,231 @@
+use anyhow::anyhow;
+use anyhow::Context;
+use deno_core::op2;
+use serde::Deserialize;
+use zbus::zvariant::OwnedObjectPath;
+use zbus::Connection;
+
+#[zbus::proxy(
+    interface = "org.freedesktop.login1.Manager",
+    default_service = "org.freedesktop.login1",
+    default_path = "/org/freedesktop/login1"
+)]
+trait LoginManager {
+    fn get_session(&self, session_id: &str) -> zbus::Result<OwnedObjectPath>;
+
+    #[zbus(name = "GetSessionByPID")]
+    fn get_session_by_pid(&self, pid: u32) -> zbus::Result<OwnedObjectPath>;
+
+    fn suspend(&self, interactive: bool) -> zbus::Result<()>;
+
+    fn reboot(&self, interactive: bool) -> zbus::Result<()>;
+
+    fn power_off(&self, interactive: bool) -> zbus::Result<()>;
+}
+
+#[zbus::proxy(
+    interface = "org.freedesktop.login1.Session",
+    default_service = "org.freedesktop.login1"
+)]
+trait LoginSession {
+    fn lock(&self) -> zbus::Result<()>;
+
+    fn terminate(&self) -> zbus::Result<()>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum SystemAction {
+    #[serde(rename = "lock")]
+    Lock,
+    #[serde(rename = "log_out")]
+    LogOut,
+    #[serde(rename = "suspend")]
+    Suspend,
+    #[serde(rename = "reboot")]
+    Reboot,
+    #[serde(rename = "power_off")]
+    PowerOff,
+}
+
+#[op2(async)]
+pub async fn linux_system_action(#[serde] action: SystemAction) -> anyhow::Result<()> {
+    let connection = Connection::system()
+        .await
+        .context("Unable to connect to system D-Bus")?;
+
+    let session_id = std::env::var("XDG_SESSION_ID").ok();
+
+    run_system_action(&connection, action, session_id.as_deref())
+        .await
+        .with_context(|| format!("Unable to run system action {:?}", action))
+}
+
+pub async fn run_system_action(
+    connection: &Connection,
+    action: SystemAction,
+    session_id: Option<&str>,
+) -> anyhow::Result<()> {
+    let manager = LoginManagerProxy::new(connection).await?;
+
+    // interactive means polkit is allowed to ask user for password if needed
+    match action {
+        SystemAction::Lock => {
+            let session = current_session(connection, &manager, session_id).await?;
+
+            session.lock().await?;
+        }
+        SystemAction::LogOut => {
+            let session = current_session(connection, &manager, session_id).await?;
+
+            session.terminate().await?;
+        }
+        SystemAction::Suspend => {
+            manager.suspend(true).await?;
+        }
+        SystemAction::Reboot => {
+            manager.reboot(true).await?;
+        }
+        SystemAction::PowerOff => {
+            manager.power_off(true).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn current_session<'a>(
+    connection: &Connection,
+    manager: &LoginManagerProxy<'a>,
+    session_id: Option<&str>,
+) -> anyhow::Result<LoginSessionProxy<'a>> {
+    // when server is started as systemd user service, its process doesn't belong to any session,
+    // so session id from environment is preferred
+    let session_path = match session_id {
+        Some(session_id) => manager.get_session(session_id).await?,
+        None => {
+            let pid = std::process::id();
+
+            manager
+                .get_session_by_pid(pid)
+                .await
+                .map_err(|err| anyhow!("Unable to find login session of process {}: {}", pid, err))?
+        }
+    };
+
+    let session = LoginSessionProxy::builder(connection)
+        .path(session_path)?
+        .build()
+        .await?;
+
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::net::UnixStream;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use zbus::connection::Builder;
+    use zbus::zvariant::ObjectPath;
+    use zbus::Guid;
+
+    use super::*;
+
+    const SESSION_PATH: &str = "/org/freedesktop/login1/session/_31";
+
+    type Calls = Arc<Mutex<Vec<String>>>;
+
+    struct MockManager {
+        calls: Calls,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.login1.Manager")]
+    impl MockManager {
+        fn get_session(&self, session_id: &str) -> zbus::fdo::Result<OwnedObjectPath> {
+            self.calls.lock().unwrap().push(format!("GetSession({})", session_id));
+
+            Ok(ObjectPath::try_from(SESSION_PATH).unwrap().into())
+        }
+
+        #[zbus(name = "GetSessionByPID")]
+        fn get_session_by_pid(&self, _pid: u32) -> zbus::fdo::Result<OwnedObjectPath> {
+            self.calls.lock().unwrap().push("GetSessionByPID".to_string());
+
+            Ok(ObjectPath::try_from(SESSION_PATH).unwrap().into())
+        }
+
+        fn suspend(&self, interactive: bool) {
+            self.calls.lock().unwrap().push(format!("Suspend({})", interactive));
+        }
+
+        fn reboot(&self, interactive: bool) {
+            self.calls.lock().unwrap().push(format!("Reboot({})", interactive));
+        }
+
+        fn power_off(&self, interactive: bool) {
+            self.calls.lock().unwrap().push(format!("PowerOff({})", interactive));
+        }
+    }
+
+    struct MockSession {
+        calls: Calls,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.login1.Session")]
+    impl MockSession {
+        fn lock(&self) {
+            self.calls.lock().unwrap().push("Lock".to_string());
+        }
+
+        fn terminate(&self) {
+            self.calls.lock().unwrap().push("Terminate".to_string());
+        }
+    }
+
+    fn run(action: SystemAction, session_id: Option<&str>) -> Vec<String> {
+        let calls = Calls::default();
+
+        futures::executor::block_on(async {
+            let (client_stream, server_stream) = UnixStream::pair().unwrap();
+
+            let server = Builder::unix_stream(server_stream)
+                .server(Guid::generate())
+                .unwrap()
+                .p2p()
+                .serve_at("/org/freedesktop/login1", MockManager { calls: calls.clone() })
+                .unwrap()
+                .serve_at(SESSION_PATH, MockSession { calls: calls.clone() })
+                .unwrap()
+                .build();
+
+            let client = Builder::unix_stream(client_stream).p2p().build();
+
+            let (client, _server) = futures::try_join!(client, server).unwrap();
+
+            run_system_action(&client, action, session_id).await.unwrap();
+        });
+
+        let calls = calls.lock().unwrap().clone();
+
+        calls
+    }
+
+    #[test]
+    fn test_power_actions() {
+        assert_eq!(run(SystemAction::Suspend, None), vec!["Suspend(true)"]);
+        assert_eq!(run(SystemAction::Reboot, None), vec!["Reboot(true)"]);
+        assert_eq!(run(SystemAction::PowerOff, None), vec!["PowerOff(true)"]);
+    }
+
+    #[test]
+    fn test_session_actions_use_session_id() {
+        assert_eq!(run(SystemAction::Lock, Some("1")), vec!["GetSession(1)", "Lock"]);
+        assert_eq!(run(SystemAction::LogOut, Some("1")), vec!["GetSession(1)", "Terminate"]);
+    }
+
+    #[test]
+    fn test_session_actions_fallback_to_pid() {
+        assert_eq!(run(SystemAction::Lock, None), vec!["GetSessionByPID", "Lock"]);
+    }
+}
@@ -34,6 +34,8 @@ pub async fn reload_search_index(
                 entrypoint_icon: item.entrypoint_icon.map(|buffer| buffer.to_vec()),
                 entrypoint_actions: item.entrypoint_actions,
                 entrypoint_accessories: item.entrypoint_accessories,
+                entrypoint_keywords: item.entrypoint_keywords,
+                entrypoint_application: item.entrypoint_application,
             }
         })
         .collect();
@@ -26,6 +26,7 @@ use gauntlet_common::model::DatePickerWidget;
 use gauntlet_common::model::DetailWidget;
 use gauntlet_common::model::EmptyViewWidget;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::FilePickerWidget;
 use gauntlet_common::model::FormWidget;
 use gauntlet_common::model::FormWidgetOrderedMembers;
 use gauntlet_common::model::GridItemWidget;
@@ -52,6 +53,7 @@ use gauntlet_common::model::ListSectionWidget;
 use gauntlet_common::model::ListSectionWidgetOrderedMembers;
 use gauntlet_common::model::ListWidget;
 use gauntlet_common::model::ListWidgetOrderedMembers;
+use gauntlet_common::model::MarkdownWidget;
 use gauntlet_common::model::MetadataIconWidget;
 use gauntlet_common::model::MetadataLinkWidget;
 use gauntlet_common::model::MetadataSeparatorWidget;
@@ -61,6 +63,7 @@ use gauntlet_common::model::MetadataTagListWidgetOrderedMembers;
 use gauntlet_common::model::MetadataValueWidget;
 use gauntlet_common::model::MetadataWidget;
 use gauntlet_common::model::MetadataWidgetOrderedMembers;
+use gauntlet_common::model::NumberFieldWidget;
 use gauntlet_common::model::ParagraphWidget;
 use gauntlet_common::model::PasswordFieldWidget;
 use gauntlet_common::model::PhysicalKey;
@@ -72,7 +75,18 @@ use gauntlet_common::model::SelectItemWidget;
 use gauntlet_common::model::SelectWidget;
 use gauntlet_common::model::SelectWidgetOrderedMembers;
 use gauntlet_common::model::SeparatorWidget;
+use gauntlet_common::model::TableCellAccessories;
+use gauntlet_common::model::TableCellWidget;
+use gauntlet_common::model::TableColumnWidget;
+use gauntlet_common::model::TableRowWidget;
+use gauntlet_common::model::TableRowWidgetOrderedMembers;
+use gauntlet_common::model::TableWidget;
+use gauntlet_common::model::TableWidgetOrderedMembers;
+use gauntlet_common::model::TagPickerItemWidget;
+use gauntlet_common::model::TagPickerWidget;
+use gauntlet_common::model::TagPickerWidgetOrderedMembers;
 use gauntlet_common::model::TextAccessoryWidget;
+use gauntlet_common::model::TextAreaWidget;
 use gauntlet_common::model::TextFieldWidget;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiRenderLocation;
@@ -94,6 +108,8 @@ use tokio::runtime::Handle;
 use crate::api::BackendForPluginRuntimeApi;
 use crate::api::BackendForPluginRuntimeApiProxy;
 use crate::component_model::ComponentModel;
+use crate::model::JsConfirmAlert;
+use crate::model::JsToast;
 use crate::model::JsUiRenderLocation;
 use crate::plugin_data::PluginData;
 
@@ -276,6 +292,45 @@ pub async fn show_hud(state: Rc<RefCell<OpState>>, #[string] display: String) ->
     api.ui_show_hud(display).await.map_err(Into::into)
 }
 
+#[op2(async)]
+pub async fn show_toast(state: Rc<RefCell<OpState>>, #[serde] toast: JsToast) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.ui_show_toast(toast).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn hide_toast(state: Rc<RefCell<OpState>>, toast_id: u32) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.ui_hide_toast(toast_id).await.map_err(Into::into)
+}
+
+#[op2(async)]
+pub async fn show_confirm_alert(state: Rc<RefCell<OpState>>, #[serde] alert: JsConfirmAlert) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.ui_show_confirm_alert(alert).await.map_err(Into::into)
+}
+
 #[op2(async)]
 pub async fn hide_window(state: Rc<RefCell<OpState>>) -> anyhow::Result<()> {
     let api = {
@@ -289,6 +344,25 @@ pub async fn hide_window(state: Rc<RefCell<OpState>>) -> anyhow::Result<()> {
     api.ui_hide_window().await.map_err(Into::into)
 }
 
+#[op2(async)]
+pub async fn paste_to_active_window(
+    state: Rc<RefCell<OpState>>,
+    #[string] text: String,
+    cursor_offset_from_end: u32,
+) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.ui_paste_to_active_window(text, cursor_offset_from_end)
+        .await
+        .map_err(Into::into)
+}
+
 #[op2(async)]
 pub async fn update_loading_bar(
     state: Rc<RefCell<OpState>>,
@@ -155,8 +155,13 @@ async fn request_loop(
         match request_data {
             FrontendApiRequestData::UpdateLoadingBar { .. }
             | FrontendApiRequestData::ShowHud { .. }
+            | FrontendApiRequestData::ShowToast { .. }
+            | FrontendApiRequestData::HideToast { .. }
+            | FrontendApiRequestData::ClearToasts { .. }
+            | FrontendApiRequestData::ShowConfirmAlert { .. }
             | FrontendApiRequestData::ShowWindow {}
             | FrontendApiRequestData::HideWindow {}
+            | FrontendApiRequestData::PasteToActiveWindow { .. }
             | FrontendApiRequestData::ClearInlineView { .. }
             | FrontendApiRequestData::SetTheme { .. }
             | FrontendApiRequestData::OpenPluginView { .. }
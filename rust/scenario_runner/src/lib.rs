@@ -1,3 +1,4 @@
+use gauntlet_common::model::NavigationKeymap;
 use gauntlet_common::model::UiSetupData;
 use gauntlet_common::model::UiTheme;
 use gauntlet_common::model::WindowPositionMode;
@@ -29,10 +30,17 @@ pub async fn run_scenario_runner_mock_server(
     responder.respond(Ok(BackendForFrontendApiResponseData::SetupData {
         data: UiSetupData {
             window_position_file: None,
+            window_position_per_monitor_file: None,
+            window_width: 750.0,
+            window_height: 450.0,
+            max_visible_results: None,
             theme,
             global_shortcut: None,
             global_entrypoint_shortcuts: Default::default(),
             close_on_unfocus: false,
+            restore_last_query: false,
+            navigation_keymap: NavigationKeymap::Default,
+            keybindings: Default::default(),
             window_position_mode: WindowPositionMode::Static,
         },
     }));
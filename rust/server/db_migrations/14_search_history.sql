@@ -0,0 +1,7 @@
+CREATE TABLE search_history
+(
+    query     TEXT NOT NULL,
+    last_used REAL NOT NULL,
+
+    PRIMARY KEY (query)
+);
@@ -0,0 +1,10 @@
+CREATE TABLE clipboard_history
+(
+    id           INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    text_data    TEXT,
+    png_data     BLOB,
+    image_width  INTEGER,
+    image_height INTEGER,
+    pinned       INTEGER NOT NULL DEFAULT FALSE,
+    created_at   REAL    NOT NULL
+);
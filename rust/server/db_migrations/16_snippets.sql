@@ -0,0 +1,8 @@
+CREATE TABLE snippet
+(
+    id         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name       TEXT    NOT NULL,
+    text       TEXT    NOT NULL,
+    created_at REAL    NOT NULL,
+    updated_at REAL    NOT NULL
+);
@@ -247,6 +247,9 @@ async fn run_server(
 
     application_manager.reload_all_plugins().await?;
 
+    #[cfg(not(feature = "scenario_runner"))]
+    application_manager.start_clipboard_history();
+
     tokio::spawn({
         let application_manager = application_manager.clone();
 
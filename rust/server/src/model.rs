@@ -31,6 +31,14 @@ pub enum IntermediateUiEvent {
         modifier_alt: bool,
         modifier_meta: bool,
     },
+    HandleToastAction {
+        toast_id: u32,
+        action_index: usize,
+    },
+    HandleConfirmAlert {
+        alert_id: u32,
+        confirmed: bool,
+    },
     OpenInlineView {
         text: String,
     },
@@ -1,3 +1,6 @@
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::io::Cursor;
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -9,6 +12,10 @@ use arboard::ImageData;
 use gauntlet_plugin_runtime::JsClipboardData;
 use image::RgbaImage;
 
+// convention introduced by KDE Klipper, set by KeePassXC and other password managers
+#[cfg(target_os = "linux")]
+const PASSWORD_MANAGER_HINT_MIME: &str = "x-kde-passwordManagerHint";
+
 #[derive(Clone)]
 pub struct Clipboard {
     clipboard: Arc<RwLock<arboard::Clipboard>>,
@@ -64,6 +71,79 @@ impl Clipboard {
         Ok(JsClipboardData { text_data, png_data })
     }
 
+    /// Changes when clipboard content changes. Text is checked first and image is read only if there is no text,
+    /// because getting an image converts and copies the whole bitmap, which is slow for large images like screenshots
+    pub fn content_hash(&self) -> anyhow::Result<Option<u64>> {
+        let mut clipboard = self.clipboard.write().expect("lock is poisoned");
+
+        let mut hasher = DefaultHasher::new();
+
+        match clipboard.get_text() {
+            Ok(data) => {
+                data.hash(&mut hasher);
+
+                return Ok(Some(hasher.finish()));
+            }
+            Err(err) => {
+                match err {
+                    arboard::Error::ContentNotAvailable => {}
+                    err @ _ => {
+                        return Err(unknown_err_clipboard(err));
+                    }
+                }
+            }
+        }
+
+        match clipboard.get_image() {
+            Ok(data) => {
+                data.width.hash(&mut hasher);
+                data.height.hash(&mut hasher);
+                data.bytes.hash(&mut hasher);
+
+                Ok(Some(hasher.finish()))
+            }
+            Err(err) => {
+                match err {
+                    arboard::Error::ContentNotAvailable => Ok(None),
+                    err @ _ => Err(unknown_err_clipboard(err)),
+                }
+            }
+        }
+    }
+
+    /// Whether current content is marked as secret by password manager.
+    /// On Linux requires `wl-paste` on Wayland or `xclip` on X11, returns error if it is not installed
+    #[cfg(target_os = "linux")]
+    pub fn is_concealed(&self) -> anyhow::Result<bool> {
+        // arboard doesn't expose available mime types, so external tools are used
+        let (program, args): (&str, &[&str]) = if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            ("wl-paste", &["--list-types"])
+        } else {
+            ("xclip", &["-selection", "clipboard", "-target", "TARGETS", "-out"])
+        };
+
+        let output = std::process::Command::new(program)
+            .args(args)
+            .output()
+            .with_context(|| format!("unable to run {}", program))?;
+
+        // exits with error when clipboard is empty, in which case there is nothing to conceal
+        if !output.status.success() {
+            return Ok(false);
+        }
+
+        let concealed = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|mime| mime.trim() == PASSWORD_MANAGER_HINT_MIME);
+
+        Ok(concealed)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn is_concealed(&self) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
     pub fn read_text(&self) -> anyhow::Result<Option<String>> {
         let mut clipboard = self.clipboard.write().expect("lock is poisoned");
 
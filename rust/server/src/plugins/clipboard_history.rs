@@ -0,0 +1,205 @@
+use std::io::Cursor;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::thread;
+use std::time::Duration;
+
+use gauntlet_plugin_runtime::JsClipboardData;
+use gauntlet_plugin_runtime::JsClipboardHistoryEntry;
+use tokio::runtime::Handle;
+
+use crate::plugins::clipboard::Clipboard;
+use crate::plugins::config_reader::ClipboardHistoryConfig;
+use crate::plugins::data_db_repository::DataDbRepository;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone)]
+pub struct ClipboardHistory {
+    repository: DataDbRepository,
+    clipboard: Clipboard,
+    config: Arc<RwLock<ClipboardHistoryConfig>>,
+    last_content_hash: Arc<Mutex<Option<u64>>>,
+    concealed_check_failed: Arc<AtomicBool>,
+}
+
+impl ClipboardHistory {
+    pub fn new(repository: DataDbRepository, clipboard: Clipboard) -> Self {
+        Self {
+            repository,
+            clipboard,
+            config: Arc::new(RwLock::new(ClipboardHistoryConfig::default())),
+            last_content_hash: Arc::new(Mutex::new(None)),
+            concealed_check_failed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn set_config(&self, config: ClipboardHistoryConfig) {
+        *self.config.write().expect("lock is poisoned") = config;
+    }
+
+    pub fn start(&self) {
+        let clipboard_history = self.clone();
+        let handle = tokio::runtime::Handle::current();
+
+        // content present when server starts is not recorded
+        self.skip_current_content();
+
+        // clipboard access is blocking, so polling is done on a separate thread
+        thread::Builder::new()
+            .name("gauntlet-clipboard-history".to_string())
+            .spawn(move || {
+                loop {
+                    thread::sleep(POLL_INTERVAL);
+
+                    let config = clipboard_history.config.read().expect("lock is poisoned").clone();
+
+                    if !config.enabled {
+                        continue;
+                    }
+
+                    let content_hash = match clipboard_history.clipboard.content_hash() {
+                        Ok(content_hash) => content_hash,
+                        Err(err) => {
+                            tracing::debug!("Unable to read clipboard for clipboard history: {:?}", err);
+                            continue;
+                        }
+                    };
+
+                    {
+                        let mut last_content_hash =
+                            clipboard_history.last_content_hash.lock().expect("lock is poisoned");
+
+                        if content_hash.is_none() || content_hash == *last_content_hash {
+                            continue;
+                        }
+
+                        *last_content_hash = content_hash;
+                    }
+
+                    if let Err(err) = clipboard_history.record(&handle, &config) {
+                        tracing::error!("Unable to save clipboard history entry: {:?}", err);
+                    }
+                }
+            })
+            .expect("failed to spawn thread");
+    }
+
+    fn record(&self, handle: &Handle, config: &ClipboardHistoryConfig) -> anyhow::Result<()> {
+        if config.ignore_concealed && self.is_concealed() {
+            tracing::debug!("Clipboard content is marked as concealed, not saving it to clipboard history");
+
+            return Ok(());
+        }
+
+        let JsClipboardData { text_data, png_data } = self.clipboard.read()?;
+
+        let size = text_data.as_ref().map(|data| data.len()).unwrap_or_default()
+            + png_data.as_ref().map(|data| data.len()).unwrap_or_default();
+
+        if size > config.max_entry_size {
+            tracing::debug!(
+                "Clipboard content is too large ({} bytes), not saving it to clipboard history",
+                size
+            );
+
+            return Ok(());
+        }
+
+        // whitespace-only text is not worth keeping, unless there is also an image
+        let text_data = text_data.filter(|text| !text.trim().is_empty());
+
+        if text_data.is_none() && png_data.is_none() {
+            return Ok(());
+        }
+
+        // size is stored separately, so that list of entries doesn't need to load and decode images
+        let image_size = png_data.as_ref().and_then(|png_data| {
+            image::io::Reader::with_format(Cursor::new(png_data), image::ImageFormat::Png)
+                .into_dimensions()
+                .ok()
+        });
+
+        handle.block_on(self.repository.add_clipboard_history_entry(
+            text_data,
+            png_data,
+            image_size,
+            config.max_entries,
+            config.retention_days,
+        ))
+    }
+
+    fn is_concealed(&self) -> bool {
+        // required tool is not installed, there is no point in spawning it again on every clipboard change
+        if self.concealed_check_failed.load(Ordering::Relaxed) {
+            return false;
+        }
+
+        match self.clipboard.is_concealed() {
+            Ok(concealed) => concealed,
+            Err(err) => {
+                self.concealed_check_failed.store(true, Ordering::Relaxed);
+
+                tracing::warn!(
+                    "Unable to check whether clipboard content is concealed, entries from password managers will be saved to clipboard history: {:?}",
+                    err
+                );
+
+                false
+            }
+        }
+    }
+
+    pub async fn list(&self, query: &str) -> anyhow::Result<Vec<JsClipboardHistoryEntry>> {
+        let entries = self
+            .repository
+            .clipboard_history(query)
+            .await?
+            .into_iter()
+            .map(|entry| {
+                JsClipboardHistoryEntry {
+                    id: entry.id,
+                    text_data: entry.text_data,
+                    image_width: entry.image_width,
+                    image_height: entry.image_height,
+                    pinned: entry.pinned,
+                    created_at: entry.created_at,
+                }
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    pub async fn copy(&self, id: i64) -> anyhow::Result<()> {
+        let entry = self.repository.get_clipboard_history_entry(id).await?;
+
+        self.clipboard.write(JsClipboardData {
+            text_data: entry.text_data,
+            png_data: entry.png_data,
+        })?;
+
+        // png written to clipboard is not guaranteed to be byte-identical when read back,
+        // so it is not recorded again to avoid duplicate entries
+        self.skip_current_content();
+
+        Ok(())
+    }
+
+    pub fn skip_current_content(&self) {
+        let content_hash = self.clipboard.content_hash().ok().flatten();
+
+        *self.last_content_hash.lock().expect("lock is poisoned") = content_hash;
+    }
+
+    pub async fn set_pinned(&self, id: i64, pinned: bool) -> anyhow::Result<()> {
+        self.repository.set_clipboard_history_entry_pinned(id, pinned).await
+    }
+
+    pub async fn remove(&self, id: i64) -> anyhow::Result<()> {
+        self.repository.remove_clipboard_history_entry(id).await
+    }
+}
@@ -1,17 +1,93 @@
 use std::cell::Cell;
+use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
+use std::sync::RwLock;
 
+use anyhow::anyhow;
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::LauncherAction;
+use gauntlet_common::model::NavigationKeymap;
+use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::PhysicalShortcut;
 use serde::Deserialize;
 
 use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbWritePendingPlugin;
 
+const DEFAULT_WINDOW_WIDTH: u32 = 750;
+const DEFAULT_WINDOW_HEIGHT: u32 = 450;
+const MIN_WINDOW_WIDTH: u32 = 500;
+const MIN_WINDOW_HEIGHT: u32 = 300;
+const DEFAULT_CLIPBOARD_HISTORY_MAX_ENTRIES: usize = 500;
+const DEFAULT_CLIPBOARD_HISTORY_MAX_ENTRY_SIZE: usize = 5 * 1024 * 1024;
+const DEFAULT_CLIPBOARD_HISTORY_RETENTION_DAYS: u64 = 30;
+
 pub struct ConfigReader {
     dirs: Dirs,
     repository: DataDbRepository,
     close_on_unfocus: AtomicBool,
+    restore_last_query: AtomicBool,
+    window_width: AtomicU32,
+    window_height: AtomicU32,
+    // 0 means no limit
+    max_visible_results: AtomicUsize,
+    navigation_keymap: RwLock<NavigationKeymap>,
+    keybindings: RwLock<HashMap<LauncherAction, PhysicalShortcut>>,
+    search_sections: RwLock<Option<SearchSectionsConfig>>,
+    clipboard_history: RwLock<ClipboardHistoryConfig>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize)]
+pub enum SearchSectionKind {
+    #[serde(rename = "recently_used")]
+    RecentlyUsed,
+    #[serde(rename = "commands")]
+    Commands,
+    #[serde(rename = "applications")]
+    Applications,
+    // one section per plugin
+    #[serde(rename = "generated")]
+    Generated,
+}
+
+const DEFAULT_SEARCH_SECTION_ORDER: [SearchSectionKind; 4] = [
+    SearchSectionKind::RecentlyUsed,
+    SearchSectionKind::Commands,
+    SearchSectionKind::Applications,
+    SearchSectionKind::Generated,
+];
+
+#[derive(Debug, Clone)]
+pub struct SearchSectionsConfig {
+    pub order: Vec<SearchSectionKind>,
+    pub limits: HashMap<SearchSectionKind, usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClipboardHistoryConfig {
+    pub enabled: bool,
+    pub max_entries: usize,
+    // in bytes, larger entries are not saved
+    pub max_entry_size: usize,
+    // None means entries are kept until max_entries is reached
+    pub retention_days: Option<u64>,
+    // skip entries which password managers mark as concealed
+    pub ignore_concealed: bool,
+}
+
+impl Default for ClipboardHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries: DEFAULT_CLIPBOARD_HISTORY_MAX_ENTRIES,
+            max_entry_size: DEFAULT_CLIPBOARD_HISTORY_MAX_ENTRY_SIZE,
+            retention_days: Some(DEFAULT_CLIPBOARD_HISTORY_RETENTION_DAYS),
+            ignore_concealed: true,
+        }
+    }
 }
 
 impl ConfigReader {
@@ -20,6 +96,14 @@ impl ConfigReader {
             dirs,
             repository,
             close_on_unfocus: AtomicBool::new(true),
+            restore_last_query: AtomicBool::new(false),
+            window_width: AtomicU32::new(DEFAULT_WINDOW_WIDTH),
+            window_height: AtomicU32::new(DEFAULT_WINDOW_HEIGHT),
+            max_visible_results: AtomicUsize::new(0),
+            navigation_keymap: RwLock::new(NavigationKeymap::Default),
+            keybindings: RwLock::new(default_keybindings()),
+            search_sections: RwLock::new(None),
+            clipboard_history: RwLock::new(ClipboardHistoryConfig::default()),
         }
     }
 
@@ -39,10 +123,91 @@ impl ConfigReader {
         //     }
         // }
 
-        self.close_on_unfocus.store(
-            config.main_window.unwrap_or_default().close_on_unfocus,
-            Ordering::SeqCst,
-        );
+        let main_window = config.main_window.unwrap_or_default();
+
+        self.close_on_unfocus
+            .store(main_window.close_on_unfocus, Ordering::SeqCst);
+        self.restore_last_query
+            .store(main_window.restore_last_query, Ordering::SeqCst);
+
+        let window_width = main_window.width.unwrap_or(DEFAULT_WINDOW_WIDTH);
+        let window_height = main_window.height.unwrap_or(DEFAULT_WINDOW_HEIGHT);
+
+        self.window_width
+            .store(window_width.max(MIN_WINDOW_WIDTH), Ordering::SeqCst);
+        self.window_height
+            .store(window_height.max(MIN_WINDOW_HEIGHT), Ordering::SeqCst);
+        self.max_visible_results
+            .store(main_window.max_visible_results.unwrap_or(0), Ordering::SeqCst);
+
+        let navigation_keymap = match main_window.keymap {
+            ApplicationConfigKeymap::Default => NavigationKeymap::Default,
+            ApplicationConfigKeymap::Vim => NavigationKeymap::Vim,
+            ApplicationConfigKeymap::Emacs => NavigationKeymap::Emacs,
+        };
+
+        *self.navigation_keymap.write().unwrap() = navigation_keymap;
+
+        let mut keybindings = default_keybindings();
+
+        for (action_id, shortcut) in config.keybindings.unwrap_or_default() {
+            let Some(action) = LauncherAction::from_id(&action_id) else {
+                tracing::error!("Unknown action in keybindings config: {}", action_id);
+                continue;
+            };
+
+            match parse_shortcut(&shortcut) {
+                Ok(shortcut) => {
+                    keybindings.insert(action, shortcut);
+                }
+                Err(err) => {
+                    tracing::error!("Unable to parse keybinding for action {}, error: {:#}", action_id, err);
+                }
+            }
+        }
+
+        *self.keybindings.write().unwrap() = keybindings;
+
+        let search_sections = config.search_sections.map(|search_sections| {
+            let mut order = search_sections.order.unwrap_or_default();
+
+            // sections missing from config are shown after configured ones
+            for kind in DEFAULT_SEARCH_SECTION_ORDER {
+                if !order.contains(&kind) {
+                    order.push(kind);
+                }
+            }
+
+            SearchSectionsConfig {
+                order,
+                limits: search_sections.limits.unwrap_or_default(),
+            }
+        });
+
+        *self.search_sections.write().unwrap() = search_sections;
+
+        let clipboard_history = config.clipboard_history.unwrap_or_default();
+
+        let clipboard_history = ClipboardHistoryConfig {
+            enabled: clipboard_history.enabled,
+            max_entries: clipboard_history
+                .max_entries
+                .unwrap_or(DEFAULT_CLIPBOARD_HISTORY_MAX_ENTRIES),
+            max_entry_size: clipboard_history
+                .max_entry_size
+                .unwrap_or(DEFAULT_CLIPBOARD_HISTORY_MAX_ENTRY_SIZE),
+            // 0 disables time based retention
+            retention_days: match clipboard_history
+                .retention_days
+                .unwrap_or(DEFAULT_CLIPBOARD_HISTORY_RETENTION_DAYS)
+            {
+                0 => None,
+                retention_days => Some(retention_days),
+            },
+            ignore_concealed: clipboard_history.ignore_concealed,
+        };
+
+        *self.clipboard_history.write().unwrap() = clipboard_history;
 
         Ok(())
     }
@@ -70,11 +235,105 @@ impl ConfigReader {
     pub fn close_on_unfocus(&self) -> bool {
         self.close_on_unfocus.load(Ordering::SeqCst)
     }
+
+    pub fn restore_last_query(&self) -> bool {
+        self.restore_last_query.load(Ordering::SeqCst)
+    }
+
+    pub fn window_size(&self) -> (f32, f32) {
+        let width = self.window_width.load(Ordering::SeqCst);
+        let height = self.window_height.load(Ordering::SeqCst);
+
+        (width as f32, height as f32)
+    }
+
+    pub fn max_visible_results(&self) -> Option<usize> {
+        match self.max_visible_results.load(Ordering::SeqCst) {
+            0 => None,
+            value => Some(value),
+        }
+    }
+
+    pub fn navigation_keymap(&self) -> NavigationKeymap {
+        *self.navigation_keymap.read().unwrap()
+    }
+
+    pub fn keybindings(&self) -> HashMap<LauncherAction, PhysicalShortcut> {
+        self.keybindings.read().unwrap().clone()
+    }
+
+    pub fn search_sections(&self) -> Option<SearchSectionsConfig> {
+        self.search_sections.read().unwrap().clone()
+    }
+
+    pub fn clipboard_history(&self) -> ClipboardHistoryConfig {
+        self.clipboard_history.read().unwrap().clone()
+    }
+}
+
+fn default_keybindings() -> HashMap<LauncherAction, PhysicalShortcut> {
+    LauncherAction::ALL
+        .into_iter()
+        .map(|action| (action, action.default_shortcut()))
+        .collect()
+}
+
+// accepts values like "Alt+K", "Ctrl+Shift+Enter" or "Meta+Comma"
+fn parse_shortcut(value: &str) -> anyhow::Result<PhysicalShortcut> {
+    let mut parts: Vec<_> = value.split('+').map(|part| part.trim()).collect();
+
+    let key = parts
+        .pop()
+        .filter(|key| !key.is_empty())
+        .ok_or(anyhow!("key is missing"))?;
+
+    let mut shortcut = PhysicalShortcut {
+        physical_key: parse_physical_key(key).ok_or(anyhow!("unknown key: {}", key))?,
+        modifier_shift: false,
+        modifier_control: false,
+        modifier_alt: false,
+        modifier_meta: false,
+    };
+
+    for modifier in parts {
+        match modifier.to_lowercase().as_str() {
+            "shift" => shortcut.modifier_shift = true,
+            "ctrl" | "control" => shortcut.modifier_control = true,
+            "alt" | "option" => shortcut.modifier_alt = true,
+            "meta" | "cmd" | "super" | "win" => shortcut.modifier_meta = true,
+            _ => return Err(anyhow!("unknown modifier: {}", modifier)),
+        }
+    }
+
+    Ok(shortcut)
+}
+
+fn parse_physical_key(key: &str) -> Option<PhysicalKey> {
+    let mut chars = key.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(char), None) if char.is_ascii_alphabetic() => {
+            PhysicalKey::try_from_value(&format!("Key{}", char.to_ascii_uppercase()))
+        }
+        (Some(char), None) if char.is_ascii_digit() => PhysicalKey::try_from_value(&format!("Digit{}", char)),
+        _ => {
+            match key.to_lowercase().as_str() {
+                "esc" => Some(PhysicalKey::Escape),
+                "return" => Some(PhysicalKey::Enter),
+                _ => PhysicalKey::try_from_value(key),
+            }
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Default)]
 #[serde(deny_unknown_fields)]
 pub struct ApplicationConfig {
+    // action id -> shortcut
+    keybindings: Option<HashMap<String, String>>,
+    // results are grouped into sections only if present
+    search_sections: Option<ApplicationConfigSearchSections>,
+    clipboard_history: Option<ApplicationConfigClipboardHistory>,
     main_window: Option<ApplicationConfigWindow>, // #[serde(default)]
                                                   // configuration_mode: ConfigurationModeConfig,
                                                   // #[serde(default)]
@@ -84,14 +343,80 @@ pub struct ApplicationConfig {
 #[derive(Debug, Deserialize)]
 pub struct ApplicationConfigWindow {
     close_on_unfocus: bool,
+    #[serde(default)]
+    restore_last_query: bool,
+    #[serde(default)]
+    width: Option<u32>,
+    #[serde(default)]
+    height: Option<u32>,
+    #[serde(default)]
+    max_visible_results: Option<usize>,
+    #[serde(default)]
+    keymap: ApplicationConfigKeymap,
 }
 
 impl Default for ApplicationConfigWindow {
     fn default() -> Self {
-        Self { close_on_unfocus: true }
+        Self {
+            close_on_unfocus: true,
+            restore_last_query: false,
+            width: None,
+            height: None,
+            max_visible_results: None,
+            keymap: ApplicationConfigKeymap::Default,
+        }
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ApplicationConfigSearchSections {
+    #[serde(default)]
+    order: Option<Vec<SearchSectionKind>>,
+    #[serde(default)]
+    limits: Option<HashMap<SearchSectionKind, usize>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApplicationConfigClipboardHistory {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    max_entries: Option<usize>,
+    #[serde(default)]
+    max_entry_size: Option<usize>,
+    #[serde(default)]
+    retention_days: Option<u64>,
+    #[serde(default = "default_true")]
+    ignore_concealed: bool,
+}
+
+impl Default for ApplicationConfigClipboardHistory {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries: None,
+            max_entry_size: None,
+            retention_days: None,
+            ignore_concealed: true,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub enum ApplicationConfigKeymap {
+    #[default]
+    #[serde(rename = "default")]
+    Default,
+    #[serde(rename = "vim")]
+    Vim,
+    #[serde(rename = "emacs")]
+    Emacs,
+}
+
 // #[derive(Debug, Deserialize)]
 // struct PluginEntryConfig {
 //     id: String,
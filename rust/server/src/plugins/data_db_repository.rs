@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::PathBuf;
+use std::time::SystemTime;
 
 use anyhow::anyhow;
 use anyhow::Context;
@@ -308,6 +309,12 @@ pub enum DbTheme {
 pub enum DbWindowPositionMode {
     #[serde(rename = "active_monitor")]
     ActiveMonitor,
+    #[serde(rename = "cursor_monitor")]
+    CursorMonitor,
+    #[serde(rename = "focused_window_monitor")]
+    FocusedWindowMonitor,
+    #[serde(rename = "remember_per_monitor")]
+    RememberPerMonitor,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -394,7 +401,37 @@ pub struct DbPluginEntrypointFrecencyStats {
     pub num_accesses: i32,
 }
 
+#[derive(sqlx::FromRow)]
+pub struct DbClipboardHistoryEntry {
+    pub id: i64,
+    pub text_data: Option<String>,
+    pub png_data: Option<Vec<u8>>,
+    pub pinned: bool,
+    pub created_at: f64,
+}
+
+// same as DbClipboardHistoryEntry but without image data, which is only needed to copy the entry
+#[derive(sqlx::FromRow)]
+pub struct DbClipboardHistoryListEntry {
+    pub id: i64,
+    pub text_data: Option<String>,
+    pub image_width: Option<u32>,
+    pub image_height: Option<u32>,
+    pub pinned: bool,
+    pub created_at: f64,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbSnippet {
+    pub id: i64,
+    pub name: String,
+    pub text: String,
+    pub created_at: f64,
+    pub updated_at: f64,
+}
+
 const SETTINGS_DATA_ID: &str = "settings_data"; // only one row in the table
+const SEARCH_HISTORY_LIMIT: i64 = 100;
 
 impl DataDbRepository {
     pub async fn new(dirs: Dirs) -> anyhow::Result<Self> {
@@ -1036,6 +1073,248 @@ impl DataDbRepository {
         Ok(result)
     }
 
+    pub async fn search_history(&self) -> anyhow::Result<Vec<String>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (String,)>("SELECT query FROM search_history ORDER BY last_used DESC")
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|result| result.0)
+            .collect();
+
+        Ok(result)
+    }
+
+    pub async fn add_search_history_entry(&self, query: &str) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let last_used = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("failed to get system time")
+            .as_secs_f64();
+
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO search_history (query, last_used)
+                VALUES(?1, ?2)
+                    ON CONFLICT (query)
+                        DO UPDATE SET last_used = ?2
+        "#;
+
+        sqlx::query(sql).bind(query).bind(last_used).execute(&mut *tx).await?;
+
+        // language=SQLite
+        let sql = r#"
+            DELETE FROM search_history
+                WHERE query NOT IN (SELECT query FROM search_history ORDER BY last_used DESC LIMIT ?1)
+        "#;
+
+        sqlx::query(sql).bind(SEARCH_HISTORY_LIMIT).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn clear_search_history(&self) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM search_history").execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    pub async fn clipboard_history(&self, query: &str) -> anyhow::Result<Vec<DbClipboardHistoryListEntry>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT id, text_data, image_width, image_height, pinned, created_at FROM clipboard_history
+                WHERE ?1 = '' OR instr(lower(text_data), lower(?1)) > 0
+                ORDER BY pinned DESC, created_at DESC
+        "#;
+
+        let result = sqlx::query_as::<_, DbClipboardHistoryListEntry>(sql)
+            .bind(query)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_clipboard_history_entry(&self, id: i64) -> anyhow::Result<DbClipboardHistoryEntry> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, DbClipboardHistoryEntry>("SELECT * FROM clipboard_history WHERE id = ?1")
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn add_clipboard_history_entry(
+        &self,
+        text_data: Option<String>,
+        png_data: Option<Vec<u8>>,
+        image_size: Option<(u32, u32)>,
+        max_entries: usize,
+        retention_days: Option<u64>,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let created_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("failed to get system time")
+            .as_secs_f64();
+
+        // the same content copied again is moved to the top instead of being duplicated
+        // language=SQLite
+        let sql = r#"
+            UPDATE clipboard_history
+                SET created_at = ?3
+                WHERE text_data IS ?1 AND png_data IS ?2
+        "#;
+
+        let updated = sqlx::query(sql)
+            .bind(&text_data)
+            .bind(&png_data)
+            .bind(created_at)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        if updated == 0 {
+            // language=SQLite
+            let sql = r#"
+                INSERT INTO clipboard_history (text_data, png_data, image_width, image_height, pinned, created_at)
+                    VALUES(?1, ?2, ?3, ?4, FALSE, ?5)
+            "#;
+
+            sqlx::query(sql)
+                .bind(&text_data)
+                .bind(&png_data)
+                .bind(image_size.map(|(width, _)| width))
+                .bind(image_size.map(|(_, height)| height))
+                .bind(created_at)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        // pinned entries are never removed automatically
+        if let Some(retention_days) = retention_days {
+            let oldest_allowed = created_at - (retention_days * 24 * 60 * 60) as f64;
+
+            // language=SQLite
+            sqlx::query("DELETE FROM clipboard_history WHERE pinned = FALSE AND created_at < ?1")
+                .bind(oldest_allowed)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        // language=SQLite
+        let sql = r#"
+            DELETE FROM clipboard_history
+                WHERE pinned = FALSE AND id NOT IN (
+                    SELECT id FROM clipboard_history WHERE pinned = FALSE ORDER BY created_at DESC LIMIT ?1
+                )
+        "#;
+
+        sqlx::query(sql).bind(max_entries as i64).execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn set_clipboard_history_entry_pinned(&self, id: i64, pinned: bool) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE clipboard_history SET pinned = ?1 WHERE id = ?2")
+            .bind(pinned)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_clipboard_history_entry(&self, id: i64) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM clipboard_history WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn snippets(&self, query: &str) -> anyhow::Result<Vec<DbSnippet>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT * FROM snippet
+                WHERE ?1 = '' OR instr(lower(name), lower(?1)) > 0 OR instr(lower(text), lower(?1)) > 0
+                ORDER BY name COLLATE NOCASE
+        "#;
+
+        let result = sqlx::query_as::<_, DbSnippet>(sql)
+            .bind(query)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_snippet(&self, id: Option<i64>, name: &str, text: &str) -> anyhow::Result<i64> {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("failed to get system time")
+            .as_secs_f64();
+
+        let id = match id {
+            Some(id) => {
+                // language=SQLite
+                let sql = r#"
+                    UPDATE snippet
+                        SET name = ?1, text = ?2, updated_at = ?3
+                        WHERE id = ?4
+                "#;
+
+                sqlx::query(sql)
+                    .bind(name)
+                    .bind(text)
+                    .bind(now)
+                    .bind(id)
+                    .execute(&self.pool)
+                    .await?;
+
+                id
+            }
+            None => {
+                // language=SQLite
+                let sql = r#"
+                    INSERT INTO snippet (name, text, created_at, updated_at)
+                        VALUES(?1, ?2, ?3, ?3)
+                "#;
+
+                sqlx::query(sql)
+                    .bind(name)
+                    .bind(text)
+                    .bind(now)
+                    .execute(&self.pool)
+                    .await?
+                    .last_insert_rowid()
+            }
+        };
+
+        Ok(id)
+    }
+
+    pub async fn remove_snippet(&self, id: i64) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM snippet WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_plugin_enabled(&self, plugin_id: &str, enabled: bool) -> anyhow::Result<()> {
         // language=SQLite
         sqlx::query("UPDATE plugin SET enabled = ?1 WHERE id = ?2")
@@ -23,8 +23,12 @@ use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::SearchResultAccessory;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::UiConfirmAlert;
+use gauntlet_common::model::UiConfirmAlertActionStyle;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiRenderLocation;
+use gauntlet_common::model::UiToast;
+use gauntlet_common::model::UiToastStyle;
 use gauntlet_common::model::UiWidgetId;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
@@ -34,6 +38,9 @@ use gauntlet_plugin_runtime::recv_message;
 use gauntlet_plugin_runtime::send_message;
 use gauntlet_plugin_runtime::BackendForPluginRuntimeApi;
 use gauntlet_plugin_runtime::JsClipboardData;
+use gauntlet_plugin_runtime::JsClipboardHistoryEntry;
+use gauntlet_plugin_runtime::JsConfirmAlert;
+use gauntlet_plugin_runtime::JsConfirmAlertActionStyle;
 use gauntlet_plugin_runtime::JsEvent;
 use gauntlet_plugin_runtime::JsGeneratedSearchItem;
 use gauntlet_plugin_runtime::JsGeneratedSearchItemAccessory;
@@ -49,6 +56,9 @@ use gauntlet_plugin_runtime::JsPluginPermissionsFileSystem;
 use gauntlet_plugin_runtime::JsPluginPermissionsMainSearchBar;
 use gauntlet_plugin_runtime::JsPluginRuntimeMessage;
 use gauntlet_plugin_runtime::JsPreferenceUserData;
+use gauntlet_plugin_runtime::JsSnippet;
+use gauntlet_plugin_runtime::JsToast;
+use gauntlet_plugin_runtime::JsToastStyle;
 use gauntlet_plugin_runtime::JsUiPropertyValue;
 use gauntlet_plugin_runtime::JsUiRenderLocation;
 use gauntlet_utils::channel::RequestResult;
@@ -73,6 +83,7 @@ use tokio_util::sync::CancellationToken;
 use crate::model::IntermediateUiEvent;
 use crate::plugins::binary_data_gatherer::BinaryDataGatherer;
 use crate::plugins::clipboard::Clipboard;
+use crate::plugins::clipboard_history::ClipboardHistory;
 use crate::plugins::data_db_repository::db_entrypoint_from_str;
 use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbPluginClipboardPermissions;
@@ -90,6 +101,8 @@ use crate::search::SearchIndexItemActionActionType;
 use crate::PLUGIN_CONNECT_ENV;
 use crate::PLUGIN_UUID_ENV;
 
+const PASTE_CLIPBOARD_RESTORE_DELAY: Duration = Duration::from_millis(500);
+
 pub struct PluginRuntimeData {
     pub id: PluginId,
     pub uuid: String,
@@ -105,6 +118,7 @@ pub struct PluginRuntimeData {
     pub frontend_api: FrontendApiProxy,
     pub dirs: Dirs,
     pub clipboard: Clipboard,
+    pub clipboard_history: ClipboardHistory,
 }
 
 pub struct PluginPermissions {
@@ -162,6 +176,14 @@ pub enum OnePluginCommandData {
         modifier_alt: bool,
         modifier_meta: bool,
     },
+    HandleToastAction {
+        toast_id: u32,
+        action_index: usize,
+    },
+    HandleConfirmAlert {
+        alert_id: u32,
+        confirmed: bool,
+    },
     RefreshSearchIndex,
 }
 
@@ -180,6 +202,7 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         data.db_repository,
         data.search_index,
         data.clipboard,
+        data.clipboard_history,
         data.frontend_api,
         data.uuid.clone(),
         data.id.clone(),
@@ -220,6 +243,7 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
     };
 
     let home_dir = data.dirs.home_dir();
+    let calculator_file = data.dirs.calculator_file();
     let local_storage_dir = data.dirs.plugin_local_storage(&plugin_uuid);
     let uds_socket_file = data.dirs.plugin_uds_socket(&plugin_uuid);
     let plugin_cache_dir = data.dirs.plugin_cache(&plugin_uuid)?;
@@ -258,6 +282,11 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         .context("non-uft8 paths are not supported")?
         .to_string();
 
+    let calculator_file = calculator_file
+        .to_str()
+        .context("non-uft8 paths are not supported")?
+        .to_string();
+
     let local_storage_dir = local_storage_dir
         .to_str()
         .context("non-uft8 paths are not supported")?
@@ -296,6 +325,7 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         entrypoint_names: data.entrypoint_names,
         dev_plugin,
         home_dir,
+        calculator_file,
         local_storage_dir,
         plugin_cache_dir,
         plugin_data_dir,
@@ -462,6 +492,12 @@ async fn event_loop(
                             modifier_meta,
                         })
                     }
+                    OnePluginCommandData::HandleToastAction { toast_id, action_index } => {
+                        Some(IntermediateUiEvent::HandleToastAction { toast_id, action_index })
+                    }
+                    OnePluginCommandData::HandleConfirmAlert { alert_id, confirmed } => {
+                        Some(IntermediateUiEvent::HandleConfirmAlert { alert_id, confirmed })
+                    }
                     OnePluginCommandData::RefreshSearchIndex => Some(IntermediateUiEvent::RefreshSearchIndex),
                 }
             }
@@ -549,20 +585,7 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
             event_name,
             event_arguments,
         } => {
-            let event_arguments = event_arguments
-                .into_iter()
-                .map(|arg| {
-                    match arg {
-                        UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
-                        UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
-                        UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
-                        UiPropertyValue::Undefined => JsUiPropertyValue::Undefined,
-                        UiPropertyValue::Array(_) | UiPropertyValue::Bytes(_) | UiPropertyValue::Object(_) => {
-                            todo!()
-                        }
-                    }
-                })
-                .collect();
+            let event_arguments = event_arguments.into_iter().map(to_js_property_value).collect();
 
             JsEvent::ViewEvent {
                 widget_id,
@@ -592,17 +615,47 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
                 modifier_meta,
             }
         }
+        IntermediateUiEvent::HandleToastAction { toast_id, action_index } => {
+            JsEvent::ToastActionEvent { toast_id, action_index }
+        }
+        IntermediateUiEvent::HandleConfirmAlert { alert_id, confirmed } => {
+            JsEvent::ConfirmAlertEvent { alert_id, confirmed }
+        }
         IntermediateUiEvent::OpenInlineView { text } => JsEvent::OpenInlineView { text },
         IntermediateUiEvent::RefreshSearchIndex => JsEvent::RefreshSearchIndex,
     }
 }
 
+fn to_js_property_value(value: UiPropertyValue) -> JsUiPropertyValue {
+    match value {
+        UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
+        UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
+        UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
+        UiPropertyValue::Array(value) => {
+            JsUiPropertyValue::Array {
+                value: value.into_iter().map(to_js_property_value).collect(),
+            }
+        }
+        UiPropertyValue::Object(value) => {
+            JsUiPropertyValue::Object {
+                value: value
+                    .into_iter()
+                    .map(|(key, value)| (key, to_js_property_value(value)))
+                    .collect(),
+            }
+        }
+        // event arguments never contain binary data, and there is no js representation for it in events
+        UiPropertyValue::Undefined | UiPropertyValue::Bytes(_) => JsUiPropertyValue::Undefined,
+    }
+}
+
 #[derive(Clone)]
 pub struct BackendForPluginRuntimeApiImpl {
     icon_cache: IconCache,
     repository: DataDbRepository,
     search_index: SearchIndex,
     clipboard: Clipboard,
+    clipboard_history: ClipboardHistory,
     frontend_api: FrontendApiProxy,
     plugin_uuid: String,
     plugin_id: PluginId,
@@ -616,6 +669,7 @@ impl BackendForPluginRuntimeApiImpl {
         repository: DataDbRepository,
         search_index: SearchIndex,
         clipboard: Clipboard,
+        clipboard_history: ClipboardHistory,
         frontend_api: FrontendApiProxy,
         plugin_uuid: String,
         plugin_id: PluginId,
@@ -627,6 +681,7 @@ impl BackendForPluginRuntimeApiImpl {
             repository,
             search_index,
             clipboard,
+            clipboard_history,
             frontend_api,
             plugin_uuid,
             plugin_id,
@@ -636,6 +691,17 @@ impl BackendForPluginRuntimeApiImpl {
     }
 }
 
+impl BackendForPluginRuntimeApiImpl {
+    fn check_bundled_plugin(&self, feature: &str) -> anyhow::Result<()> {
+        // clipboard history and snippets may contain sensitive data, so they are only available to bundled plugin
+        if self.plugin_id.to_string() != "bundled://gauntlet" {
+            return Err(anyhow!("Plugin doesn't have access to {}", feature));
+        }
+
+        Ok(())
+    }
+}
+
 impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
     async fn reload_search_index(
         &self,
@@ -744,6 +810,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                     entrypoint_frecency,
                     entrypoint_actions,
                     entrypoint_accessories,
+                    entrypoint_keywords: item.entrypoint_keywords,
+                    entrypoint_application: item.entrypoint_application,
                     entrypoint_generator,
                 })
             })
@@ -796,6 +864,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
                             entrypoint_accessories: vec![],
+                            entrypoint_keywords: vec![],
+                            entrypoint_application: false,
                         }))
                     }
                     DbPluginEntrypointType::View => {
@@ -808,6 +878,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
                             entrypoint_accessories: vec![],
+                            entrypoint_keywords: vec![],
+                            entrypoint_application: false,
                         }))
                     }
                     DbPluginEntrypointType::EntrypointGenerator | DbPluginEntrypointType::InlineView => Ok(None),
@@ -970,6 +1042,63 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         self.clipboard.clear().map_err(Into::into)
     }
 
+    async fn clipboard_history_list(&self, query: String) -> RequestResult<Vec<JsClipboardHistoryEntry>> {
+        self.check_bundled_plugin("clipboard history")?;
+
+        self.clipboard_history.list(&query).await.map_err(Into::into)
+    }
+
+    async fn clipboard_history_copy(&self, id: i64) -> RequestResult<()> {
+        self.check_bundled_plugin("clipboard history")?;
+
+        self.clipboard_history.copy(id).await.map_err(Into::into)
+    }
+
+    async fn clipboard_history_set_pinned(&self, id: i64, pinned: bool) -> RequestResult<()> {
+        self.check_bundled_plugin("clipboard history")?;
+
+        self.clipboard_history.set_pinned(id, pinned).await.map_err(Into::into)
+    }
+
+    async fn clipboard_history_remove(&self, id: i64) -> RequestResult<()> {
+        self.check_bundled_plugin("clipboard history")?;
+
+        self.clipboard_history.remove(id).await.map_err(Into::into)
+    }
+
+    async fn snippets_list(&self, query: String) -> RequestResult<Vec<JsSnippet>> {
+        self.check_bundled_plugin("snippets")?;
+
+        let snippets = self
+            .repository
+            .snippets(&query)
+            .await?
+            .into_iter()
+            .map(|snippet| {
+                JsSnippet {
+                    id: snippet.id,
+                    name: snippet.name,
+                    text: snippet.text,
+                    updated_at: snippet.updated_at,
+                }
+            })
+            .collect();
+
+        Ok(snippets)
+    }
+
+    async fn snippet_save(&self, id: Option<i64>, name: String, text: String) -> RequestResult<i64> {
+        self.check_bundled_plugin("snippets")?;
+
+        self.repository.save_snippet(id, &name, &text).await.map_err(Into::into)
+    }
+
+    async fn snippet_remove(&self, id: i64) -> RequestResult<()> {
+        self.check_bundled_plugin("snippets")?;
+
+        self.repository.remove_snippet(id).await.map_err(Into::into)
+    }
+
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> RequestResult<()> {
         self.frontend_api
             .update_loading_bar(self.plugin_id.clone(), entrypoint_id, show)
@@ -984,12 +1113,94 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         Ok(())
     }
 
+    async fn ui_show_toast(&self, toast: JsToast) -> RequestResult<()> {
+        let toast = UiToast {
+            id: toast.id,
+            style: match toast.style {
+                JsToastStyle::Success => UiToastStyle::Success,
+                JsToastStyle::Failure => UiToastStyle::Failure,
+                JsToastStyle::Animated => UiToastStyle::Animated,
+            },
+            title: toast.title,
+            message: toast.message,
+            primary_action: toast.primary_action,
+            secondary_action: toast.secondary_action,
+        };
+
+        self.frontend_api.show_toast(self.plugin_id.clone(), toast).await?;
+
+        Ok(())
+    }
+
+    async fn ui_hide_toast(&self, toast_id: u32) -> RequestResult<()> {
+        self.frontend_api.hide_toast(self.plugin_id.clone(), toast_id).await?;
+
+        Ok(())
+    }
+
+    async fn ui_show_confirm_alert(&self, alert: JsConfirmAlert) -> RequestResult<()> {
+        let alert = UiConfirmAlert {
+            id: alert.id,
+            title: alert.title,
+            message: alert.message,
+            icon: alert.icon,
+            primary_action: alert.primary_action,
+            primary_action_style: match alert.primary_action_style {
+                JsConfirmAlertActionStyle::Default => UiConfirmAlertActionStyle::Default,
+                JsConfirmAlertActionStyle::Destructive => UiConfirmAlertActionStyle::Destructive,
+            },
+            dismiss_action: alert.dismiss_action,
+        };
+
+        self.frontend_api
+            .show_confirm_alert(self.plugin_id.clone(), alert)
+            .await?;
+
+        Ok(())
+    }
+
     async fn ui_hide_window(&self) -> RequestResult<()> {
         self.frontend_api.hide_window().await?;
 
         Ok(())
     }
 
+    async fn ui_paste_to_active_window(&self, text: String, cursor_offset_from_end: u32) -> RequestResult<()> {
+        let allow = self.permissions.clipboard.contains(&PluginPermissionsClipboard::Write);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'write' permission for clipboard").into());
+        }
+
+        tracing::debug!("Pasting to active window, plugin id: {:?}", self.plugin_id);
+
+        // text is transferred to target window using clipboard, original content is restored afterwards
+        let original = self.clipboard.read().ok();
+
+        self.clipboard.write_text(text)?;
+        self.clipboard_history.skip_current_content();
+
+        let result = self.frontend_api.paste_to_active_window(cursor_offset_from_end).await;
+
+        // target window reads clipboard only after receiving paste keystroke
+        tokio::time::sleep(PASTE_CLIPBOARD_RESTORE_DELAY).await;
+
+        match original {
+            Some(original) if original.text_data.is_some() || original.png_data.is_some() => {
+                self.clipboard.write(original)?;
+            }
+            _ => {
+                self.clipboard.clear()?;
+            }
+        }
+
+        self.clipboard_history.skip_current_content();
+
+        result?;
+
+        Ok(())
+    }
+
     async fn ui_get_action_id_for_shortcut(
         &self,
         entrypoint_id: EntrypointId,
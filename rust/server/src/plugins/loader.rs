@@ -259,9 +259,14 @@ impl PluginLoader {
         let plugin_name = plugin_manifest.gauntlet.name;
         let plugin_description = plugin_manifest.gauntlet.description;
 
+        let current_system = Self::current_system();
+
         let entrypoints: Vec<_> = plugin_manifest
             .entrypoint
             .into_iter()
+            .filter(|entrypoint| {
+                entrypoint.supported_system.is_empty() || entrypoint.supported_system.contains(&current_system)
+            })
             .map(|entrypoint| {
                 DbWritePluginEntrypoint {
                     id: entrypoint.id,
@@ -656,17 +661,7 @@ impl PluginLoader {
             env_exists || fs_read_exists || fs_write_exists || command_exists || executable_exists || system_exists;
 
         if os_required {
-            let current_system = if cfg!(target_os = "linux") {
-                PluginManifestSupportedSystem::Linux
-            } else if cfg!(target_os = "macos") {
-                PluginManifestSupportedSystem::MacOS
-            } else if cfg!(target_os = "windows") {
-                PluginManifestSupportedSystem::Windows
-            } else {
-                panic!("OS not supported")
-            };
-
-            if !supported_systems.contains(&current_system) {
+            if !supported_systems.contains(&Self::current_system()) {
                 return Err(anyhow!(
                     "Plugin doesn't support current operating system. Operating systems supported by plugin: [{}]",
                     supported_systems_str
@@ -674,6 +669,22 @@ impl PluginLoader {
             }
         }
 
+        for entrypoint in &plugin_manifest.entrypoint {
+            let unsupported_system = entrypoint
+                .supported_system
+                .iter()
+                .find(|system| !supported_systems.contains(system));
+
+            if let Some(unsupported_system) = unsupported_system {
+                return Err(anyhow!(
+                    "Entrypoint '{}' specifies operating system that plugin doesn't support: {}. Operating systems supported by plugin: [{}]",
+                    entrypoint.id,
+                    unsupported_system,
+                    supported_systems_str
+                ));
+            }
+        }
+
         let has_inline_view = plugin_manifest
             .entrypoint
             .iter()
@@ -692,6 +703,18 @@ impl PluginLoader {
         Ok(())
     }
 
+    fn current_system() -> PluginManifestSupportedSystem {
+        if cfg!(target_os = "linux") {
+            PluginManifestSupportedSystem::Linux
+        } else if cfg!(target_os = "macos") {
+            PluginManifestSupportedSystem::MacOS
+        } else if cfg!(target_os = "windows") {
+            PluginManifestSupportedSystem::Windows
+        } else {
+            panic!("OS not supported")
+        }
+    }
+
     fn validate_path_permissions(
         paths: &[String],
         supports_linux: &bool,
@@ -9,9 +9,11 @@ use anyhow::anyhow;
 use anyhow::Context;
 use gauntlet_common::detached_process::CommandExt;
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::quick_pick_shortcut;
 use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::KeyboardEventOrigin;
+use gauntlet_common::model::LauncherAction;
 use gauntlet_common::model::LocalSaveData;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
@@ -24,12 +26,15 @@ use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::model::SettingsEntrypoint;
 use gauntlet_common::model::SettingsEntrypointType;
 use gauntlet_common::model::SettingsGeneratedEntrypoint;
+use gauntlet_common::model::SettingsKeybinding;
+use gauntlet_common::model::SettingsKeybindingAction;
 use gauntlet_common::model::SettingsPlugin;
 use gauntlet_common::model::SettingsTheme;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiSetupData;
 use gauntlet_common::model::UiWidgetId;
 use gauntlet_common::model::WindowPositionMode;
+use gauntlet_common::model::QUICK_PICK_COUNT;
 use gauntlet_common::rpc::backend_api::BackendForFrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
@@ -52,6 +57,7 @@ use tokio::runtime::Handle;
 
 use crate::model::ActionShortcutKey;
 use crate::plugins::clipboard::Clipboard;
+use crate::plugins::clipboard_history::ClipboardHistory;
 use crate::plugins::config_reader::ConfigReader;
 use crate::plugins::data_db_repository::db_entrypoint_from_str;
 use crate::plugins::data_db_repository::DataDbRepository;
@@ -81,6 +87,7 @@ use crate::search::SearchIndex;
 
 mod binary_data_gatherer;
 mod clipboard;
+mod clipboard_history;
 mod config_reader;
 mod data_db_repository;
 mod download_status;
@@ -110,6 +117,7 @@ pub struct ApplicationManager {
     frontend_api: FrontendApiProxy,
     dirs: Dirs,
     clipboard: Clipboard,
+    clipboard_history: ClipboardHistory,
     settings: Settings,
 }
 
@@ -125,6 +133,7 @@ impl ApplicationManager {
         let icon_cache = IconCache::new(dirs.clone());
         let run_status_holder = RunStatusHolder::new();
         let clipboard = Clipboard::new()?;
+        let clipboard_history = ClipboardHistory::new(db_repository.clone(), clipboard.clone());
         let settings = Settings::new(dirs.clone(), db_repository.clone(), frontend_api.clone())?;
         let search_index = SearchIndex::create_index(frontend_api.clone(), settings.clone())?;
 
@@ -140,6 +149,7 @@ impl ApplicationManager {
             icon_cache,
             frontend_api,
             clipboard,
+            clipboard_history,
             settings,
             dirs,
         })
@@ -147,6 +157,7 @@ impl ApplicationManager {
 
     pub async fn setup_data(&self) -> anyhow::Result<UiSetupData> {
         let window_position_file = self.dirs.window_position();
+        let window_position_per_monitor_file = self.dirs.window_position_per_monitor();
         let theme = self.settings.effective_theme().await?;
         let global_shortcut = self.settings.global_shortcut().await?.map(|(shortcut, _)| shortcut);
         let global_entrypoint_shortcuts = self
@@ -158,13 +169,25 @@ impl ApplicationManager {
             .collect();
         let window_position_mode = self.settings.window_position_mode_setting().await?;
         let close_on_unfocus = self.config_reader.close_on_unfocus();
+        let restore_last_query = self.config_reader.restore_last_query();
+        let (window_width, window_height) = self.config_reader.window_size();
+        let max_visible_results = self.config_reader.max_visible_results();
+        let navigation_keymap = self.config_reader.navigation_keymap();
+        let keybindings = self.config_reader.keybindings();
 
         Ok(UiSetupData {
             window_position_file: Some(window_position_file),
+            window_position_per_monitor_file: Some(window_position_per_monitor_file),
+            window_width,
+            window_height,
+            max_visible_results,
             theme,
             global_shortcut,
             global_entrypoint_shortcuts,
             close_on_unfocus,
+            restore_last_query,
+            navigation_keymap,
+            keybindings,
             window_position_mode,
         })
     }
@@ -185,6 +208,10 @@ impl ApplicationManager {
         Ok(())
     }
 
+    pub fn start_clipboard_history(&self) {
+        self.clipboard_history.start();
+    }
+
     pub fn clear_all_icon_cache_dir(&self) -> anyhow::Result<()> {
         tracing::debug!("clearing all icon cache");
 
@@ -200,7 +227,9 @@ impl ApplicationManager {
     }
 
     pub fn search(&self, text: &str, render_inline_view: bool) -> anyhow::Result<Vec<SearchResult>> {
-        let result = self.search_index.search(&text);
+        let search_sections = self.config_reader.search_sections();
+
+        let result = self.search_index.search(&text, search_sections.as_ref());
 
         if render_inline_view {
             self.handle_inline_view(&text);
@@ -654,6 +683,81 @@ impl ApplicationManager {
         self.settings.window_position_mode_setting().await
     }
 
+    pub async fn search_history(&self) -> anyhow::Result<Vec<String>> {
+        self.db_repository.search_history().await
+    }
+
+    pub async fn add_search_history_entry(&self, query: String) -> anyhow::Result<()> {
+        let query = query.trim();
+
+        if query.is_empty() {
+            return Ok(());
+        }
+
+        self.db_repository.add_search_history_entry(query).await
+    }
+
+    pub async fn clear_search_history(&self) -> anyhow::Result<()> {
+        tracing::info!("Clearing search history");
+
+        self.db_repository.clear_search_history().await
+    }
+
+    pub async fn keybindings(&self) -> anyhow::Result<Vec<SettingsKeybinding>> {
+        let keybindings = self.config_reader.keybindings();
+
+        let mut plugin_action_shortcuts = vec![];
+
+        for (plugin, entrypoints) in self.db_repository.list_plugins_and_entrypoints().await? {
+            for entrypoint in entrypoints {
+                let action_shortcuts = self.db_repository.action_shortcuts(&plugin.id, &entrypoint.id).await?;
+
+                for action in &entrypoint.actions {
+                    if let Some(shortcut) = action_shortcuts.get(&action.id) {
+                        let description = format!(
+                            "\"{}\" action of \"{}\" entrypoint in \"{}\" plugin",
+                            action.description, entrypoint.name, plugin.name
+                        );
+
+                        plugin_action_shortcuts.push((shortcut.clone(), description));
+                    }
+                }
+            }
+        }
+
+        let launcher_keybindings = LauncherAction::ALL.into_iter().filter_map(|action| {
+            let shortcut = keybindings.get(&action)?.clone();
+
+            Some((SettingsKeybindingAction::Launcher(action), shortcut))
+        });
+
+        // quick pick is not configurable but shadows plugin action shortcuts in main view the same way
+        let quick_pick_keybindings = (0..QUICK_PICK_COUNT).filter_map(|index| {
+            let shortcut = quick_pick_shortcut(index)?;
+
+            Some((SettingsKeybindingAction::QuickPick(index), shortcut))
+        });
+
+        let result = launcher_keybindings
+            .chain(quick_pick_keybindings)
+            .map(|(action, shortcut)| {
+                let conflicts = plugin_action_shortcuts
+                    .iter()
+                    .filter(|(plugin_shortcut, _)| plugin_shortcut == &shortcut)
+                    .map(|(_, description)| description.clone())
+                    .collect();
+
+                SettingsKeybinding {
+                    action,
+                    shortcut,
+                    conflicts,
+                }
+            })
+            .collect();
+
+        Ok(result)
+    }
+
     pub async fn set_preference_value(
         &self,
         plugin_id: PluginId,
@@ -688,6 +792,9 @@ impl ApplicationManager {
     pub async fn reload_config(&self) -> anyhow::Result<()> {
         self.config_reader.reload_config().await?;
 
+        self.clipboard_history
+            .set_config(self.config_reader.clipboard_history());
+
         Ok(())
     }
 
@@ -829,6 +936,20 @@ impl ApplicationManager {
         })
     }
 
+    pub fn handle_toast_action(&self, plugin_id: PluginId, toast_id: u32, action_index: usize) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::HandleToastAction { toast_id, action_index },
+        })
+    }
+
+    pub fn handle_confirm_alert(&self, plugin_id: PluginId, alert_id: u32, confirmed: bool) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::HandleConfirmAlert { alert_id, confirmed },
+        })
+    }
+
     pub fn request_search_index_refresh(&self, plugin_id: PluginId) {
         self.send_command(PluginCommand::One {
             id: plugin_id,
@@ -977,6 +1098,7 @@ impl ApplicationManager {
             frontend_api: self.frontend_api.clone(),
             dirs: self.dirs.clone(),
             clipboard: self.clipboard.clone(),
+            clipboard_history: self.clipboard_history.clone(),
         };
 
         self.start_plugin_runtime(data);
@@ -987,7 +1109,12 @@ impl ApplicationManager {
     async fn stop_plugin(&self, plugin_id: PluginId) {
         tracing::info!(target = "plugin", "Stopping plugin with id: {:?}", plugin_id);
 
-        self.run_status_holder.stop_plugin(&plugin_id)
+        self.run_status_holder.stop_plugin(&plugin_id);
+
+        // toast actions can't be handled by stopped plugin
+        if let Err(err) = self.frontend_api.clear_toasts(plugin_id).await {
+            tracing::warn!(target = "plugin", "error occurred when clearing toasts {:?}", err)
+        }
     }
 
     fn start_plugin_runtime(&self, data: PluginRuntimeData) {
@@ -1136,6 +1263,23 @@ impl BackendForFrontendApi for ApplicationManager {
         Ok(())
     }
 
+    async fn send_toast_action_event(
+        &self,
+        plugin_id: PluginId,
+        toast_id: u32,
+        action_index: usize,
+    ) -> RequestResult<()> {
+        self.handle_toast_action(plugin_id, toast_id, action_index);
+
+        Ok(())
+    }
+
+    async fn send_confirm_alert_event(&self, plugin_id: PluginId, alert_id: u32, confirmed: bool) -> RequestResult<()> {
+        self.handle_confirm_alert(plugin_id, alert_id, confirmed);
+
+        Ok(())
+    }
+
     async fn open_settings_window(&self) -> RequestResult<()> {
         self.handle_open_settings_window();
 
@@ -1164,6 +1308,18 @@ impl BackendForFrontendApi for ApplicationManager {
 
         Ok(())
     }
+
+    async fn search_history(&self) -> RequestResult<Vec<String>> {
+        let result = self.search_history().await?;
+
+        Ok(result)
+    }
+
+    async fn save_search_history_entry(&self, query: String) -> RequestResult<()> {
+        self.add_search_history_entry(query).await?;
+
+        Ok(())
+    }
 }
 
 fn plugin_preference_from_db(id: &str, value: DbPluginPreference) -> PluginPreference {
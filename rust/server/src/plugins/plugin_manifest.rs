@@ -48,6 +48,11 @@ pub struct PluginManifestEntrypoint {
     #[serde(default)]
     #[schemars(description = "List of definitions of plugin actions")]
     pub actions: Vec<PluginManifestAction>,
+    #[serde(default)]
+    #[schemars(
+        description = "List of operating systems entrypoint is available on, if empty entrypoint is available on all operating systems supported by plugin"
+    )]
+    pub supported_system: Vec<PluginManifestSupportedSystem>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
@@ -390,6 +390,9 @@ impl Settings {
         let window_position_mode = match &settings.window_position_mode {
             None => WindowPositionMode::Static,
             Some(DbWindowPositionMode::ActiveMonitor) => WindowPositionMode::ActiveMonitor,
+            Some(DbWindowPositionMode::CursorMonitor) => WindowPositionMode::CursorMonitor,
+            Some(DbWindowPositionMode::FocusedWindowMonitor) => WindowPositionMode::FocusedWindowMonitor,
+            Some(DbWindowPositionMode::RememberPerMonitor) => WindowPositionMode::RememberPerMonitor,
         };
 
         Ok(window_position_mode)
@@ -401,6 +404,9 @@ impl Settings {
         let window_position_mode = match mode {
             WindowPositionMode::Static => None,
             WindowPositionMode::ActiveMonitor => Some(DbWindowPositionMode::ActiveMonitor),
+            WindowPositionMode::CursorMonitor => Some(DbWindowPositionMode::CursorMonitor),
+            WindowPositionMode::FocusedWindowMonitor => Some(DbWindowPositionMode::FocusedWindowMonitor),
+            WindowPositionMode::RememberPerMonitor => Some(DbWindowPositionMode::RememberPerMonitor),
         };
 
         settings.window_position_mode = window_position_mode;
@@ -10,6 +10,7 @@ use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::PluginPreferenceUserData;
 use gauntlet_common::model::SearchResult;
+use gauntlet_common::model::SettingsKeybinding;
 use gauntlet_common::model::SettingsPlugin;
 use gauntlet_common::model::SettingsTheme;
 use gauntlet_common::model::UiPropertyValue;
@@ -214,6 +215,17 @@ impl BackendForSettingsApi for BackendServerImpl {
             .map_err(Into::into)
     }
 
+    async fn clear_search_history(&self) -> RequestResult<()> {
+        self.application_manager
+            .clear_search_history()
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn keybindings(&self) -> RequestResult<Vec<SettingsKeybinding>> {
+        self.application_manager.keybindings().await.map_err(Into::into)
+    }
+
     async fn set_preference_value(
         &self,
         plugin_id: PluginId,
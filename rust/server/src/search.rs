@@ -13,6 +13,7 @@ use gauntlet_common::model::SearchResultAccessory;
 use gauntlet_common::model::SearchResultEntrypointAction;
 use gauntlet_common::model::SearchResultEntrypointActionType;
 use gauntlet_common::model::SearchResultEntrypointType;
+use gauntlet_common::model::SearchResultSection;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::rpc::frontend_api::FrontendApiProxy;
 use tantivy::collector::TopDocs;
@@ -31,6 +32,8 @@ use tantivy::IndexWriter;
 use tantivy::ReloadPolicy;
 use tantivy::Searcher;
 
+use crate::plugins::config_reader::SearchSectionKind;
+use crate::plugins::config_reader::SearchSectionsConfig;
 use crate::plugins::settings::Settings;
 
 #[derive(Clone)]
@@ -48,6 +51,7 @@ pub struct SearchIndex {
     plugin_name: Field,
     plugin_id: Field,
     entrypoint_alias: Field,
+    entrypoint_keywords: Field,
 }
 
 struct PluginData {
@@ -64,6 +68,8 @@ struct EntrypointData {
     actions: Vec<EntrypointActionData>,
     accessories: Vec<SearchResultAccessory>,
     search_alias: Option<String>,
+    keywords: Vec<String>,
+    application: bool,
 }
 
 struct EntrypointActionData {
@@ -108,6 +114,8 @@ pub struct SearchIndexItem {
     pub entrypoint_frecency: f64,
     pub entrypoint_actions: Vec<SearchIndexItemAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
+    pub entrypoint_keywords: Vec<String>,
+    pub entrypoint_application: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -134,6 +142,7 @@ impl SearchIndex {
             schema_builder.add_text_field("plugin_name", TEXT | STORED);
             schema_builder.add_text_field("plugin_id", STRING | STORED);
             schema_builder.add_text_field("entrypoint_alias", TEXT | STORED);
+            schema_builder.add_text_field("entrypoint_keywords", TEXT);
 
             schema_builder.build()
         };
@@ -149,6 +158,9 @@ impl SearchIndex {
         let entrypoint_alias = schema
             .get_field("entrypoint_alias")
             .expect("plugin_id field should exist");
+        let entrypoint_keywords = schema
+            .get_field("entrypoint_keywords")
+            .expect("entrypoint_keywords field should exist");
 
         let index = Index::create_in_ram(schema.clone());
 
@@ -166,6 +178,7 @@ impl SearchIndex {
             plugin_name,
             plugin_id,
             entrypoint_alias,
+            entrypoint_keywords,
         })
     }
 
@@ -239,6 +252,10 @@ impl SearchIndex {
             document.add_field_value(self.entrypoint_alias, alias.clone())
         }
 
+        for keyword in &entrypoint_data.keywords {
+            document.add_field_value(self.entrypoint_keywords, keyword.clone())
+        }
+
         index_writer.add_document(document)?;
 
         index_writer.commit()?;
@@ -292,6 +309,8 @@ impl SearchIndex {
                     actions,
                     accessories: item.entrypoint_accessories,
                     search_alias: aliases.get(&(plugin_id.clone(), item.entrypoint_id.clone())).cloned(),
+                    keywords: item.entrypoint_keywords,
+                    application: item.entrypoint_application,
                 };
 
                 (item.entrypoint_id.clone(), data)
@@ -328,6 +347,10 @@ impl SearchIndex {
                 document.add_field_value(self.entrypoint_alias, alias.clone())
             }
 
+            for keyword in &entrypoint_data.keywords {
+                document.add_field_value(self.entrypoint_keywords, keyword.clone())
+            }
+
             index_writer.add_document(document)?;
         }
 
@@ -399,9 +422,12 @@ impl SearchIndex {
             .collect()
     }
 
-    pub fn search(&self, query: &str) -> anyhow::Result<Vec<SearchResult>> {
+    pub fn search(&self, query: &str, sections: Option<&SearchSectionsConfig>) -> anyhow::Result<Vec<SearchResult>> {
         let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
+        // recently used section only makes sense when nothing is typed
+        let show_recently_used = query.is_empty();
+
         let searcher = self.index_reader.searcher();
 
         let query_parser = QueryParser::new(
@@ -409,6 +435,7 @@ impl SearchIndex {
             self.entrypoint_name,
             self.plugin_name,
             self.entrypoint_alias,
+            self.entrypoint_keywords,
         );
 
         let query = query_parser.create_query(query);
@@ -443,7 +470,18 @@ impl SearchIndex {
 
         result.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
 
-        let result = result.into_iter().map(|(item, _)| item).collect::<Vec<_>>();
+        let result = match sections {
+            None => {
+                result
+                    .into_iter()
+                    .map(|(mut item, _)| {
+                        item.section = None;
+                        item
+                    })
+                    .collect::<Vec<_>>()
+            }
+            Some(sections) => group_search_results(result, show_recently_used, sections),
+        };
 
         drop(entrypoint_data);
 
@@ -510,6 +548,21 @@ impl SearchIndex {
 
                 let entrypoint_accessories = entrypoint_data.accessories.iter().cloned().collect();
 
+                let section = match &entrypoint_data.entrypoint_type {
+                    SearchResultEntrypointType::Command | SearchResultEntrypointType::View => {
+                        SearchResultSection::Commands
+                    }
+                    SearchResultEntrypointType::Generated => {
+                        if entrypoint_data.application {
+                            SearchResultSection::Applications
+                        } else {
+                            SearchResultSection::Generated {
+                                plugin_name: plugin_name.clone(),
+                            }
+                        }
+                    }
+                };
+
                 let result_item = SearchResult {
                     entrypoint_type: entrypoint_data.entrypoint_type.clone(),
                     entrypoint_name,
@@ -524,6 +577,7 @@ impl SearchIndex {
                     entrypoint_actions,
                     entrypoint_accessories,
                     entrypoint_alias,
+                    section: Some(section),
                 };
 
                 Ok((result_item, entrypoint_data.frecency))
@@ -534,11 +588,68 @@ impl SearchIndex {
     }
 }
 
+// expects results to be already sorted by score, order inside the section is preserved
+fn group_search_results(
+    results: Vec<(SearchResult, f64)>,
+    show_recently_used: bool,
+    config: &SearchSectionsConfig,
+) -> Vec<SearchResult> {
+    let mut sections: Vec<(SearchResultSection, Vec<SearchResult>)> = vec![];
+
+    let recently_used_limit = config.limits.get(&SearchSectionKind::RecentlyUsed).cloned();
+    let mut recently_used_count = 0;
+
+    for (mut item, frecency) in results {
+        // items that don't fit into recently used section stay in their own section instead of being dropped
+        let recently_used_full = recently_used_limit.is_some_and(|limit| recently_used_count >= limit);
+
+        if show_recently_used && frecency > 0.0 && !recently_used_full {
+            item.section = Some(SearchResultSection::RecentlyUsed);
+            recently_used_count += 1;
+        }
+
+        let section = item.section.clone().expect("section is always set when fetching");
+
+        match sections.iter_mut().find(|(existing, _)| existing == &section) {
+            Some((_, items)) => items.push(item),
+            None => sections.push((section, vec![item])),
+        }
+    }
+
+    // stable sort, so generated sections of different plugins are ordered by their best result
+    sections.sort_by_key(|(section, _)| {
+        let kind = search_section_kind(section);
+
+        config.order.iter().position(|configured| configured == &kind)
+    });
+
+    sections
+        .into_iter()
+        .flat_map(|(section, mut items)| {
+            if let Some(limit) = config.limits.get(&search_section_kind(&section)) {
+                items.truncate(*limit);
+            }
+
+            items
+        })
+        .collect()
+}
+
+fn search_section_kind(section: &SearchResultSection) -> SearchSectionKind {
+    match section {
+        SearchResultSection::RecentlyUsed => SearchSectionKind::RecentlyUsed,
+        SearchResultSection::Applications => SearchSectionKind::Applications,
+        SearchResultSection::Commands => SearchSectionKind::Commands,
+        SearchResultSection::Generated { .. } => SearchSectionKind::Generated,
+    }
+}
+
 struct QueryParser {
     tokenizer_manager: TokenizerManager,
     entrypoint_name: Field,
     plugin_name: Field,
     entrypoint_alias: Field,
+    entrypoint_keywords: Field,
 }
 
 impl QueryParser {
@@ -547,12 +658,14 @@ impl QueryParser {
         entrypoint_name: Field,
         plugin_name: Field,
         entrypoint_alias: Field,
+        entrypoint_keywords: Field,
     ) -> Self {
         Self {
             tokenizer_manager,
             entrypoint_name,
             plugin_name,
             entrypoint_alias,
+            entrypoint_keywords,
         }
     }
 
@@ -582,11 +695,13 @@ impl QueryParser {
         let entrypoint_name_terms = terms_fn(self.entrypoint_name);
         let plugin_name_terms = terms_fn(self.plugin_name);
         let entrypoint_alias_terms = terms_fn(self.entrypoint_alias);
+        let entrypoint_keywords_terms = terms_fn(self.entrypoint_keywords);
 
         Box::new(BooleanQuery::union(vec![
             Box::new(entrypoint_name_terms),
             Box::new(plugin_name_terms),
             Box::new(entrypoint_alias_terms),
+            Box::new(entrypoint_keywords_terms),
         ]))
     }
 